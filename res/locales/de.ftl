@@ -3,21 +3,52 @@ telemetry-label-plateau = P(plateaudruck)
 telemetry-label-expiratory = P(PEEP)
 telemetry-label-cycles = Zyklen/Minuten
 telemetry-label-ratio = Inspirationsdauer
-telemetry-label-ratio-details = ein.-aus. verhältnis
+telemetry-label-ratio-details = { $unit } (ein.-aus. verhältnis { $ratio })
 telemetry-label-tidal = Atemzugvolumen
 telemetry-label-minute-volume = Minutenlautstärke
+telemetry-label-triggered = Getriggerte Atemzüge
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minute
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Daten nicht aktualisiert seit { $seconds }s
+settings-notice-corrupt = Gespeicherte Einstellungen waren unlesbar und wurden zurückgesetzt (zum Ausblenden tippen)
+settings-notice-invalid = Einige gespeicherte Einstellungen waren ungültig und wurden zurückgesetzt (zum Ausblenden tippen)
+
+graph-freeze = Einfrieren
+graph-resume = Fortsetzen
+graph-freeze-save = Speichern
+graph-event-settings = Einstellungen
+graph-event-alarm = Alarm { $code }
+graph-event-triggered = Getriggert
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Einstellungen
+record-event-snooze = Stumm
+record-event-snooze-expired = Stumm abgelaufen
+record-event-preset = Patient
+record-event-eol = Bestätigung
+record-event-weaning-start = Versuch
+record-event-weaning-end = Versuchsende
+record-event-freeze = Standbild
+record-event-session-start = Sitzung
+record-event-session-end = Sitzungsende
+record-event-alarm-ack = Alarm quitt.
+record-event-modal = Menü
 
 alarms-title = ALARME
 alarms-empty = Kein Alarm.
+alarms-resolved = Behoben: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = Der Plateaudruck wird nicht erreicht
 alarms-message-patient-unplugged = Der Patient ist ausgesteckt
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Das Atemvolumen ist zu gering
 alarms-message-tidal-volume-high = Das Atemvolumen ist zu hoch
 alarms-message-peak-pressure-high = Der Spitzendruck ist zu hoch
 alarms-message-expiratory-flow-too-low = Der exspiratorische Fluss ist zu gering
+alarms-message-telemetry-lost = Telemetrie verloren (Daten veraltet)
+alarms-message-driving-pressure-too-high = Der Driving Pressure ist zu hoch
+alarms-message-compliance-too-low = Die statische Compliance ist zu niedrig
+alarms-message-resistance-too-high = Der Atemwegswiderstand ist zu hoch
 alarms-message-unknown = Unbekannter Alarm (Code prüfen)
 
 status-unit-stopped = Gerät gestoppt
 status-unit-active = Gerät aktiv
 status-power-battery = Batterie
 status-power-ac = Stromversorgung
+status-snooze-remaining = Stumm { $remaining }
+status-snooze-escalated = Neuer Alarm { $remaining }
 
 mode-class-pc = Druck
 mode-class-vc = Volumen
@@ -53,6 +90,10 @@ mode-group-general = Allgemeines
 mode-group-alarms = Alarm
 
 advanced-group-statistics = Statistiken
+advanced-group-mechanics = Mechanik
+advanced-group-weaning = Entwöhnung
+advanced-group-session = Sitzung
+advanced-group-preferences = Präferenzen
 advanced-group-settings = Optionen
 
 modal-close = Schließen
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Exspirationsrate (hoch)
 modal-mode-alarm-low-tidal-volume = Atemzugvolumen (gering)
 modal-mode-alarm-high-tidal-volume = Atemzugvolumen (hoch)
 modal-mode-alarm-leak = Leckvolumen (hoch)
+modal-mode-alarm-leak-measured = Gemessen: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Spitzendruck (hoch)
+modal-mode-alarms-auto-set = Auto-Setzen
 
 modal-advanced-locale = Sprache
+modal-advanced-theme = Farbschema
+modal-advanced-theme-default = Standard
+modal-advanced-theme-night = Nacht
+modal-advanced-theme-high-contrast = Hoher Kontrast
 modal-advanced-date = Datum
 modal-advanced-time = Zeit
 modal-advanced-timezone = Zeitzone
+modal-advanced-value-maximum = { $value } (max. { $maximum })
+
+modal-advanced-weaning-duration = Versuchsdauer
+modal-advanced-weaning-minutes = { $minutes } Min.
+modal-advanced-weaning-trial = Atemversuch
+modal-advanced-weaning-start = Gestoppt - starten?
+modal-advanced-weaning-stop = Läuft - stoppen?
+modal-advanced-weaning-elapsed = Verstrichene Zeit
+modal-advanced-weaning-rsbi = RSBI
+modal-advanced-weaning-rate = Spontanfrequenz
+modal-advanced-weaning-minute-volume = Minutenvolumen
+modal-advanced-weaning-result = Ergebnis
+modal-advanced-weaning-result-ongoing = Läuft
+modal-advanced-weaning-result-passed = Bestanden
+modal-advanced-weaning-result-failed = Nicht bestanden
+modal-advanced-weaning-result-aborted = Vorzeitig gestoppt
+modal-advanced-weaning-result-unavailable = Nur PC-VSAI
+
+modal-advanced-session-patient = Patient
+modal-advanced-session-patient-input = Tippen, dann eingeben
+modal-advanced-session-started = Gestartet am
+modal-advanced-session-duration = Dauer
+modal-advanced-session-events = Alarme / Einstellungen
+modal-advanced-session-record = Aufzeichnung
+modal-advanced-session-state = Sitzung
+modal-advanced-session-start = Beendet - starten?
+modal-advanced-session-end = Läuft - beenden?
+modal-advanced-session-new-patient = Neuer Patient
+modal-advanced-session-new = Starten
+
+modal-advanced-preferences-graph-seconds = Diagrammzeitraum
+modal-advanced-preferences-pressure-unit = Druckeinheit
+modal-advanced-preferences-flow-unit = Durchflusseinheit
+modal-advanced-preferences-volume-unit = Volumeneinheit
+modal-advanced-preferences-snooze-max = Max. Stummschaltung
+modal-advanced-preferences-brightness = Helligkeit
+modal-advanced-preferences-preset-age = Standard-Altersgruppe
+modal-advanced-preferences-preset-gender = Standardgeschlecht
+modal-advanced-preferences-record-directory = Aufzeichnungsordner
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Inbetriebnahme...
 initializing-connected = Initialisierung...
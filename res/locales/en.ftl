@@ -3,21 +3,52 @@ telemetry-label-plateau = P(plateau)
 telemetry-label-expiratory = P(expiratory)
 telemetry-label-cycles = Cycles/minute
 telemetry-label-ratio = Inspiratory duration
-telemetry-label-ratio-details = insp-exp. ratio of
+telemetry-label-ratio-details = { $unit } (insp-exp. ratio of { $ratio })
 telemetry-label-tidal = Tidal volume
 telemetry-label-minute-volume = Minute volume
+telemetry-label-triggered = Triggered breaths
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minute
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Data not updated since { $seconds }s
+settings-notice-corrupt = Saved settings could not be read and were reset (tap to dismiss)
+settings-notice-invalid = Some saved settings were not valid and were reset (tap to dismiss)
+
+graph-freeze = Freeze
+graph-resume = Resume
+graph-freeze-save = Save
+graph-event-settings = Settings
+graph-event-alarm = Alarm { $code }
+graph-event-triggered = Triggered
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Settings
+record-event-snooze = Snooze
+record-event-snooze-expired = Snooze expired
+record-event-preset = Patient
+record-event-eol = Confirm
+record-event-weaning-start = Trial
+record-event-weaning-end = Trial end
+record-event-freeze = Freeze
+record-event-session-start = Session
+record-event-session-end = Session end
+record-event-alarm-ack = Alarm ack.
+record-event-modal = Menu
 
 alarms-title = ALARMS
 alarms-empty = No alarm is active.
+alarms-resolved = Resolved: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = Plateau pressure is not reached
 alarms-message-patient-unplugged = Patient is unplugged
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Tidal volume is too low
 alarms-message-tidal-volume-high = Tidal volume is too high
 alarms-message-peak-pressure-high = Peak pressure is too high
 alarms-message-expiratory-flow-too-low = Expiratory flow is too low
+alarms-message-telemetry-lost = Telemetry lost (data is stale)
+alarms-message-driving-pressure-too-high = Driving pressure is too high
+alarms-message-compliance-too-low = Static compliance is too low
+alarms-message-resistance-too-high = Airway resistance is too high
 alarms-message-unknown = Unknown alarm (check code)
 
 status-unit-stopped = Unit stopped
 status-unit-active = Unit active
 status-power-battery = Battery
 status-power-ac = AC power
+status-snooze-remaining = Snoozed { $remaining }
+status-snooze-escalated = New alarm { $remaining }
 
 mode-class-pc = Pressure
 mode-class-vc = Volume
@@ -53,6 +90,10 @@ mode-group-general = General
 mode-group-alarms = Alarms
 
 advanced-group-statistics = Statistics
+advanced-group-mechanics = Mechanics
+advanced-group-weaning = Weaning
+advanced-group-session = Session
+advanced-group-preferences = Preferences
 advanced-group-settings = Settings
 
 modal-close = Close
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Respiratory rate (max)
 modal-mode-alarm-low-tidal-volume = Tidal volume (min)
 modal-mode-alarm-high-tidal-volume = Tidal volume (max)
 modal-mode-alarm-leak = Leak volume (max)
+modal-mode-alarm-leak-measured = Measured: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Peak pressure (max)
+modal-mode-alarms-auto-set = Auto-set
 
 modal-advanced-locale = Language
+modal-advanced-theme = Theme
+modal-advanced-theme-default = Default
+modal-advanced-theme-night = Night
+modal-advanced-theme-high-contrast = High contrast
 modal-advanced-date = Date
 modal-advanced-time = Time
 modal-advanced-timezone = Timezone
+modal-advanced-value-maximum = { $value } (max { $maximum })
+
+modal-advanced-weaning-duration = Trial duration
+modal-advanced-weaning-minutes = { $minutes } min
+modal-advanced-weaning-trial = Breathing trial
+modal-advanced-weaning-start = Stopped - start?
+modal-advanced-weaning-stop = Running - stop?
+modal-advanced-weaning-elapsed = Elapsed time
+modal-advanced-weaning-rsbi = RSBI
+modal-advanced-weaning-rate = Spontaneous rate
+modal-advanced-weaning-minute-volume = Minute volume
+modal-advanced-weaning-result = Result
+modal-advanced-weaning-result-ongoing = In progress
+modal-advanced-weaning-result-passed = Passed
+modal-advanced-weaning-result-failed = Failed
+modal-advanced-weaning-result-aborted = Stopped early
+modal-advanced-weaning-result-unavailable = PC-VSAI only
+
+modal-advanced-session-patient = Patient
+modal-advanced-session-patient-input = Tap, then type
+modal-advanced-session-started = Started at
+modal-advanced-session-duration = Duration
+modal-advanced-session-events = Alarms / settings
+modal-advanced-session-record = Record file
+modal-advanced-session-state = Session
+modal-advanced-session-start = Ended - start?
+modal-advanced-session-end = Ongoing - end?
+modal-advanced-session-new-patient = New patient
+modal-advanced-session-new = Start
+
+modal-advanced-preferences-graph-seconds = Graph time range
+modal-advanced-preferences-pressure-unit = Pressure unit
+modal-advanced-preferences-flow-unit = Flow unit
+modal-advanced-preferences-volume-unit = Volume unit
+modal-advanced-preferences-snooze-max = Maximum snooze
+modal-advanced-preferences-brightness = Brightness
+modal-advanced-preferences-preset-age = Default age group
+modal-advanced-preferences-preset-gender = Default gender
+modal-advanced-preferences-record-directory = Record directory
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Starting up...
 initializing-connected = Initializing...
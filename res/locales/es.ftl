@@ -3,21 +3,52 @@ telemetry-label-plateau = P(meseta)
 telemetry-label-expiratory = P(exhalación)
 telemetry-label-cycles = Ciclos/minutos
 telemetry-label-ratio = Duración inspiratoria
-telemetry-label-ratio-details = relación insp-exp
+telemetry-label-ratio-details = { $unit } (relación insp-exp { $ratio })
 telemetry-label-tidal = Volumen corriente
 telemetry-label-minute-volume = Volumen minuto
+telemetry-label-triggered = Ciclos disparados
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minuto
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Datos no actualizados desde hace { $seconds }s
+settings-notice-corrupt = Los ajustes guardados no se pudieron leer y se restablecieron (tocar para ocultar)
+settings-notice-invalid = Algunos ajustes guardados no eran válidos y se restablecieron (tocar para ocultar)
+
+graph-freeze = Congelar
+graph-resume = Reanudar
+graph-freeze-save = Guardar
+graph-event-settings = Ajustes
+graph-event-alarm = Alarma { $code }
+graph-event-triggered = Disparado
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Ajustes
+record-event-snooze = Silencio
+record-event-snooze-expired = Fin del silencio
+record-event-preset = Paciente
+record-event-eol = Confirmación
+record-event-weaning-start = Prueba
+record-event-weaning-end = Fin prueba
+record-event-freeze = Congelado
+record-event-session-start = Sesión
+record-event-session-end = Fin de sesión
+record-event-alarm-ack = Alarma conf.
+record-event-modal = Menú
 
 alarms-title = ALARMAS
 alarms-empty = Ninguna alarma.
+alarms-resolved = Resuelta: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = No se alcanza la presión de meseta
 alarms-message-patient-unplugged = El paciente está desenchufado
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = El volumen corriente es demasiado bajo
 alarms-message-tidal-volume-high = El volumen corriente es demasiado alto
 alarms-message-peak-pressure-high = La presión máxima es demasiado alta
 alarms-message-expiratory-flow-too-low = El flujo espiratorio es demasiado bajo
+alarms-message-telemetry-lost = Telemetría perdida (datos obsoletos)
+alarms-message-driving-pressure-too-high = La presión de distensión es demasiado alta
+alarms-message-compliance-too-low = La distensibilidad estática es demasiado baja
+alarms-message-resistance-too-high = La resistencia de la vía aérea es demasiado alta
 alarms-message-unknown = Alarma desconocida (código de verificación)
 
 status-unit-stopped = Unidad detenida
 status-unit-active = Unidad activa
 status-power-battery = Batería
 status-power-ac = Corriente alterna
+status-snooze-remaining = Silenciado { $remaining }
+status-snooze-escalated = Nueva alarma { $remaining }
 
 mode-class-pc = Presión
 mode-class-vc = Volumen
@@ -53,6 +90,10 @@ mode-group-general = General
 mode-group-alarms = Alarmas
 
 advanced-group-statistics = Estadísticas
+advanced-group-mechanics = Mecánica
+advanced-group-weaning = Destete
+advanced-group-session = Sesión
+advanced-group-preferences = Preferencias
 advanced-group-settings = Configuraciones
 
 modal-close = Cerca
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Tasa espiratoria (alta)
 modal-mode-alarm-low-tidal-volume = Volumen corriente (bajo)
 modal-mode-alarm-high-tidal-volume = Volumen corriente (alto)
 modal-mode-alarm-leak = Volumen de fuga (alto)
+modal-mode-alarm-leak-measured = Medida: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Presión pico (alto)
+modal-mode-alarms-auto-set = Auto-ajustar
 
 modal-advanced-locale = Idioma
+modal-advanced-theme = Tema
+modal-advanced-theme-default = Predeterminado
+modal-advanced-theme-night = Noche
+modal-advanced-theme-high-contrast = Alto contraste
 modal-advanced-date = Fecha
 modal-advanced-time = Hora
 modal-advanced-timezone = Zona horaria
+modal-advanced-value-maximum = { $value } (máx. { $maximum })
+
+modal-advanced-weaning-duration = Duración de la prueba
+modal-advanced-weaning-minutes = { $minutes } min
+modal-advanced-weaning-trial = Prueba respiratoria
+modal-advanced-weaning-start = Detenida - ¿iniciar?
+modal-advanced-weaning-stop = En curso - ¿detener?
+modal-advanced-weaning-elapsed = Tiempo transcurrido
+modal-advanced-weaning-rsbi = IRRS
+modal-advanced-weaning-rate = Frecuencia espontánea
+modal-advanced-weaning-minute-volume = Volumen minuto
+modal-advanced-weaning-result = Resultado
+modal-advanced-weaning-result-ongoing = En curso
+modal-advanced-weaning-result-passed = Superada
+modal-advanced-weaning-result-failed = Fallida
+modal-advanced-weaning-result-aborted = Detenida antes
+modal-advanced-weaning-result-unavailable = Solo PC-VSAI
+
+modal-advanced-session-patient = Paciente
+modal-advanced-session-patient-input = Tocar y escribir
+modal-advanced-session-started = Iniciada el
+modal-advanced-session-duration = Duración
+modal-advanced-session-events = Alarmas / ajustes
+modal-advanced-session-record = Grabación
+modal-advanced-session-state = Sesión
+modal-advanced-session-start = Terminada - ¿iniciar?
+modal-advanced-session-end = En curso - ¿terminar?
+modal-advanced-session-new-patient = Nuevo paciente
+modal-advanced-session-new = Iniciar
+
+modal-advanced-preferences-graph-seconds = Duración de gráficos
+modal-advanced-preferences-pressure-unit = Unidad de presión
+modal-advanced-preferences-flow-unit = Unidad de flujo
+modal-advanced-preferences-volume-unit = Unidad de volumen
+modal-advanced-preferences-snooze-max = Silencio máximo
+modal-advanced-preferences-brightness = Brillo
+modal-advanced-preferences-preset-age = Grupo de edad por defecto
+modal-advanced-preferences-preset-gender = Sexo por defecto
+modal-advanced-preferences-record-directory = Carpeta de grabación
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Empezando...
 initializing-connected = Inicializando...
@@ -3,21 +3,52 @@ telemetry-label-plateau = P(plateau)
 telemetry-label-expiratory = P(expiration)
 telemetry-label-cycles = Cycles/minute
 telemetry-label-ratio = Temps inspiratoire
-telemetry-label-ratio-details = ratio insp-exp. de
+telemetry-label-ratio-details = { $unit } (ratio insp-exp. de { $ratio })
 telemetry-label-tidal = Volume courant
 telemetry-label-minute-volume = Volume minute
+telemetry-label-triggered = Cycles déclenchés
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minute
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Données non mises à jour depuis { $seconds }s
+settings-notice-corrupt = Les réglages enregistrés étaient illisibles et ont été réinitialisés (toucher pour masquer)
+settings-notice-invalid = Certains réglages enregistrés étaient invalides et ont été réinitialisés (toucher pour masquer)
+
+graph-freeze = Figer
+graph-resume = Reprendre
+graph-freeze-save = Enregistrer
+graph-event-settings = Réglages
+graph-event-alarm = Alarme { $code }
+graph-event-triggered = Déclenché
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Réglages
+record-event-snooze = Sourdine
+record-event-snooze-expired = Fin de sourdine
+record-event-preset = Patient
+record-event-eol = Confirmation
+record-event-weaning-start = Essai
+record-event-weaning-end = Fin essai
+record-event-freeze = Figé
+record-event-session-start = Session
+record-event-session-end = Fin de session
+record-event-alarm-ack = Alarme acq.
+record-event-modal = Menu
 
 alarms-title = ALARMES
 alarms-empty = Aucune alarme.
+alarms-resolved = Résolue : { $alarm }
 
 alarms-message-plateau-pressure-not-reached = Pression plateau non atteinte
 alarms-message-patient-unplugged = Patient deconnecté
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Volume courant trop faible
 alarms-message-tidal-volume-high = Volume courant trop élevé
 alarms-message-peak-pressure-high = Pression de crête trop élevée
 alarms-message-expiratory-flow-too-low = Débit expiratoire trop faible
+alarms-message-telemetry-lost = Télémétrie perdue (données figées)
+alarms-message-driving-pressure-too-high = La pression motrice est trop élevée
+alarms-message-compliance-too-low = La compliance statique est trop basse
+alarms-message-resistance-too-high = La résistance des voies aériennes est trop élevée
 alarms-message-unknown = Alarme inconnue (vérifiez le code)
 
 status-unit-stopped = Unité arrêtée
 status-unit-active = Unité active
 status-power-battery = Batterie
 status-power-ac = Branché sur AC
+status-snooze-remaining = En sourdine { $remaining }
+status-snooze-escalated = Nouvelle alarme { $remaining }
 
 mode-class-pc = Pression
 mode-class-vc = Volume
@@ -53,6 +90,10 @@ mode-group-general = Général
 mode-group-alarms = Alarmes
 
 advanced-group-statistics = Statistiques
+advanced-group-mechanics = Mécanique
+advanced-group-weaning = Sevrage
+advanced-group-session = Session
+advanced-group-preferences = Préférences
 advanced-group-settings = Paramètres
 
 modal-close = Fermer
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Cycles par minute (haut)
 modal-mode-alarm-low-tidal-volume = Volume courant (bas)
 modal-mode-alarm-high-tidal-volume = Volume courant (haut)
 modal-mode-alarm-leak = Volume de fuite (haut)
+modal-mode-alarm-leak-measured = Mesurée : { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Pression de crête (haut)
+modal-mode-alarms-auto-set = Auto-régler
 
 modal-advanced-locale = Langue
+modal-advanced-theme = Thème
+modal-advanced-theme-default = Par défaut
+modal-advanced-theme-night = Nuit
+modal-advanced-theme-high-contrast = Contraste élevé
 modal-advanced-date = Date
 modal-advanced-time = Heure
 modal-advanced-timezone = Fuseau horaire
+modal-advanced-value-maximum = { $value } (max { $maximum })
+
+modal-advanced-weaning-duration = Durée de l'essai
+modal-advanced-weaning-minutes = { $minutes } min
+modal-advanced-weaning-trial = Essai de ventilation
+modal-advanced-weaning-start = Arrêté - démarrer ?
+modal-advanced-weaning-stop = En cours - arrêter ?
+modal-advanced-weaning-elapsed = Temps écoulé
+modal-advanced-weaning-rsbi = IRSP
+modal-advanced-weaning-rate = Fréquence spontanée
+modal-advanced-weaning-minute-volume = Volume minute
+modal-advanced-weaning-result = Résultat
+modal-advanced-weaning-result-ongoing = En cours
+modal-advanced-weaning-result-passed = Réussi
+modal-advanced-weaning-result-failed = Échoué
+modal-advanced-weaning-result-aborted = Arrêté avant la fin
+modal-advanced-weaning-result-unavailable = VS-AI uniquement
+
+modal-advanced-session-patient = Patient
+modal-advanced-session-patient-input = Toucher, puis saisir
+modal-advanced-session-started = Démarrée le
+modal-advanced-session-duration = Durée
+modal-advanced-session-events = Alarmes / réglages
+modal-advanced-session-record = Enregistrement
+modal-advanced-session-state = Session
+modal-advanced-session-start = Terminée - démarrer ?
+modal-advanced-session-end = En cours - terminer ?
+modal-advanced-session-new-patient = Nouveau patient
+modal-advanced-session-new = Démarrer
+
+modal-advanced-preferences-graph-seconds = Durée des graphiques
+modal-advanced-preferences-pressure-unit = Unité de pression
+modal-advanced-preferences-flow-unit = Unité de débit
+modal-advanced-preferences-volume-unit = Unité de volume
+modal-advanced-preferences-snooze-max = Mise en sourdine max.
+modal-advanced-preferences-brightness = Luminosité
+modal-advanced-preferences-preset-age = Tranche d'âge par défaut
+modal-advanced-preferences-preset-gender = Sexe par défaut
+modal-advanced-preferences-record-directory = Dossier d'enregistrement
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Démarrage en cours...
 initializing-connected = Préparation en cours...
@@ -0,0 +1,280 @@
+telemetry-label-peak = P(שיא)
+telemetry-label-plateau = P(פלטו)
+telemetry-label-expiratory = P(נשיפה)
+telemetry-label-cycles = מחזורים/דקה
+telemetry-label-ratio = משך שאיפה
+telemetry-label-ratio-details = { $unit } (יחס שאיפה-נשיפה { $ratio })
+telemetry-label-tidal = נפח נשימה
+telemetry-label-minute-volume = נפח דקה
+telemetry-label-triggered = נשימות מוזנקות
+
+telemetry-unit-cmh2o = cmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
+telemetry-unit-lpm = L/min
+telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
+telemetry-unit-per-minute = /דקה
+telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
+telemetry-unit-milliseconds = ms
+telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = הנתונים לא עודכנו מזה { $seconds } שניות
+settings-notice-corrupt = לא ניתן היה לקרוא את ההגדרות השמורות והן אופסו (הקש להסתרה)
+settings-notice-invalid = חלק מההגדרות השמורות לא היו תקינות ואופסו (הקש להסתרה)
+
+graph-freeze = הקפאה
+graph-resume = המשך
+graph-freeze-save = שמירה
+graph-event-settings = הגדרות
+graph-event-alarm = התראה { $code }
+graph-event-triggered = מוזנקת
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = הגדרות
+record-event-snooze = השתקה
+record-event-snooze-expired = ההשתקה הסתיימה
+record-event-preset = מטופל
+record-event-eol = אישור
+record-event-weaning-start = ניסיון
+record-event-weaning-end = סוף ניסיון
+record-event-freeze = הקפאה
+record-event-session-start = טיפול
+record-event-session-end = סוף טיפול
+record-event-alarm-ack = אישור התראה
+record-event-modal = תפריט
+
+alarms-title = התראות
+alarms-empty = אין התראה פעילה.
+alarms-resolved = נפתרה: { $alarm }
+
+alarms-message-plateau-pressure-not-reached = לחץ הפלטו לא הושג
+alarms-message-patient-unplugged = המטופל מנותק
+alarms-message-peep-pressure-not-reached = לחץ PEEP לא הושג
+alarms-message-battery-low = הסוללה חלשה
+alarms-message-battery-very-low = הסוללה חלשה מאוד
+alarms-message-power-cable-unplugged = כבל החשמל מנותק
+alarms-message-pressure-too-high = הלחץ גבוה מדי
+alarms-message-inspiratory-minute-volume-low = נפח דקה בשאיפה נמוך מדי
+alarms-message-inspiratory-minute-volume-high = נפח דקה בשאיפה גבוה מדי
+alarms-message-expiratory-minute-volume-low = נפח דקה בנשיפה נמוך מדי
+alarms-message-expiratory-minute-volume-high = נפח דקה בנשיפה גבוה מדי
+alarms-message-respiratory-rate-low = קצב הנשימה נמוך מדי
+alarms-message-respiratory-rate-high = קצב הנשימה גבוה מדי
+alarms-message-leak-high = נפח הדליפה גבוה מדי
+alarms-message-tidal-volume-low = נפח הנשימה נמוך מדי
+alarms-message-tidal-volume-high = נפח הנשימה גבוה מדי
+alarms-message-peak-pressure-high = לחץ השיא גבוה מדי
+alarms-message-expiratory-flow-too-low = זרימת הנשיפה נמוכה מדי
+alarms-message-telemetry-lost = הטלמטריה אבדה (נתונים לא עדכניים)
+alarms-message-driving-pressure-too-high = לחץ ההנעה גבוה מדי
+alarms-message-compliance-too-low = ההיענות הסטטית נמוכה מדי
+alarms-message-resistance-too-high = התנגודת בדרכי האוויר גבוהה מדי
+alarms-message-unknown = התראה לא ידועה (בדקו את הקוד)
+
+status-unit-stopped = המכשיר עצור
+status-unit-active = המכשיר פעיל
+status-power-battery = סוללה
+status-power-ac = חשמל
+status-snooze-remaining = מושתק { $remaining }
+status-snooze-escalated = התראה חדשה { $remaining }
+
+mode-class-pc = לחץ
+mode-class-vc = נפח
+mode-type-cmv = CMV
+mode-type-ac = AC
+mode-type-vsai = VSAI
+mode-group-general = כללי
+mode-group-alarms = התראות
+
+advanced-group-statistics = סטטיסטיקה
+advanced-group-mechanics = מכניקה
+advanced-group-weaning = גמילה
+advanced-group-session = טיפול
+advanced-group-preferences = העדפות
+advanced-group-settings = הגדרות
+
+modal-close = סגירה
+modal-apply = החלה
+modal-cancel = התעלמות
+
+modal-preset-title = שלום. נא להגדיר את פרטי המטופל.
+modal-preset-subtitle = יוחלו הגדרות מוכנות מראש, שניתן לשנות לאחר מכן.
+modal-preset-gender = מין
+modal-preset-gender-male = זכר
+modal-preset-gender-female = נקבה
+modal-preset-age = קבוצת גיל
+modal-preset-size = גובה
+modal-preset-age-child = ילד
+modal-preset-age-teenager = מתבגר
+modal-preset-age-adult = מבוגר
+
+modal-run-status = מצב מכשיר ההנשמה
+modal-run-status-started = פועל — הקישו לעצירה
+modal-run-status-stopped = עצור — הקישו להפעלה
+
+modal-snooze-alarms = השתקת התראות
+modal-snooze-alarms-active = צפצופים פעילים (ברירת מחדל)
+modal-snooze-alarms-inactive = צפצופים מושבתים (לזמן מה)
+
+modal-mode-pressure-inspiratory = לחץ שאיפה
+modal-mode-pressure-expiratory = לחץ נשיפה
+modal-mode-time-inspiratory = זמן שאיפה
+modal-mode-time-inspiratory-minimum = זמן שאיפה (מינימום)
+modal-mode-time-inspiratory-maximum = זמן שאיפה (מקסימום)
+modal-mode-flow-inspiratory = זרימת שאיפה
+modal-mode-cycles-per-minute = מחזורים לדקה
+modal-mode-tidal-volume = נפח נשימה
+modal-mode-plateau-duration = משך פלטו
+modal-mode-trigger-offset = סף הזנקה
+modal-mode-trigger-expiratory = הזנקת נשיפה
+modal-mode-alarm-low-inspiratory-minute-volume = נפח דקה בשאיפה (מינ׳)
+modal-mode-alarm-high-inspiratory-minute-volume = נפח דקה בשאיפה (מקס׳)
+modal-mode-alarm-low-expiratory-minute-volume = נפח דקה בנשיפה (מינ׳)
+modal-mode-alarm-high-expiratory-minute-volume = נפח דקה בנשיפה (מקס׳)
+modal-mode-alarm-low-respiratory-rate = קצב נשימה (מינ׳)
+modal-mode-alarm-high-respiratory-rate = קצב נשימה (מקס׳)
+modal-mode-alarm-low-tidal-volume = נפח נשימה (מינ׳)
+modal-mode-alarm-high-tidal-volume = נפח נשימה (מקס׳)
+modal-mode-alarm-leak = נפח דליפה (מקס׳)
+modal-mode-alarm-leak-measured = נמדד: { $leak } { $unit } ({ $percent }%)
+modal-mode-alarm-peak-pressure = לחץ שיא (מקס׳)
+modal-mode-alarms-auto-set = הגדרה אוטומטית
+
+modal-advanced-locale = שפה
+modal-advanced-theme = ערכת צבעים
+modal-advanced-theme-default = ברירת מחדל
+modal-advanced-theme-night = לילה
+modal-advanced-theme-high-contrast = ניגודיות גבוהה
+modal-advanced-date = תאריך
+modal-advanced-time = שעה
+modal-advanced-timezone = אזור זמן
+modal-advanced-value-maximum = { $value } (מקס׳ { $maximum })
+
+modal-advanced-weaning-duration = משך הניסיון
+modal-advanced-weaning-minutes = { $minutes } דק׳
+modal-advanced-weaning-trial = ניסיון נשימה
+modal-advanced-weaning-start = עצור - להתחיל?
+modal-advanced-weaning-stop = פועל - לעצור?
+modal-advanced-weaning-elapsed = זמן שחלף
+modal-advanced-weaning-rsbi = RSBI
+modal-advanced-weaning-rate = קצב ספונטני
+modal-advanced-weaning-minute-volume = נפח דקה
+modal-advanced-weaning-result = תוצאה
+modal-advanced-weaning-result-ongoing = בתהליך
+modal-advanced-weaning-result-passed = עבר
+modal-advanced-weaning-result-failed = נכשל
+modal-advanced-weaning-result-aborted = נעצר מוקדם
+modal-advanced-weaning-result-unavailable = PC-VSAI בלבד
+
+modal-advanced-session-patient = מטופל
+modal-advanced-session-patient-input = הקישו ואז הקלידו
+modal-advanced-session-started = התחיל ב־
+modal-advanced-session-duration = משך
+modal-advanced-session-events = התראות / הגדרות
+modal-advanced-session-record = קובץ הקלטה
+modal-advanced-session-state = טיפול
+modal-advanced-session-start = הסתיים - להתחיל?
+modal-advanced-session-end = פעיל - לסיים?
+modal-advanced-session-new-patient = מטופל חדש
+modal-advanced-session-new = התחלה
+
+modal-advanced-preferences-graph-seconds = טווח זמן בגרף
+modal-advanced-preferences-pressure-unit = יחידת לחץ
+modal-advanced-preferences-flow-unit = יחידת זרימה
+modal-advanced-preferences-volume-unit = יחידת נפח
+modal-advanced-preferences-snooze-max = השתקה מרבית
+modal-advanced-preferences-brightness = בהירות
+modal-advanced-preferences-preset-age = קבוצת גיל ברירת מחדל
+modal-advanced-preferences-preset-gender = מין ברירת מחדל
+modal-advanced-preferences-record-directory = תיקיית הקלטות
+modal-advanced-preferences-seconds = { $seconds } שנ׳
+
+initializing-connecting = מתחיל...
+initializing-connected = מאתחל...
+
+error-title-no-device = אופס. לא ניתן להגיע לליבה.
+error-title-timed-out = אופס. אתחול הליבה נכשל.
+error-title-bad-protocol = שגיאה כללית בפרוטוקול הטלמטריה.
+error-title-watchdog = אוי. בקר ההנשמה קרס.
+error-title-sensor-failure = חיישן מנותק. הבטיחות נפגעה.
+error-title-other = אופס. אירעה שגיאה לא ידועה.
+
+error-message-no-device = לא ניתן להגיע לבקר הטלמטריה. האם הוא מוגדר כראוי?
+error-message-timed-out = ייתכן שחלק מהרכיבים לא הופעלו. נסו לכבות ולהדליק את המכשיר.
+error-message-bad-protocol = הקושחה משתמשת בפרוטוקול טלמטריה שאינו נתמך. נא לעדכן את התוכנה.
+error-message-watchdog = טיימר ה־watchdog הופעל, ולכן הבקר הפנימי הופעל מחדש.
+error-message-sensor-failure = מזהה חיישן:
+error-message-other = סיבה:
+
+stop-title = מכשיר ההנשמה אינו פעיל
+stop-description = הקישו על ערך כלשהו כדי להגדיר אותו, ואז לחצו על הפעלה.
+
+end-of-line-title-primary = מצב שירות
+end-of-line-title-secondary = בדיקת סוף קו ייצור
+
+end-of-line-content-title-step-start = התחלת בדיקת סוף הקו.
+end-of-line-content-title-step-check-fan = נא לבדוק את המאווררים.
+end-of-line-content-title-step-test-battery-dead = בודק את מצב הסוללה...
+end-of-line-content-title-step-disconnect-mains = נא לנתק את כבל החשמל.
+end-of-line-content-title-step-connect-mains = נא לחבר את כבל החשמל.
+end-of-line-content-title-step-check-buzzer = בודק את הזמזם...
+end-of-line-content-title-step-check-all-buttons = נא ללחוץ על כל הכפתורים.
+end-of-line-content-title-step-check-ui-screen = נא להקיש על מסך המגע.
+end-of-line-content-title-step-plug-air-test-system = נא לחבר את מערכת בדיקת הריאה.
+end-of-line-content-title-step-reach-maximum-pressure = בודק לחץ מרבי...
+end-of-line-content-title-step-maximum-pressure-reached = הלחץ המרבי הושג. סוגר שסתומים...
+end-of-line-content-title-step-start-leak-measure = בודק דליפות...
+end-of-line-content-title-step-reach-null-pressure = לא זוהתה דליפה. פותח שסתומים...
+end-of-line-content-title-step-confirm-before-oxygen-test = נא לחבר את מערכת בדיקת החמצן.
+end-of-line-content-title-step-start-oxygen-test = בודק חמצן...
+end-of-line-content-title-step-wait-before-blower-long-run = נא לנתק את מערכת בדיקת החמצן.
+end-of-line-content-title-step-start-blower-long-run = בודק את יציבות המפוח...
+
+end-of-line-content-title-failure-expander-not-connected = המרחיב אינו מחובר.
+end-of-line-content-title-failure-battery-deeply-discharged = הסוללה פרוקה לחלוטין.
+end-of-line-content-title-failure-maximum-pressure-not-reached = לא ניתן היה להגיע ללחץ המרבי.
+end-of-line-content-title-failure-leak-too-high = דליפת האוויר גבוהה מדי.
+end-of-line-content-title-failure-minimum-pressure-not-reached = לא ניתן היה להגיע ללחץ המזערי.
+end-of-line-content-title-failure-oxygen-pressure-not-reached = לא ניתן היה להגיע ללחץ החמצן.
+end-of-line-content-title-failure-pressure-not-stable = לחץ המפוח אינו יציב.
+end-of-line-content-title-failure-flow-not-stable = זרימת המפוח אינה יציבה.
+
+end-of-line-content-title-end-confirm = כל הבדיקות עברו בהצלחה!
+end-of-line-content-title-end-display-pressure = מציג פרטי לחץ.
+end-of-line-content-title-end-display-flow = מציג פרטי זרימה.
+
+end-of-line-content-message-step-start = בדיקת סוף הקו תתחיל. הקישו על המשך.
+end-of-line-content-message-step-check-fan = מאווררי הקירור אמורים לפעול. הקישו על המשך.
+end-of-line-content-message-step-test-battery-dead = תקינות הסוללה תיבדק. נא להמתין.
+end-of-line-content-message-step-disconnect-mains = חיבור לחשמל מונע את בדיקת הסוללות. נא לנתק אותו.
+end-of-line-content-message-step-connect-mains = תקינות ספק הכוח תיבדק לאחר החיבור לחשמל.
+end-of-line-content-message-step-check-buzzer = הזמזם אמור לפעול. הקישו לאישור.
+end-of-line-content-message-step-check-all-buttons = יש ללחוץ על כל כפתור בזה אחר זה.
+end-of-line-content-message-step-check-ui-screen = נא להקיש על כפתור ההמשך במסך המגע.
+end-of-line-content-message-step-plug-air-test-system = ריאת הבדיקה אמורה להיות מחוברת. הקישו על המשך.
+end-of-line-content-message-step-reach-maximum-pressure = מעלה לחץ, נא להמתין. אין ללחוץ על ריאת הבדיקה!
+end-of-line-content-message-step-maximum-pressure-reached = הלחץ המרבי הושג. נא להמתין.
+end-of-line-content-message-step-start-leak-measure = מערכת האוויר נבדקת לאיתור דליפות. נא להמתין.
+end-of-line-content-message-step-reach-null-pressure = לא זוהתה דליפה. נא להמתין.
+end-of-line-content-message-step-confirm-before-oxygen-test = פתח החמצן אמור להיות מחובר. הקישו על המשך.
+end-of-line-content-message-step-start-oxygen-test = מערבל החמצן נבדק. נא להמתין.
+end-of-line-content-message-step-wait-before-blower-long-run = יציבות המפוח תיבדק. הקישו על המשך.
+end-of-line-content-message-step-start-blower-long-run = המפוח נבדק. הפעולה תימשך זמן מה.
+
+end-of-line-content-message-failure-expander-not-connected = נא לחבר את ספק הכוח ללוח האם.
+end-of-line-content-message-failure-battery-deeply-discharged = יש להחליף את הסוללה, מאחר שהמתח שלה נמוך מדי.
+end-of-line-content-message-failure-maximum-pressure-not-reached = לא ניתן היה להגיע ללחץ היעד במעגל האוויר.
+end-of-line-content-message-failure-leak-too-high = מערכת האוויר דולפת יותר מדי. נא לבדוק את מעגל האוויר.
+end-of-line-content-message-failure-minimum-pressure-not-reached = ייתכן שהשסתומים לא נפתחו כראוי. נא לבדוק אותם.
+end-of-line-content-message-failure-oxygen-pressure-not-reached = ייתכן שצינורות החמצן חסומים. נא לבדוק אותם.
+end-of-line-content-message-failure-pressure-not-stable = המפוח לא הצליח לשמור על לחץ אוויר יציב.
+end-of-line-content-message-failure-flow-not-stable = המפוח לא הצליח לשמור על זרימת אוויר יציבה.
+
+end-of-line-content-message-end-confirm = מכשיר ההנשמה עבר את כל הבדיקות. הקישו לצפייה בפרטים.
+end-of-line-content-message-end-display-pressure = פרטי בדיקת הלחץ מוצגים במסך הניפוי.
+end-of-line-content-message-end-display-flow = פרטי בדיקת הזרימה מוצגים במסך הניפוי.
+
+end-of-line-content-button-continue = המשך
@@ -3,21 +3,52 @@ telemetry-label-plateau = P(altopiano)
 telemetry-label-expiratory = P(espiratorio)
 telemetry-label-cycles = Cicli / minuti
 telemetry-label-ratio = Durata inspiratoria
-telemetry-label-ratio-details = ispir.-rapp. scadenza
+telemetry-label-ratio-details = { $unit } (ispir.-rapp. scadenza { $ratio })
 telemetry-label-tidal = Volume corrente
 telemetry-label-minute-volume = Volume minuto
+telemetry-label-triggered = Atti attivati
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minuto
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Dati non aggiornati da { $seconds }s
+settings-notice-corrupt = Le impostazioni salvate erano illeggibili e sono state ripristinate (tocca per nascondere)
+settings-notice-invalid = Alcune impostazioni salvate non erano valide e sono state ripristinate (tocca per nascondere)
+
+graph-freeze = Blocca
+graph-resume = Riprendi
+graph-freeze-save = Salva
+graph-event-settings = Impostazioni
+graph-event-alarm = Allarme { $code }
+graph-event-triggered = Attivato
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Impostazioni
+record-event-snooze = Silenzio
+record-event-snooze-expired = Fine silenzio
+record-event-preset = Paziente
+record-event-eol = Conferma
+record-event-weaning-start = Prova
+record-event-weaning-end = Fine prova
+record-event-freeze = Blocco
+record-event-session-start = Sessione
+record-event-session-end = Fine sessione
+record-event-alarm-ack = Allarme conf.
+record-event-modal = Menu
 
 alarms-title = ALLARMI
 alarms-empty = Nessun allarme.
+alarms-resolved = Risolto: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = La pressione di plateau non viene raggiunta
 alarms-message-patient-unplugged = Il paziente è scollegato
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Il volume corrente è troppo basso
 alarms-message-tidal-volume-high = Il volume corrente è troppo alto
 alarms-message-peak-pressure-high = La pressione massima è troppo alta
 alarms-message-expiratory-flow-too-low = Il flusso espiratorio è troppo basso
+alarms-message-telemetry-lost = Telemetria persa (dati non aggiornati)
+alarms-message-driving-pressure-too-high = La pressione di guida è troppo alta
+alarms-message-compliance-too-low = La compliance statica è troppo bassa
+alarms-message-resistance-too-high = La resistenza delle vie aeree è troppo alta
 alarms-message-unknown = Allarme sconosciuto (codice di controllo)
 
 status-unit-stopped = L'unità si è fermata
 status-unit-active = Unità attiva
 status-power-battery = Battery
 status-power-ac = Corrente alternata
+status-snooze-remaining = Silenziato { $remaining }
+status-snooze-escalated = Nuovo allarme { $remaining }
 
 mode-class-pc = Pressione
 mode-class-vc = Volume
@@ -53,6 +90,10 @@ mode-group-general = Generale
 mode-group-alarms = Allarmi
 
 advanced-group-statistics = Statistiche
+advanced-group-mechanics = Meccanica
+advanced-group-weaning = Svezzamento
+advanced-group-session = Sessione
+advanced-group-preferences = Preferenze
 advanced-group-settings = Impostazioni
 
 modal-close = Cerca
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Tasso espiratorio (alto)
 modal-mode-alarm-low-tidal-volume = Volume corrente (basso)
 modal-mode-alarm-high-tidal-volume = Volume corrente (alto)
 modal-mode-alarm-leak = Perdita di volume (alto)
+modal-mode-alarm-leak-measured = Misurata: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Pressione di picco (alto)
+modal-mode-alarms-auto-set = Auto-imposta
 
 modal-advanced-locale = Linguaggio
+modal-advanced-theme = Tema
+modal-advanced-theme-default = Predefinito
+modal-advanced-theme-night = Notte
+modal-advanced-theme-high-contrast = Alto contrasto
 modal-advanced-date = Data
 modal-advanced-time = Tempo
 modal-advanced-timezone = Fuso orario
+modal-advanced-value-maximum = { $value } (max { $maximum })
+
+modal-advanced-weaning-duration = Durata della prova
+modal-advanced-weaning-minutes = { $minutes } min
+modal-advanced-weaning-trial = Prova di respiro
+modal-advanced-weaning-start = Ferma - avviare?
+modal-advanced-weaning-stop = In corso - fermare?
+modal-advanced-weaning-elapsed = Tempo trascorso
+modal-advanced-weaning-rsbi = RSBI
+modal-advanced-weaning-rate = Frequenza spontanea
+modal-advanced-weaning-minute-volume = Volume minuto
+modal-advanced-weaning-result = Risultato
+modal-advanced-weaning-result-ongoing = In corso
+modal-advanced-weaning-result-passed = Superata
+modal-advanced-weaning-result-failed = Fallita
+modal-advanced-weaning-result-aborted = Fermata prima
+modal-advanced-weaning-result-unavailable = Solo PC-VSAI
+
+modal-advanced-session-patient = Paziente
+modal-advanced-session-patient-input = Tocca, poi digita
+modal-advanced-session-started = Iniziata il
+modal-advanced-session-duration = Durata
+modal-advanced-session-events = Allarmi / impostazioni
+modal-advanced-session-record = Registrazione
+modal-advanced-session-state = Sessione
+modal-advanced-session-start = Terminata - avviare?
+modal-advanced-session-end = In corso - terminare?
+modal-advanced-session-new-patient = Nuovo paziente
+modal-advanced-session-new = Avvia
+
+modal-advanced-preferences-graph-seconds = Durata dei grafici
+modal-advanced-preferences-pressure-unit = Unità di pressione
+modal-advanced-preferences-flow-unit = Unità di flusso
+modal-advanced-preferences-volume-unit = Unità di volume
+modal-advanced-preferences-snooze-max = Silenziamento max.
+modal-advanced-preferences-brightness = Luminosità
+modal-advanced-preferences-preset-age = Fascia d'età predefinita
+modal-advanced-preferences-preset-gender = Sesso predefinito
+modal-advanced-preferences-record-directory = Cartella di registrazione
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Cominciando...
 initializing-connected = Inizializzazione in corso...
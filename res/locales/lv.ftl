@@ -3,21 +3,52 @@ telemetry-label-plateau = A(atslābums)
 telemetry-label-expiratory = I(izelpa)
 telemetry-label-cycles = Cikli/minutē
 telemetry-label-ratio = Ieelpošanas ilgums
-telemetry-label-ratio-details = ieel.-izel. attiecība
+telemetry-label-ratio-details = { $unit } (ieel.-izel. attiecība { $ratio })
 telemetry-label-tidal = Plūdmaiņas tilpums
 telemetry-label-minute-volume = Minūtes skaļums
+telemetry-label-triggered = Iniciētās ieelpas
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minutē
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Dati nav atjaunināti kopš { $seconds }s
+settings-notice-corrupt = Saglabātos iestatījumus neizdevās nolasīt, tie tika atiestatīti (pieskarieties, lai paslēptu)
+settings-notice-invalid = Daži saglabātie iestatījumi nebija derīgi un tika atiestatīti (pieskarieties, lai paslēptu)
+
+graph-freeze = Iesaldēt
+graph-resume = Atsākt
+graph-freeze-save = Saglabāt
+graph-event-settings = Iestatījumi
+graph-event-alarm = Trauksme { $code }
+graph-event-triggered = Iniciēts
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Iestatījumi
+record-event-snooze = Klusums
+record-event-snooze-expired = Klusums beidzās
+record-event-preset = Pacients
+record-event-eol = Apstiprināt
+record-event-weaning-start = Tests
+record-event-weaning-end = Testa beigas
+record-event-freeze = Iesaldēts
+record-event-session-start = Sesija
+record-event-session-end = Sesijas beigas
+record-event-alarm-ack = Trauksme apst.
+record-event-modal = Izvēlne
 
 alarms-title = TRAUKSME
 alarms-empty = Nav trauksmes.
+alarms-resolved = Novērsta: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = Plato spiediens nav sasniegts
 alarms-message-patient-unplugged = Pacients ir atvienots no tīkla
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Plūdmaiņu daudzums ir pārāk mazs
 alarms-message-tidal-volume-high = Plūdmaiņu apjoms ir pārāk liels
 alarms-message-peak-pressure-high = Maksimālais spiediens ir pārāk augsts
 alarms-message-expiratory-flow-too-low = Izelpas plūsma ir pārāk maza
+alarms-message-telemetry-lost = Telemetrija zaudēta (dati novecojuši)
+alarms-message-driving-pressure-too-high = Dzinējspiediens ir pārāk augsts
+alarms-message-compliance-too-low = Statiskā atbilstība ir pārāk zema
+alarms-message-resistance-too-high = Elpceļu pretestība ir pārāk augsta
 alarms-message-unknown = Nezināms trauksme (pārbaudes kods)
 
 status-unit-stopped = Ierīce apstājās
 status-unit-active = Ierīce aktīva
 status-power-battery = Akumulators
 status-power-ac = Maiņstrāvas avots
+status-snooze-remaining = Apklusināts { $remaining }
+status-snooze-escalated = Jauna trauksme { $remaining }
 
 mode-class-pc = Spiediens
 mode-class-vc = Skaļums
@@ -53,6 +90,10 @@ mode-group-general = Vispārīgi
 mode-group-alarms = Signāli
 
 advanced-group-statistics = Statistika
+advanced-group-mechanics = Mehānika
+advanced-group-weaning = Atradināšana
+advanced-group-session = Sesija
+advanced-group-preferences = Preferences
 advanced-group-settings = Iestatījumi
 
 modal-close = Aizvērt
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Derīguma termiņš (augsts)
 modal-mode-alarm-low-tidal-volume = Plūdmaiņu daudzums (zems)
 modal-mode-alarm-high-tidal-volume = Plūdmaiņu daudzums (liels)
 modal-mode-alarm-leak = Noplūdes apjoms (liels)
+modal-mode-alarm-leak-measured = Izmērīts: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Maksimālais spiediens (liels)
+modal-mode-alarms-auto-set = Auto-iestatīt
 
 modal-advanced-locale = Valoda
+modal-advanced-theme = Tēma
+modal-advanced-theme-default = Noklusējuma
+modal-advanced-theme-night = Nakts
+modal-advanced-theme-high-contrast = Augsts kontrasts
 modal-advanced-date = Datums
 modal-advanced-time = Laiks
 modal-advanced-timezone = Laika zona
+modal-advanced-value-maximum = { $value } (maks. { $maximum })
+
+modal-advanced-weaning-duration = Testa ilgums
+modal-advanced-weaning-minutes = { $minutes } min
+modal-advanced-weaning-trial = Elpošanas tests
+modal-advanced-weaning-start = Apturēts - sākt?
+modal-advanced-weaning-stop = Notiek - apturēt?
+modal-advanced-weaning-elapsed = Pagājušais laiks
+modal-advanced-weaning-rsbi = RSBI
+modal-advanced-weaning-rate = Spontānā frekvence
+modal-advanced-weaning-minute-volume = Minūtes tilpums
+modal-advanced-weaning-result = Rezultāts
+modal-advanced-weaning-result-ongoing = Notiek
+modal-advanced-weaning-result-passed = Izturēts
+modal-advanced-weaning-result-failed = Neizturēts
+modal-advanced-weaning-result-aborted = Apturēts priekšlaicīgi
+modal-advanced-weaning-result-unavailable = Tikai PC-VSAI
+
+modal-advanced-session-patient = Pacients
+modal-advanced-session-patient-input = Pieskarieties, tad ievadiet
+modal-advanced-session-started = Sākta
+modal-advanced-session-duration = Ilgums
+modal-advanced-session-events = Trauksmes / iestatījumi
+modal-advanced-session-record = Ieraksts
+modal-advanced-session-state = Sesija
+modal-advanced-session-start = Beigta - sākt?
+modal-advanced-session-end = Notiek - beigt?
+modal-advanced-session-new-patient = Jauns pacients
+modal-advanced-session-new = Sākt
+
+modal-advanced-preferences-graph-seconds = Grafiku laika posms
+modal-advanced-preferences-pressure-unit = Spiediena mērvienība
+modal-advanced-preferences-flow-unit = Plūsmas mērvienība
+modal-advanced-preferences-volume-unit = Tilpuma mērvienība
+modal-advanced-preferences-snooze-max = Maks. apklusināšana
+modal-advanced-preferences-brightness = Spilgtums
+modal-advanced-preferences-preset-age = Noklusējuma vecuma grupa
+modal-advanced-preferences-preset-gender = Noklusējuma dzimums
+modal-advanced-preferences-record-directory = Ierakstu mape
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Notiek palaišana...
 initializing-connected = Notiek inicializēšana...
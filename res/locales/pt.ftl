@@ -3,21 +3,52 @@ telemetry-label-plateau = P (platô)
 telemetry-label-expiratory = P (expiratório)
 telemetry-label-cycles = Ciclos/minuto
 telemetry-label-ratio = Duração inspiratória
-telemetry-label-ratio-details = taxa de insp-exp.
+telemetry-label-ratio-details = { $unit } (taxa de insp-exp. { $ratio })
 telemetry-label-tidal = Volume corr.
 telemetry-label-minute-volume = Volume minuto
+telemetry-label-triggered = Ciclos disparados
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = mbar
 telemetry-unit-lpm = L/min
 telemetry-unit-mlpm = mL/min
+telemetry-unit-mlps = mL/s
 telemetry-unit-per-minute = /minuto
 telemetry-unit-milliliters = mL
+telemetry-unit-liters = L
 telemetry-unit-milliseconds = ms
 telemetry-unit-centimeters = cm
+telemetry-unit-percent = %
+
+telemetry-stale-since = Dados não atualizados há { $seconds }s
+settings-notice-corrupt = As definições guardadas não puderam ser lidas e foram repostas (toque para ocultar)
+settings-notice-invalid = Algumas definições guardadas não eram válidas e foram repostas (toque para ocultar)
+
+graph-freeze = Congelar
+graph-resume = Retomar
+graph-freeze-save = Guardar
+graph-event-settings = Ajustes
+graph-event-alarm = Alarme { $code }
+graph-event-triggered = Disparado
+graph-cursor-values = { $time }s · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Definições
+record-event-snooze = Silêncio
+record-event-snooze-expired = Fim do silêncio
+record-event-preset = Paciente
+record-event-eol = Confirmação
+record-event-weaning-start = Teste
+record-event-weaning-end = Fim teste
+record-event-freeze = Congelado
+record-event-session-start = Sessão
+record-event-session-end = Fim da sessão
+record-event-alarm-ack = Alarme conf.
+record-event-modal = Menu
 
 alarms-title = ALARMES
 alarms-empty = Sem alarme.
+alarms-resolved = Resolvido: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = A pressão de platô não foi atingida
 alarms-message-patient-unplugged = Paciente está desconectado
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = O volume corrente está muito baixo
 alarms-message-tidal-volume-high = O volume corrente está muito alto
 alarms-message-peak-pressure-high = A pressão de pico é muito alta
 alarms-message-expiratory-flow-too-low = O fluxo expiratório está muito baixo
+alarms-message-telemetry-lost = Telemetria perdida (dados desatualizados)
+alarms-message-driving-pressure-too-high = A pressão de distensão está muito alta
+alarms-message-compliance-too-low = A complacência estática está muito baixa
+alarms-message-resistance-too-high = A resistência das vias aéreas está muito alta
 alarms-message-unknown = Alarme desconhecido (código de verificação)
 
 status-unit-stopped = Unidade parada
 status-unit-active = Unidade ativa
 status-power-battery = Bateria
 status-power-ac = Alimentação
+status-snooze-remaining = Silenciado { $remaining }
+status-snooze-escalated = Novo alarme { $remaining }
 
 mode-class-pc = Pressão
 mode-class-vc = Volume
@@ -53,6 +90,10 @@ mode-group-general = Geral
 mode-group-alarms = Alarmes
 
 advanced-group-statistics = Estatisticas
+advanced-group-mechanics = Mecânica
+advanced-group-weaning = Desmame
+advanced-group-session = Sessão
+advanced-group-preferences = Preferências
 advanced-group-settings = Configurações
 
 modal-close = Fechar
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Taxa expiratória (alta)
 modal-mode-alarm-low-tidal-volume = Volume corrente (baixo)
 modal-mode-alarm-high-tidal-volume = Volume corrente (alto)
 modal-mode-alarm-leak = Volume de vazamento (alto)
+modal-mode-alarm-leak-measured = Medida: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Pressão de pico (alto)
+modal-mode-alarms-auto-set = Auto-ajustar
 
 modal-advanced-locale = Língua
+modal-advanced-theme = Tema
+modal-advanced-theme-default = Padrão
+modal-advanced-theme-night = Noite
+modal-advanced-theme-high-contrast = Alto contraste
 modal-advanced-date = Encontro
 modal-advanced-time = Tempo
 modal-advanced-timezone = Fuso horário
+modal-advanced-value-maximum = { $value } (máx. { $maximum })
+
+modal-advanced-weaning-duration = Duração do teste
+modal-advanced-weaning-minutes = { $minutes } min
+modal-advanced-weaning-trial = Teste respiratório
+modal-advanced-weaning-start = Parado - iniciar?
+modal-advanced-weaning-stop = Em curso - parar?
+modal-advanced-weaning-elapsed = Tempo decorrido
+modal-advanced-weaning-rsbi = IRRS
+modal-advanced-weaning-rate = Frequência espontânea
+modal-advanced-weaning-minute-volume = Volume minuto
+modal-advanced-weaning-result = Resultado
+modal-advanced-weaning-result-ongoing = Em curso
+modal-advanced-weaning-result-passed = Aprovado
+modal-advanced-weaning-result-failed = Reprovado
+modal-advanced-weaning-result-aborted = Parado antes
+modal-advanced-weaning-result-unavailable = Apenas PC-VSAI
+
+modal-advanced-session-patient = Paciente
+modal-advanced-session-patient-input = Tocar e digitar
+modal-advanced-session-started = Iniciada em
+modal-advanced-session-duration = Duração
+modal-advanced-session-events = Alarmes / ajustes
+modal-advanced-session-record = Gravação
+modal-advanced-session-state = Sessão
+modal-advanced-session-start = Terminada - iniciar?
+modal-advanced-session-end = Em curso - terminar?
+modal-advanced-session-new-patient = Novo paciente
+modal-advanced-session-new = Iniciar
+
+modal-advanced-preferences-graph-seconds = Duração dos gráficos
+modal-advanced-preferences-pressure-unit = Unidade de pressão
+modal-advanced-preferences-flow-unit = Unidade de fluxo
+modal-advanced-preferences-volume-unit = Unidade de volume
+modal-advanced-preferences-snooze-max = Silêncio máximo
+modal-advanced-preferences-brightness = Brilho
+modal-advanced-preferences-preset-age = Faixa etária padrão
+modal-advanced-preferences-preset-gender = Sexo padrão
+modal-advanced-preferences-record-directory = Pasta de gravação
+modal-advanced-preferences-seconds = { $seconds } s
 
 initializing-connecting = Começando...
 initializing-connected = Inicializando...
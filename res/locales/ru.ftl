@@ -3,21 +3,52 @@ telemetry-label-plateau = Давление плато
 telemetry-label-expiratory = ПДКВ
 telemetry-label-cycles = Циклов в минуту
 telemetry-label-ratio = Продолжительность вдоха
-telemetry-label-ratio-details = соотн. вдох-выдох
+telemetry-label-ratio-details = { $unit } (соотн. вдох-выдох { $ratio })
 telemetry-label-tidal = Дыхательный объем
 telemetry-label-minute-volume = Минутный объем
+telemetry-label-triggered = Вызванные вдохи
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = мбар
 telemetry-unit-lpm = Л/мин
 telemetry-unit-mlpm = мл/мин
+telemetry-unit-mlps = мл/с
 telemetry-unit-per-minute = /минута
 telemetry-unit-milliliters = миллилитров
+telemetry-unit-liters = литров
 telemetry-unit-milliseconds = миллисекунды
 telemetry-unit-centimeters = см
+telemetry-unit-percent = %
+
+telemetry-stale-since = Данные не обновлялись { $seconds } с
+settings-notice-corrupt = Сохранённые настройки не удалось прочитать, они сброшены (нажмите, чтобы скрыть)
+settings-notice-invalid = Некоторые сохранённые настройки были неверны и сброшены (нажмите, чтобы скрыть)
+
+graph-freeze = Стоп
+graph-resume = Далее
+graph-freeze-save = Сохранить
+graph-event-settings = Настройки
+graph-event-alarm = Тревога { $code }
+graph-event-triggered = Вызван
+graph-cursor-values = { $time } с · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Настройки
+record-event-snooze = Без звука
+record-event-snooze-expired = Конец режима без звука
+record-event-preset = Пациент
+record-event-eol = Подтверждение
+record-event-weaning-start = Тест
+record-event-weaning-end = Конец теста
+record-event-freeze = Стоп-кадр
+record-event-session-start = Сеанс
+record-event-session-end = Конец сеанса
+record-event-alarm-ack = Тревога подтв.
+record-event-modal = Меню
 
 alarms-title = ТРЕВОГИ
 alarms-empty = Тревоги нет.
+alarms-resolved = Устранено: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = Давление плато не достигается
 alarms-message-patient-unplugged = Пациент отключен от сети
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Дыхательный объем слишко
 alarms-message-tidal-volume-high = Дыхательный объем слишком высокий
 alarms-message-peak-pressure-high = Пиковое давление слишком высокое
 alarms-message-expiratory-flow-too-low = Слишком низкий поток выдоха
+alarms-message-telemetry-lost = Телеметрия потеряна (данные устарели)
+alarms-message-driving-pressure-too-high = Движущее давление слишком высокое
+alarms-message-compliance-too-low = Статический комплаенс слишком низкий
+alarms-message-resistance-too-high = Сопротивление дыхательных путей слишком высокое
 alarms-message-unknown = Неизвестный сигнал тревоги
 
 status-unit-stopped = Остановлен
 status-unit-active = Активный
 status-power-battery = Батарея
 status-power-ac = Кабель
+status-snooze-remaining = Без звука { $remaining }
+status-snooze-escalated = Новая тревога { $remaining }
 
 mode-class-pc = Давление
 mode-class-vc = Объем
@@ -53,6 +90,10 @@ mode-group-general = Генеральный
 mode-group-alarms = Будильники
 
 advanced-group-statistics = Статистика
+advanced-group-mechanics = Механика
+advanced-group-weaning = Отлучение
+advanced-group-session = Сеанс
+advanced-group-preferences = Предпочтения
 advanced-group-settings = Настройки
 
 modal-close = Закрыть
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Частота выдоха (высок
 modal-mode-alarm-low-tidal-volume = Дыхательный объем (низкий)
 modal-mode-alarm-high-tidal-volume = Дыхательный объем (высокий)
 modal-mode-alarm-leak = Объем утечки (высокий)
+modal-mode-alarm-leak-measured = Измерено: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Пиковое давление (высокий)
+modal-mode-alarms-auto-set = Авто
 
 modal-advanced-locale = Язык
+modal-advanced-theme = Тема
+modal-advanced-theme-default = По умолчанию
+modal-advanced-theme-night = Ночная
+modal-advanced-theme-high-contrast = Высокий контраст
 modal-advanced-date = Свидание
 modal-advanced-time = Время
 modal-advanced-timezone = Часовой пояс
+modal-advanced-value-maximum = { $value } (макс. { $maximum })
+
+modal-advanced-weaning-duration = Длительность теста
+modal-advanced-weaning-minutes = { $minutes } мин
+modal-advanced-weaning-trial = Тест дыхания
+modal-advanced-weaning-start = Остановлен - начать?
+modal-advanced-weaning-stop = Идёт - остановить?
+modal-advanced-weaning-elapsed = Прошло времени
+modal-advanced-weaning-rsbi = ИЧПД
+modal-advanced-weaning-rate = Спонтанная частота
+modal-advanced-weaning-minute-volume = Минутный объём
+modal-advanced-weaning-result = Результат
+modal-advanced-weaning-result-ongoing = Идёт
+modal-advanced-weaning-result-passed = Пройден
+modal-advanced-weaning-result-failed = Не пройден
+modal-advanced-weaning-result-aborted = Остановлен досрочно
+modal-advanced-weaning-result-unavailable = Только PC-VSAI
+
+modal-advanced-session-patient = Пациент
+modal-advanced-session-patient-input = Нажмите и введите
+modal-advanced-session-started = Начат
+modal-advanced-session-duration = Длительность
+modal-advanced-session-events = Тревоги / настройки
+modal-advanced-session-record = Запись
+modal-advanced-session-state = Сеанс
+modal-advanced-session-start = Завершён - начать?
+modal-advanced-session-end = Идёт - завершить?
+modal-advanced-session-new-patient = Новый пациент
+modal-advanced-session-new = Начать
+
+modal-advanced-preferences-graph-seconds = Период графиков
+modal-advanced-preferences-pressure-unit = Единица давления
+modal-advanced-preferences-flow-unit = Единица потока
+modal-advanced-preferences-volume-unit = Единица объёма
+modal-advanced-preferences-snooze-max = Макс. отключение звука
+modal-advanced-preferences-brightness = Яркость
+modal-advanced-preferences-preset-age = Возрастная группа по умолчанию
+modal-advanced-preferences-preset-gender = Пол по умолчанию
+modal-advanced-preferences-record-directory = Папка записей
+modal-advanced-preferences-seconds = { $seconds } с
 
 initializing-connecting = Начиная...
 initializing-connected = Инициализация...
@@ -3,21 +3,52 @@ telemetry-label-plateau = Тиск плато
 telemetry-label-expiratory = ПТКВ
 telemetry-label-cycles = Циклів в хвилину
 telemetry-label-ratio = Тривалість натхнення
-telemetry-label-ratio-details = співвідн. вдих-видих
+telemetry-label-ratio-details = { $unit } (співвідн. вдих-видих { $ratio })
 telemetry-label-tidal = Дихальний обсяг
 telemetry-label-minute-volume = Хвилинна гучність
+telemetry-label-triggered = Ініційовані вдихи
 
 telemetry-unit-cmh2o = cmH2O
-telemetry-unit-mmh2o = mmH2O
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = мбар
 telemetry-unit-lpm = Л/хв
 telemetry-unit-mlpm = мл/хв
+telemetry-unit-mlps = мл/с
 telemetry-unit-per-minute = /хвилина
 telemetry-unit-milliliters = мілілітрів
+telemetry-unit-liters = літрів
 telemetry-unit-milliseconds = мілісекунд
 telemetry-unit-centimeters = см
+telemetry-unit-percent = %
+
+telemetry-stale-since = Дані не оновлювалися { $seconds } с
+settings-notice-corrupt = Збережені налаштування не вдалося прочитати, їх скинуто (торкніться, щоб сховати)
+settings-notice-invalid = Деякі збережені налаштування були недійсні, їх скинуто (торкніться, щоб сховати)
+
+graph-freeze = Стоп
+graph-resume = Далі
+graph-freeze-save = Зберегти
+graph-event-settings = Налаштування
+graph-event-alarm = Тривога { $code }
+graph-event-triggered = Ініційовано
+graph-cursor-values = { $time } с · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = Налаштування
+record-event-snooze = Без звуку
+record-event-snooze-expired = Кінець режиму без звуку
+record-event-preset = Пацієнт
+record-event-eol = Підтвердження
+record-event-weaning-start = Тест
+record-event-weaning-end = Кінець тесту
+record-event-freeze = Стоп-кадр
+record-event-session-start = Сеанс
+record-event-session-end = Кінець сеансу
+record-event-alarm-ack = Тривога підтв.
+record-event-modal = Меню
 
 alarms-title = ТРИВОГИ
 alarms-empty = Немає сигналізації.
+alarms-resolved = Усунуто: { $alarm }
 
 alarms-message-plateau-pressure-not-reached = Тиск на плато не досягається
 alarms-message-patient-unplugged = Пацієнт відключений від мережі
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = Дихальний об'єм занадто н
 alarms-message-tidal-volume-high = Дихальний об’єм занадто високий
 alarms-message-peak-pressure-high = Піковий тиск занадто високий
 alarms-message-expiratory-flow-too-low = Потік на видиху занадто низький
+alarms-message-telemetry-lost = Телеметрію втрачено (дані застаріли)
+alarms-message-driving-pressure-too-high = Рушійний тиск занадто високий
+alarms-message-compliance-too-low = Статичний комплаєнс занадто низький
+alarms-message-resistance-too-high = Опір дихальних шляхів занадто високий
 alarms-message-unknown = Невідомий сигнал тривоги
 
 status-unit-stopped = Зупинився
 status-unit-active = Активний
 status-power-battery = Батарея
 status-power-ac = Зовнішнє живлення
+status-snooze-remaining = Без звуку { $remaining }
+status-snooze-escalated = Нова тривога { $remaining }
 
 mode-class-pc = Тиск
 mode-class-vc = Гучність
@@ -53,6 +90,10 @@ mode-group-general = Загальні
 mode-group-alarms = Сигналізація
 
 advanced-group-statistics = Статистика
+advanced-group-mechanics = Механіка
+advanced-group-weaning = Відлучення
+advanced-group-session = Сеанс
+advanced-group-preferences = Уподобання
 advanced-group-settings = Налаштування
 
 modal-close = Закрити
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = Частота видиху (висок
 modal-mode-alarm-low-tidal-volume = Дихальний об'єм (низький)
 modal-mode-alarm-high-tidal-volume = Дихальний об'єм (високий)
 modal-mode-alarm-leak = Об'єм витоку (високий)
+modal-mode-alarm-leak-measured = Виміряно: { $leak } { $unit } ({ $percent }%)
 modal-mode-alarm-peak-pressure = Піковий тиск (високий)
+modal-mode-alarms-auto-set = Авто
 
 modal-advanced-locale = Мова
+modal-advanced-theme = Тема
+modal-advanced-theme-default = За замовчуванням
+modal-advanced-theme-night = Нічна
+modal-advanced-theme-high-contrast = Високий контраст
 modal-advanced-date = Дата
 modal-advanced-time = Час
 modal-advanced-timezone = Часовий пояс
+modal-advanced-value-maximum = { $value } (макс. { $maximum })
+
+modal-advanced-weaning-duration = Тривалість тесту
+modal-advanced-weaning-minutes = { $minutes } хв
+modal-advanced-weaning-trial = Тест дихання
+modal-advanced-weaning-start = Зупинено - почати?
+modal-advanced-weaning-stop = Триває - зупинити?
+modal-advanced-weaning-elapsed = Минуло часу
+modal-advanced-weaning-rsbi = ІЧПД
+modal-advanced-weaning-rate = Спонтанна частота
+modal-advanced-weaning-minute-volume = Хвилинний об'єм
+modal-advanced-weaning-result = Результат
+modal-advanced-weaning-result-ongoing = Триває
+modal-advanced-weaning-result-passed = Пройдено
+modal-advanced-weaning-result-failed = Не пройдено
+modal-advanced-weaning-result-aborted = Зупинено достроково
+modal-advanced-weaning-result-unavailable = Лише PC-VSAI
+
+modal-advanced-session-patient = Пацієнт
+modal-advanced-session-patient-input = Торкніться і введіть
+modal-advanced-session-started = Розпочато
+modal-advanced-session-duration = Тривалість
+modal-advanced-session-events = Тривоги / налаштування
+modal-advanced-session-record = Запис
+modal-advanced-session-state = Сеанс
+modal-advanced-session-start = Завершено - почати?
+modal-advanced-session-end = Триває - завершити?
+modal-advanced-session-new-patient = Новий пацієнт
+modal-advanced-session-new = Почати
+
+modal-advanced-preferences-graph-seconds = Період графіків
+modal-advanced-preferences-pressure-unit = Одиниця тиску
+modal-advanced-preferences-flow-unit = Одиниця потоку
+modal-advanced-preferences-volume-unit = Одиниця об'єму
+modal-advanced-preferences-snooze-max = Макс. вимкнення звуку
+modal-advanced-preferences-brightness = Яскравість
+modal-advanced-preferences-preset-age = Вікова група за замовчуванням
+modal-advanced-preferences-preset-gender = Стать за замовчуванням
+modal-advanced-preferences-record-directory = Тека записів
+modal-advanced-preferences-seconds = { $seconds } с
 
 initializing-connecting = Запуск...
 initializing-connected = Ініціалізація...
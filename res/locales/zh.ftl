@@ -3,21 +3,52 @@ telemetry-label-plateau = P（高原）
 telemetry-label-expiratory = P（呼气）
 telemetry-label-cycles = 周期/分钟
 telemetry-label-ratio = 吸气时间
-telemetry-label-ratio-details = 吸气呼气比
+telemetry-label-ratio-details = { $unit } (吸气呼气比 { $ratio })
 telemetry-label-tidal = 潮量
 telemetry-label-minute-volume = 分钟音量
+telemetry-label-triggered = 触发呼吸
 
 telemetry-unit-cmh2o = 厘米水
-telemetry-unit-mmh2o = 毫米水柱
+telemetry-unit-hpa = hPa
+telemetry-unit-mbar = 毫巴
 telemetry-unit-lpm = 升/分
 telemetry-unit-mlpm = 毫升/分钟
+telemetry-unit-mlps = 毫升/秒
 telemetry-unit-per-minute = /分钟
 telemetry-unit-milliliters = 毫升
+telemetry-unit-liters = 升
 telemetry-unit-milliseconds = 毫秒
 telemetry-unit-centimeters = 厘米
+telemetry-unit-percent = %
+
+telemetry-stale-since = 数据未更新，已持续 { $seconds } 秒
+settings-notice-corrupt = 无法读取已保存的设置，已重置（点击关闭）
+settings-notice-invalid = 部分已保存的设置无效，已重置（点击关闭）
+
+graph-freeze = 冻结
+graph-resume = 恢复
+graph-freeze-save = 保存
+graph-event-settings = 设置
+graph-event-alarm = 警报 { $code }
+graph-event-triggered = 触发
+graph-cursor-values = { $time } 秒 · { $pressure } { $pressure-unit } · { $flow } { $flow-unit }
+
+record-event-settings = 设置
+record-event-snooze = 静音
+record-event-snooze-expired = 静音结束
+record-event-preset = 患者
+record-event-eol = 确认
+record-event-weaning-start = 试验
+record-event-weaning-end = 试验结束
+record-event-freeze = 冻结
+record-event-session-start = 会话
+record-event-session-end = 会话结束
+record-event-alarm-ack = 警报确认
+record-event-modal = 菜单
 
 alarms-title = 警报
 alarms-empty = 没有警报
+alarms-resolved = 已解除：{ $alarm }
 
 alarms-message-plateau-pressure-not-reached = 没有达到高原压力
 alarms-message-patient-unplugged = 拔掉病人电源
@@ -37,12 +68,18 @@ alarms-message-tidal-volume-low = 潮气量太低
 alarms-message-tidal-volume-high = 潮气量过高
 alarms-message-peak-pressure-high = 峰值压力太高
 alarms-message-expiratory-flow-too-low = 呼气量过低
+alarms-message-telemetry-lost = 遥测丢失（数据未更新）
+alarms-message-driving-pressure-too-high = 驱动压过高
+alarms-message-compliance-too-low = 静态顺应性过低
+alarms-message-resistance-too-high = 气道阻力过高
 alarms-message-unknown = 未知警报（校验码）
 
 status-unit-stopped = 单位停止
 status-unit-active = 单位活跃
 status-power-battery = 电池
 status-power-ac = 交流电
+status-snooze-remaining = 已静音 { $remaining }
+status-snooze-escalated = 新警报 { $remaining }
 
 mode-class-pc = 压力
 mode-class-vc = 卷
@@ -53,6 +90,10 @@ mode-group-general = 一般
 mode-group-alarms = 警报器
 
 advanced-group-statistics = 统计
+advanced-group-mechanics = 呼吸力学
+advanced-group-weaning = 撤机
+advanced-group-session = 会话
+advanced-group-preferences = 偏好
 advanced-group-settings = 设定值
 
 modal-close = 关
@@ -98,12 +139,58 @@ modal-mode-alarm-high-respiratory-rate = 呼气率（高）
 modal-mode-alarm-low-tidal-volume = 潮气量（低）
 modal-mode-alarm-high-tidal-volume = 潮气量（高）
 modal-mode-alarm-leak = 泄漏量（高）
+modal-mode-alarm-leak-measured = 测量值：{ $leak } { $unit }（{ $percent }%）
 modal-mode-alarm-peak-pressure = 峰值压力（高）
+modal-mode-alarms-auto-set = 自动设置
 
 modal-advanced-locale = 语言
+modal-advanced-theme = 主题
+modal-advanced-theme-default = 默认
+modal-advanced-theme-night = 夜间
+modal-advanced-theme-high-contrast = 高对比度
 modal-advanced-date = 日期
 modal-advanced-time = 时间
 modal-advanced-timezone = 时区
+modal-advanced-value-maximum = { $value }（最大 { $maximum }）
+
+modal-advanced-weaning-duration = 试验时长
+modal-advanced-weaning-minutes = { $minutes } 分钟
+modal-advanced-weaning-trial = 自主呼吸试验
+modal-advanced-weaning-start = 已停止 - 开始？
+modal-advanced-weaning-stop = 进行中 - 停止？
+modal-advanced-weaning-elapsed = 已用时间
+modal-advanced-weaning-rsbi = 浅快呼吸指数
+modal-advanced-weaning-rate = 自主呼吸频率
+modal-advanced-weaning-minute-volume = 分钟通气量
+modal-advanced-weaning-result = 结果
+modal-advanced-weaning-result-ongoing = 进行中
+modal-advanced-weaning-result-passed = 通过
+modal-advanced-weaning-result-failed = 未通过
+modal-advanced-weaning-result-aborted = 提前停止
+modal-advanced-weaning-result-unavailable = 仅限 PC-VSAI
+
+modal-advanced-session-patient = 患者
+modal-advanced-session-patient-input = 点击后输入
+modal-advanced-session-started = 开始时间
+modal-advanced-session-duration = 持续时间
+modal-advanced-session-events = 警报 / 设置
+modal-advanced-session-record = 记录文件
+modal-advanced-session-state = 会话
+modal-advanced-session-start = 已结束 - 开始？
+modal-advanced-session-end = 进行中 - 结束？
+modal-advanced-session-new-patient = 新患者
+modal-advanced-session-new = 开始
+
+modal-advanced-preferences-graph-seconds = 图表时间范围
+modal-advanced-preferences-pressure-unit = 压力单位
+modal-advanced-preferences-flow-unit = 流量单位
+modal-advanced-preferences-volume-unit = 容量单位
+modal-advanced-preferences-snooze-max = 最长静音
+modal-advanced-preferences-brightness = 亮度
+modal-advanced-preferences-preset-age = 默认年龄组
+modal-advanced-preferences-preset-gender = 默认性别
+modal-advanced-preferences-record-directory = 记录目录
+modal-advanced-preferences-seconds = { $seconds } 秒
 
 initializing-connecting = 启动...
 initializing-connected = 初始化中...
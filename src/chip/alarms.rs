@@ -0,0 +1,12 @@
+// MakAir Control UI
+//
+// Copyright: 2020, Makers For Life
+// License: Public Domain License
+
+// Notice: those alarm codes are raised by the Control UI itself, and not by the firmware. They \
+//   use a code range that is never used by the firmware, so that they can be merged with \
+//   firmware alarms without any collision.
+pub const ALARM_CODE_UI_TELEMETRY_LOST: u8 = 101;
+pub const ALARM_CODE_UI_DRIVING_PRESSURE_HIGH: u8 = 102;
+pub const ALARM_CODE_UI_COMPLIANCE_LOW: u8 = 103;
+pub const ALARM_CODE_UI_RESISTANCE_HIGH: u8 = 104;
@@ -0,0 +1,268 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::collections::VecDeque;
+
+use makair_telemetry::structures::{DataSnapshot, Phase, VentilationMode};
+
+use crate::config::environment::*;
+
+#[derive(Debug, Default)]
+pub struct ChipBreaths {
+    // Breath types over the last cycles (true if the breath was triggered by the patient)
+    cycles: VecDeque<bool>,
+    last_inhaling: Option<bool>,
+    last_cycle_start: Option<u64>,
+    last_expiratory_net_flow: Option<i32>,
+}
+
+impl ChipBreaths {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn update_from_data(
+        &mut self,
+        snapshot: &DataSnapshot,
+        mode: VentilationMode,
+        cpm_command: u8,
+    ) -> Option<bool> {
+        self.update_from_flows(
+            snapshot.systick,
+            snapshot.phase == Phase::Inhalation,
+            (snapshot.inspiratory_flow, snapshot.expiratory_flow),
+            mode,
+            cpm_command,
+        )
+    }
+
+    pub fn triggered_percent(&self) -> Option<f64> {
+        if self.cycles.is_empty() {
+            None
+        } else {
+            let triggered = self.cycles.iter().filter(|triggered| **triggered).count();
+
+            Some(triggered as f64 * 100.0 / self.cycles.len() as f64)
+        }
+    }
+
+    fn update_from_flows(
+        &mut self,
+        systick: u64,
+        inhaling: bool,
+        flows: (Option<i16>, Option<i16>),
+        mode: VentilationMode,
+        cpm_command: u8,
+    ) -> Option<bool> {
+        let cycle_started = self.last_inhaling == Some(false) && inhaling;
+
+        self.last_inhaling = Some(inhaling);
+
+        // Track net flow while exhaling (in cL/min), as a patient effort right before the cycle \
+        //   starts draws air back towards the patient
+        if !inhaling {
+            self.last_expiratory_net_flow = match flows {
+                (Some(inspiratory_flow), Some(expiratory_flow)) => {
+                    Some(inspiratory_flow as i32 - expiratory_flow as i32)
+                }
+                _ => None,
+            };
+        }
+
+        if !cycle_started {
+            return None;
+        }
+
+        let last_cycle_start = self.last_cycle_start.replace(systick);
+
+        // Only assist modes may have breaths triggered by the patient
+        if !matches!(
+            mode,
+            VentilationMode::PC_AC | VentilationMode::VC_AC | VentilationMode::PC_VSAI
+        ) {
+            self.cycles.clear();
+
+            return None;
+        }
+
+        // A cycle that started before the mandatory cycle period elapsed can only have been \
+        //   triggered by the patient, provided a patient effort was seen at the end of the \
+        //   exhalation (if flow is not known, the cycle timing alone is used)
+        // Notice: systicks are in microseconds.
+        let triggered = match (last_cycle_start, cpm_command) {
+            (Some(last_cycle_start), cpm_command) if cpm_command > 0 => {
+                let mandatory_period = 60_000_000.0 / cpm_command as f64;
+                let elapsed = systick.saturating_sub(last_cycle_start) as f64;
+
+                elapsed < mandatory_period * (1.0 - BREATHS_TRIGGERED_PERIOD_TOLERANCE)
+                    && self
+                        .last_expiratory_net_flow
+                        .map(|net_flow| net_flow >= 0)
+                        .unwrap_or(true)
+            }
+            _ => return None,
+        };
+
+        if self.cycles.len() >= BREATHS_TRIGGERED_CYCLES {
+            self.cycles.pop_back();
+        }
+
+        self.cycles.push_front(triggered);
+
+        Some(triggered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // At 20 cycles per minute, the mandatory cycle period is 3 seconds
+    const CPM_COMMAND: u8 = 20;
+    const MANDATORY_PERIOD: u64 = 3_000_000;
+
+    fn start_cycle(
+        breaths: &mut ChipBreaths,
+        systick: u64,
+        expiratory_flows: (Option<i16>, Option<i16>),
+        mode: VentilationMode,
+    ) -> Option<bool> {
+        // Exhale right before the cycle starts, then start inhaling
+        breaths.update_from_flows(
+            systick - 100_000,
+            false,
+            expiratory_flows,
+            mode,
+            CPM_COMMAND,
+        );
+        breaths.update_from_flows(systick, true, (Some(3000), Some(0)), mode, CPM_COMMAND)
+    }
+
+    #[test]
+    fn marks_mandatory_cycle() {
+        let mut breaths = ChipBreaths::new();
+
+        // The first cycle cannot be timed, thus it is not marked
+        assert_eq!(
+            start_cycle(
+                &mut breaths,
+                1_000_000,
+                (Some(0), Some(0)),
+                VentilationMode::PC_AC
+            ),
+            None
+        );
+
+        // Starting after the mandatory period elapsed means that the cycle is mandatory
+        assert_eq!(
+            start_cycle(
+                &mut breaths,
+                1_000_000 + MANDATORY_PERIOD,
+                (Some(0), Some(0)),
+                VentilationMode::PC_AC
+            ),
+            Some(false)
+        );
+
+        assert_eq!(breaths.triggered_percent(), Some(0.0));
+    }
+
+    #[test]
+    fn marks_patient_triggered_cycle() {
+        let mut breaths = ChipBreaths::new();
+
+        start_cycle(
+            &mut breaths,
+            1_000_000,
+            (Some(0), Some(0)),
+            VentilationMode::PC_AC,
+        );
+
+        // Starting early with a patient effort (air drawn towards the patient) means that the \
+        //   cycle was triggered by the patient
+        assert_eq!(
+            start_cycle(
+                &mut breaths,
+                3_000_000,
+                (Some(200), Some(50)),
+                VentilationMode::PC_AC
+            ),
+            Some(true)
+        );
+
+        // Starting early without a patient effort is not enough
+        assert_eq!(
+            start_cycle(
+                &mut breaths,
+                5_000_000,
+                (Some(0), Some(500)),
+                VentilationMode::PC_AC
+            ),
+            Some(false)
+        );
+
+        assert_eq!(breaths.triggered_percent(), Some(50.0));
+
+        // Inhaling snapshots within a cycle do not start a new cycle
+        assert_eq!(
+            breaths.update_from_flows(
+                5_100_000,
+                true,
+                (Some(3000), Some(0)),
+                VentilationMode::PC_AC,
+                CPM_COMMAND
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn resets_on_mode_switch() {
+        let mut breaths = ChipBreaths::new();
+
+        start_cycle(
+            &mut breaths,
+            1_000_000,
+            (Some(0), Some(0)),
+            VentilationMode::PC_AC,
+        );
+        start_cycle(
+            &mut breaths,
+            3_000_000,
+            (Some(200), Some(50)),
+            VentilationMode::PC_AC,
+        );
+
+        assert_eq!(breaths.triggered_percent(), Some(100.0));
+
+        // Breaths cannot be triggered by the patient in controlled modes
+        assert_eq!(
+            start_cycle(
+                &mut breaths,
+                5_000_000,
+                (Some(200), Some(50)),
+                VentilationMode::PC_CMV
+            ),
+            None
+        );
+
+        assert_eq!(breaths.triggered_percent(), None);
+
+        // Back to an assist mode, cycles get marked again
+        assert_eq!(
+            start_cycle(
+                &mut breaths,
+                5_000_000 + MANDATORY_PERIOD,
+                (Some(0), Some(0)),
+                VentilationMode::VC_AC
+            ),
+            Some(false)
+        );
+    }
+}
@@ -0,0 +1,79 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::chip::{ChipData, ChipDataPoints};
+use crate::config::environment::*;
+use crate::APP_SETTINGS;
+
+pub struct ChipFreeze {
+    pub boot_time: DateTime<Utc>,
+    pub last_tick: u64,
+    pub data_pressure: ChipData,
+    pub data_flow: ChipData,
+    // Graph time range at the time of the freeze, in seconds
+    pub seconds: i64,
+    // Cursor position over the graph time range (from 0.0 for oldest, to 1.0 for newest)
+    pub cursor: f64,
+}
+
+impl ChipFreeze {
+    pub fn new(
+        boot_time: DateTime<Utc>,
+        last_tick: u64,
+        data_pressure: &ChipData,
+        data_flow: &ChipData,
+    ) -> Self {
+        ChipFreeze {
+            boot_time,
+            last_tick,
+            data_pressure: data_pressure.clone(),
+            data_flow: data_flow.clone(),
+            seconds: APP_SETTINGS.read().unwrap().graph_seconds as i64,
+            cursor: 1.0,
+        }
+    }
+
+    pub fn move_cursor(&mut self, cursor: f64) {
+        self.cursor = cursor.max(0.0).min(1.0);
+    }
+
+    pub fn first_tick(&self) -> u64 {
+        self.last_tick
+            .saturating_sub(self.seconds as u64 * 1_000_000)
+    }
+
+    pub fn cursor_tick(&self) -> u64 {
+        let first_tick = self.first_tick();
+
+        first_tick + ((self.last_tick - first_tick) as f64 * self.cursor) as u64
+    }
+
+    pub fn cursor_offset_seconds(&self) -> f64 {
+        // Time offset from the most recent frozen point (thus, always negative)
+        -((self.last_tick - self.cursor_tick()) as f64 / 1_000_000.0)
+    }
+
+    pub fn cursor_values(&self) -> (Option<i16>, Option<i16>) {
+        let cursor_time = self.boot_time + Duration::microseconds(self.cursor_tick() as i64);
+
+        (
+            Self::nearest_value(&self.data_pressure.points, cursor_time),
+            Self::nearest_value(&self.data_flow.points, cursor_time),
+        )
+    }
+
+    fn nearest_value(points: &ChipDataPoints, time: DateTime<Utc>) -> Option<i16> {
+        // Pick the point that is the closest to the cursor, if it is close enough (there might \
+        //   be holes in data, in which case no value can be read at the cursor)
+        points
+            .iter()
+            .map(|point| ((point.0 - time).num_milliseconds().abs(), point.1))
+            .min_by_key(|(distance, _)| *distance)
+            .filter(|(distance, _)| *distance <= GRAPH_FREEZE_CURSOR_TOLERANCE_MILLISECONDS)
+            .map(|(_, value)| value)
+    }
+}
@@ -96,7 +96,8 @@ macro_rules! gen_add_data_generic {
 macro_rules! gen_clean_expired_data_from_time_generic {
     ($self:ident, $container:tt, $front_time:ident) => {
         if !$self.$container.points.is_empty() {
-            let expired_time = $front_time - chrono::Duration::seconds(GRAPH_DRAW_SECONDS);
+            let expired_time = $front_time
+                - chrono::Duration::seconds(APP_SETTINGS.read().unwrap().graph_seconds as i64);
 
             // Clear expired points
             while $self
@@ -109,6 +110,17 @@ macro_rules! gen_clean_expired_data_from_time_generic {
                 $self.$container.points.pop_back();
             }
 
+            // Clear expired events
+            while $self
+                .$container
+                .events
+                .back()
+                .map(|e| e.0 < expired_time)
+                .unwrap_or(false)
+            {
+                $self.$container.events.pop_back();
+            }
+
             // Clear expired bounds? (high)
             if let Some(bounds_high) = $self.$container.bounds_high {
                 if bounds_high.0 < expired_time {
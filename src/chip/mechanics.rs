@@ -0,0 +1,326 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use makair_telemetry::structures::{
+    DataSnapshot, MachineStateSnapshot, Phase, VentilationModeClass,
+};
+
+use crate::config::environment::*;
+
+#[derive(Debug, Default)]
+pub struct ChipMechanics {
+    // Driving pressure (in cmH2O)
+    pub driving_pressure: Option<f64>,
+    // Static compliance (in mL/cmH2O)
+    pub compliance: Option<f64>,
+    // Airway resistance (in cmH2O/L/s)
+    pub resistance: Option<f64>,
+    // Leak (in mL/min)
+    pub leak: Option<f64>,
+    // Leak (in percents of the inspired volume)
+    pub leak_percent: Option<f64>,
+    cycle_peak_inspiratory_flow: i16,
+    cycle_inspired_volume: f64,
+    cycle_expired_volume: f64,
+    cycle_duration: u64,
+    cycle_volumes_incomplete: bool,
+    last_systick: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+pub struct ChipMechanicsThresholds {
+    pub driving_pressure_high: Option<f64>,
+    pub compliance_low: Option<f64>,
+    pub resistance_high: Option<f64>,
+}
+
+impl ChipMechanics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn update_from_data(&mut self, snapshot: &DataSnapshot) {
+        self.update_flows(
+            snapshot.systick,
+            snapshot.phase == Phase::Inhalation,
+            snapshot.inspiratory_flow,
+            snapshot.expiratory_flow,
+        );
+    }
+
+    pub fn update_from_cycle(&mut self, snapshot: &MachineStateSnapshot) {
+        // Notice: pressures are expressed in mmH2O, while volumes are expressed in mL
+        let (peak, plateau, peep) = (
+            snapshot.previous_peak_pressure as f64,
+            snapshot.previous_plateau_pressure as f64,
+            snapshot.previous_peep_pressure as f64,
+        );
+
+        // Compute raw cycle values (a value cannot be computed if the plateau was not reached)
+        let driving_pressure = if plateau > peep {
+            Some((plateau - peep) / 10.0)
+        } else {
+            None
+        };
+
+        let compliance = match (driving_pressure, snapshot.previous_volume) {
+            (Some(driving_pressure), Some(volume)) if volume > 0 => {
+                Some(volume as f64 / driving_pressure)
+            }
+            _ => None,
+        };
+
+        // Notice: resistance is only computed for volume-controlled cycles, as the inspiratory \
+        //   flow is constant there, which means that the peak inspiratory flow is also the flow \
+        //   at the time the peak pressure is reached. In pressure-controlled cycles, the flow \
+        //   decelerates and is close to zero at peak pressure, so the value would be meaningless.
+        let is_volume_controlled =
+            snapshot.ventilation_mode.class() == VentilationModeClass::Volume;
+
+        let resistance = if is_volume_controlled
+            && plateau > 0.0
+            && peak >= plateau
+            && self.cycle_peak_inspiratory_flow > 0
+        {
+            // Convert peak inspiratory flow from cL/min to L/s
+            let flow = self.cycle_peak_inspiratory_flow as f64 / 6000.0;
+
+            Some((peak - plateau) / 10.0 / flow)
+        } else {
+            None
+        };
+
+        // Compute leak from integrated volumes (ie. what went in but did not come out)
+        let (leak, leak_percent) = if !self.cycle_volumes_incomplete
+            && self.cycle_duration > 0
+            && self.cycle_inspired_volume > 0.0
+        {
+            let leak_volume = (self.cycle_inspired_volume - self.cycle_expired_volume).max(0.0);
+
+            (
+                Some(leak_volume * 60_000_000.0 / self.cycle_duration as f64),
+                Some(leak_volume * 100.0 / self.cycle_inspired_volume),
+            )
+        } else {
+            (None, None)
+        };
+
+        // Smooth values over cycles (as a single cycle measurement might be noisy)
+        Self::smooth(&mut self.driving_pressure, driving_pressure);
+        Self::smooth(&mut self.compliance, compliance);
+        Self::smooth(&mut self.resistance, resistance);
+        Self::smooth(&mut self.leak, leak);
+        Self::smooth(&mut self.leak_percent, leak_percent);
+
+        // Do not keep showing the resistance from past volume-controlled cycles
+        if !is_volume_controlled {
+            self.resistance = None;
+        }
+
+        // Start tracking next cycle
+        self.cycle_peak_inspiratory_flow = 0;
+        self.cycle_inspired_volume = 0.0;
+        self.cycle_expired_volume = 0.0;
+        self.cycle_duration = 0;
+        self.cycle_volumes_incomplete = false;
+    }
+
+    pub fn exceeded(&self, thresholds: &ChipMechanicsThresholds) -> (bool, bool, bool) {
+        // Check which thresholds are exceeded (thresholds are optional, and thus never exceeded \
+        //   if not set)
+        (
+            matches!(
+                (self.driving_pressure, thresholds.driving_pressure_high),
+                (Some(value), Some(threshold)) if value > threshold
+            ),
+            matches!(
+                (self.compliance, thresholds.compliance_low),
+                (Some(value), Some(threshold)) if value < threshold
+            ),
+            matches!(
+                (self.resistance, thresholds.resistance_high),
+                (Some(value), Some(threshold)) if value > threshold
+            ),
+        )
+    }
+
+    fn update_flows(
+        &mut self,
+        systick: u64,
+        inhaling: bool,
+        inspiratory_flow: Option<i16>,
+        expiratory_flow: Option<i16>,
+    ) {
+        // Track peak inspiratory flow over the ongoing cycle (in cL/min)
+        if inhaling {
+            if let Some(inspiratory_flow) = inspiratory_flow {
+                self.cycle_peak_inspiratory_flow =
+                    self.cycle_peak_inspiratory_flow.max(inspiratory_flow);
+            }
+        }
+
+        // Integrate inspired and expired volumes over the ongoing cycle (systicks are in \
+        //   microseconds, and flows are in cL/min)
+        // Notice: if data snapshots went missing for too long, the integrated volumes cannot be \
+        //   trusted for this cycle, as would be the case if a flow value is missing.
+        if let Some(last_systick) = self.last_systick {
+            let elapsed = systick.saturating_sub(last_systick);
+
+            match (inspiratory_flow, expiratory_flow) {
+                (Some(inspiratory_flow), Some(expiratory_flow))
+                    if elapsed <= MECHANICS_INTEGRATE_MAXIMUM_GAP_MICROSECONDS =>
+                {
+                    self.cycle_inspired_volume +=
+                        inspiratory_flow.max(0) as f64 * elapsed as f64 / 6_000_000.0;
+                    self.cycle_expired_volume +=
+                        expiratory_flow.max(0) as f64 * elapsed as f64 / 6_000_000.0;
+                }
+                _ => self.cycle_volumes_incomplete = true,
+            }
+
+            self.cycle_duration += elapsed;
+        }
+
+        self.last_systick = Some(systick);
+    }
+
+    fn smooth(smoothed: &mut Option<f64>, value: Option<f64>) {
+        if let Some(value) = value {
+            *smoothed = Some(match *smoothed {
+                Some(previous) => previous + MECHANICS_SMOOTHING_FACTOR * (value - previous),
+                None => value,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use makair_telemetry::structures::VentilationMode;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn cycle(
+        mode: VentilationMode,
+        peak: u16,
+        plateau: u16,
+        peep: u16,
+        volume: Option<u16>,
+    ) -> MachineStateSnapshot {
+        MachineStateSnapshot {
+            ventilation_mode: mode,
+            previous_peak_pressure: peak,
+            previous_plateau_pressure: plateau,
+            previous_peep_pressure: peep,
+            previous_volume: volume,
+            ..MachineStateSnapshot::default()
+        }
+    }
+
+    fn assert_close(value: Option<f64>, expected: f64) {
+        assert!(
+            (value.expect("value should be known") - expected).abs() < EPSILON,
+            "{:?} should be {}",
+            value,
+            expected
+        );
+    }
+
+    #[test]
+    fn computes_driving_pressure_and_compliance() {
+        let mut mechanics = ChipMechanics::new();
+
+        // Pressures are in mmH2O, thus the driving pressure is 20 cmH2O
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 250, 50, Some(400)));
+
+        assert_close(mechanics.driving_pressure, 20.0);
+        assert_close(mechanics.compliance, 20.0);
+    }
+
+    #[test]
+    fn skips_cycles_without_plateau() {
+        let mut mechanics = ChipMechanics::new();
+
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 50, 50, Some(400)));
+
+        assert_eq!(mechanics.driving_pressure, None);
+        assert_eq!(mechanics.compliance, None);
+
+        // A missing volume only prevents the compliance from being known
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 250, 50, None));
+
+        assert_close(mechanics.driving_pressure, 20.0);
+        assert_eq!(mechanics.compliance, None);
+    }
+
+    #[test]
+    fn computes_resistance_in_volume_controlled_cycles() {
+        let mut mechanics = ChipMechanics::new();
+
+        // Constant inspiratory flow of 30 L/min (ie. 0.5 L/s, flows are in cL/min)
+        mechanics.update_flows(0, true, Some(3000), Some(0));
+        mechanics.update_flows(10_000, true, Some(3000), Some(0));
+        mechanics.update_from_cycle(&cycle(VentilationMode::VC_CMV, 300, 250, 50, Some(400)));
+
+        // Peak pressure minus plateau pressure is 5 cmH2O
+        assert_close(mechanics.resistance, 10.0);
+
+        // Flows are tracked again for each cycle, and resistance is not known in pressure modes
+        mechanics.update_flows(20_000, true, Some(3000), Some(0));
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 250, 50, Some(400)));
+
+        assert_eq!(mechanics.resistance, None);
+    }
+
+    #[test]
+    fn smooths_values_over_cycles() {
+        let mut mechanics = ChipMechanics::new();
+
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 250, 50, Some(400)));
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 400, 350, 50, Some(400)));
+
+        // The first value is used as-is, then next values are averaged exponentially
+        assert_close(
+            mechanics.driving_pressure,
+            20.0 + MECHANICS_SMOOTHING_FACTOR * (30.0 - 20.0),
+        );
+
+        // Cycles without a value do not reset the smoothed value
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 0, 50, None));
+
+        assert_close(
+            mechanics.driving_pressure,
+            20.0 + MECHANICS_SMOOTHING_FACTOR * (30.0 - 20.0),
+        );
+    }
+
+    #[test]
+    fn computes_leak_from_integrated_flows() {
+        let mut mechanics = ChipMechanics::new();
+
+        // Inspire at 60 L/min and expire at 45 L/min over 100 ms periods
+        for index in 0..=10 {
+            mechanics.update_flows(index * 100_000, true, Some(6000), Some(4500));
+        }
+
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 250, 50, Some(400)));
+
+        assert_close(mechanics.leak, 15_000.0);
+        assert_close(mechanics.leak_percent, 25.0);
+
+        // Missing flows make the leak unknown for the cycle
+        mechanics.update_flows(1_100_000, true, None, None);
+        mechanics.update_flows(1_200_000, true, Some(6000), Some(4500));
+        mechanics.update_from_cycle(&cycle(VentilationMode::PC_CMV, 300, 250, 50, Some(400)));
+
+        assert_close(mechanics.leak, 15_000.0);
+    }
+}
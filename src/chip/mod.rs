@@ -6,14 +6,26 @@
 #[macro_use]
 mod macros;
 
+pub mod alarms;
+pub mod breaths;
+pub mod freeze;
+pub mod mechanics;
+pub mod session;
 pub mod settings;
+pub mod weaning;
 
 use chrono::{offset::Utc, DateTime, Duration};
 use std::collections::{HashMap, VecDeque};
 use std::convert::TryFrom;
 use std::sync::mpsc::{self, Receiver, Sender};
-use std::time::Instant;
+use std::time::{Duration as StdDuration, Instant};
 
+use alarms::{
+    ALARM_CODE_UI_COMPLIANCE_LOW, ALARM_CODE_UI_DRIVING_PRESSURE_HIGH,
+    ALARM_CODE_UI_RESISTANCE_HIGH, ALARM_CODE_UI_TELEMETRY_LOST,
+};
+use breaths::ChipBreaths;
+use freeze::ChipFreeze;
 use makair_telemetry::alarm::{AlarmCode, RMC_SW_16};
 use makair_telemetry::control::{ControlMessage, ControlSetting};
 use makair_telemetry::serial::core;
@@ -22,33 +34,57 @@ use makair_telemetry::structures::{
     FatalErrorDetails, HighLevelError, MachineStateSnapshot, PatientGender, StoppedMessage,
     TelemetryMessage, VentilationMode,
 };
+use mechanics::{ChipMechanics, ChipMechanicsThresholds};
+use session::ChipSession;
 use settings::{
-    preset::SettingsPresetGender, ChipSettings, ChipSettingsEvent, ChipSettingsIntent,
-    SettingActionState,
+    mode::SettingsModeMeasures,
+    preset::{SettingsPreset, SettingsPresetGender},
+    snooze::SettingsSnoozeEvent,
+    ChipSettings, ChipSettingsEvent, ChipSettingsIntent, SettingActionState,
 };
+use weaning::{ChipWeaning, ChipWeaningCriteria};
 
 use crate::config::environment::*;
+use crate::serial::recorder::{RecordCommand, RecordEvent, RecordEventKind};
 use crate::utilities::parse::parse_text_lines_to_single;
 use crate::utilities::{
     battery::estimate_lead_acid_12v_2s_soc,
     units::{
-        convert_cmh2o_to_mmh2o, convert_cv_to_v, convert_mmh2o_to_cmh2o, convert_sub_ppm_to_ppm,
-        ConvertMode,
+        convert_cmh2o_to_mmh2o, convert_cmh2o_to_pressure_unit, convert_cv_to_v,
+        convert_lpm_to_flow_unit, convert_mmh2o_to_cmh2o, convert_sub_ppm_to_ppm, ConvertMode,
     },
 };
+use crate::APP_SETTINGS;
 
 const DATA_STORE_EVERY_MILLISECONDS: i64 = 1000 / TELEMETRY_POINTS_PER_SECOND as i64;
 
+const STALE_DATA_SNAPSHOT_AFTER: StdDuration =
+    StdDuration::from_millis(TELEMETRY_STALE_DATA_SNAPSHOT_AFTER_MILLISECONDS);
+const STALE_MACHINE_SNAPSHOT_AFTER: StdDuration =
+    StdDuration::from_millis(TELEMETRY_STALE_MACHINE_SNAPSHOT_AFTER_MILLISECONDS);
+
 pub type ChipDataPoint = (DateTime<Utc>, i16);
 pub type ChipDataPoints = VecDeque<ChipDataPoint>;
 pub type ChipDataBound = ChipDataPoint;
+pub type ChipDataEvent = (DateTime<Utc>, ChipDataEventKind);
+pub type ChipDataEvents = VecDeque<ChipDataEvent>;
 
+#[derive(Clone)]
 pub struct ChipData {
     pub points: ChipDataPoints,
+    pub events: ChipDataEvents,
     pub bounds_high: Option<ChipDataBound>,
     pub bounds_low: Option<ChipDataBound>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChipDataEventKind {
+    SettingsAck,
+    AlarmTriggered(AlarmCode),
+    ModeChange(VentilationMode),
+    TriggeredBreath,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ChipState {
     Initializing,
@@ -151,6 +187,12 @@ struct ChipSettingsUpdate {
     peak_pressure_alarm_threshold: Option<u16>,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ChipAlarmLatch {
+    pub priority: AlarmPriority,
+    pub cleared_at: DateTime<Utc>,
+}
+
 pub struct Chip {
     pub boot_time: Option<DateTime<Utc>>,
     pub last_tick: u64,
@@ -159,17 +201,31 @@ pub struct Chip {
     pub last_machine_snapshot: MachineStateSnapshot,
     pub last_data_snapshot: Option<DataSnapshot>,
     pub ongoing_alarms: HashMap<AlarmCode, AlarmPriority>,
+    pub ui_alarms: HashMap<AlarmCode, AlarmPriority>,
+    pub latched_alarms: HashMap<AlarmCode, ChipAlarmLatch>,
     pub estimated_soc: Option<u8>,
+    pub mechanics: ChipMechanics,
+    pub breaths: ChipBreaths,
+    pub weaning: ChipWeaning,
+    pub freeze: Option<ChipFreeze>,
+    pub session: ChipSession,
+    cycle_measures: VecDeque<SettingsModeMeasures>,
     pub settings: ChipSettings,
     pub state: ChipState,
+    pub replay_events: Vec<RecordEvent>,
+    last_data_snapshot_time: Option<Instant>,
+    last_machine_snapshot_time: Option<Instant>,
+    latch_priority: AlarmPriority,
     lora_tx: Option<Sender<TelemetryMessage>>,
     channel_for_settings: Option<Sender<ControlMessage>>,
+    channel_for_records: Option<Sender<RecordCommand>>,
 }
 
 impl ChipData {
     pub fn new() -> Self {
         Self {
             points: ChipDataPoints::with_capacity(GRAPH_NUMBER_OF_POINTS),
+            events: ChipDataEvents::new(),
             bounds_high: None,
             bounds_low: None,
         }
@@ -177,10 +233,26 @@ impl ChipData {
 
     pub fn reset(&mut self) {
         self.points.clear();
+        self.events.clear();
 
         self.clear_bounds();
     }
 
+    pub fn add_event(&mut self, time: DateTime<Utc>, kind: ChipDataEventKind) {
+        // Merge with the previous event if it is of the same kind and close enough in time (eg. \
+        //   a settings commit is acknowledged setting by setting, and would otherwise stack up \
+        //   as many markers as there were settings changed at once)
+        if let Some(last_event) = self.events.front() {
+            if last_event.1 == kind
+                && time - last_event.0 < Duration::milliseconds(GRAPH_DRAW_EVENT_MERGE_MILLISECONDS)
+            {
+                return;
+            }
+        }
+
+        self.events.push_front((time, kind));
+    }
+
     pub fn clear_bounds(&mut self) {
         self.bounds_high = None;
         self.bounds_low = None;
@@ -188,7 +260,10 @@ impl ChipData {
 }
 
 impl Chip {
-    pub fn new(lora_sender: Option<Sender<TelemetryMessage>>) -> Chip {
+    pub fn new(
+        lora_sender: Option<Sender<TelemetryMessage>>,
+        latch_priority: AlarmPriority,
+    ) -> Chip {
         Chip {
             boot_time: None,
             last_tick: 0,
@@ -197,11 +272,24 @@ impl Chip {
             last_machine_snapshot: MachineStateSnapshot::default(),
             last_data_snapshot: None,
             ongoing_alarms: HashMap::new(),
+            ui_alarms: HashMap::new(),
+            latched_alarms: HashMap::new(),
             estimated_soc: None,
+            mechanics: ChipMechanics::new(),
+            breaths: ChipBreaths::new(),
+            weaning: ChipWeaning::new(),
+            freeze: None,
+            session: ChipSession::new(),
+            cycle_measures: VecDeque::with_capacity(ALARMS_AUTO_SET_CYCLES),
             settings: ChipSettings::new(),
             state: ChipState::WaitingData(Instant::now()),
+            replay_events: Vec::new(),
+            last_data_snapshot_time: None,
+            last_machine_snapshot_time: None,
+            latch_priority,
             lora_tx: lora_sender,
             channel_for_settings: None,
+            channel_for_records: None,
         }
     }
 
@@ -214,7 +302,14 @@ impl Chip {
         self.last_data_snapshot = None;
 
         self.ongoing_alarms.clear();
+        self.ui_alarms.clear();
         self.estimated_soc = None;
+        self.mechanics.reset();
+        self.breaths.reset();
+        self.cycle_measures.clear();
+
+        self.last_data_snapshot_time = None;
+        self.last_machine_snapshot_time = None;
 
         self.update_boot_time();
     }
@@ -244,23 +339,48 @@ impl Chip {
 
     pub fn dispatch_settings_events(&mut self, events: Vec<ChipSettingsEvent>) {
         for event in events {
-            let messages = self.settings.new_settings_event(event);
+            // Acquire the kind of the event, for the record (if recording)
+            let record_kind = match &event {
+                ChipSettingsEvent::Preset(_) => RecordEventKind::PresetSubmit,
+                ChipSettingsEvent::Run(_) | ChipSettingsEvent::Mode(_) => {
+                    RecordEventKind::SettingsCommit
+                }
+                ChipSettingsEvent::Snooze(_) => RecordEventKind::SnoozeToggle,
+                ChipSettingsEvent::EndOfLine(_) => RecordEventKind::EndOfLineConfirm,
+            };
 
-            for message in messages {
-                debug!(
-                    "handled setting event: {:?}, sender: {:?}",
-                    message, self.channel_for_settings
-                );
+            self.dispatch_settings_event(event, record_kind);
+        }
+    }
 
-                if let Some(tx) = &self.channel_for_settings {
-                    if let Err(err) = tx.send(message.clone()) {
-                        error!(
-                            "error sending event {:?} to the control unit: {:?}",
-                            message, err
-                        );
-                    } else {
-                        debug!("setting event {:?} sent", message);
-                    }
+    fn dispatch_settings_event(&mut self, event: ChipSettingsEvent, record_kind: RecordEventKind) {
+        let messages = self.settings.new_settings_event(event);
+
+        // Account for the event in the ongoing patient session
+        if !messages.is_empty() {
+            match record_kind {
+                RecordEventKind::SettingsCommit => self.session.count_settings_commit(),
+                RecordEventKind::PresetSubmit => self.session.preset_pending = false,
+                _ => {}
+            }
+        }
+
+        self.record_event(record_kind, &messages);
+
+        for message in messages {
+            debug!(
+                "handled setting event: {:?}, sender: {:?}",
+                message, self.channel_for_settings
+            );
+
+            if let Some(tx) = &self.channel_for_settings {
+                if let Err(err) = tx.send(message.clone()) {
+                    error!(
+                        "error sending event {:?} to the control unit: {:?}",
+                        message, err
+                    );
+                } else {
+                    debug!("setting event {:?} sent", message);
                 }
             }
         }
@@ -272,9 +392,11 @@ impl Chip {
         //   levels (high and medium). In such cases, we should only retain the high level alarm \
         //   in the list.
 
-        // Prepare ongoing alarms object clone
+        // Prepare ongoing alarms object clone (merged with UI-originated alarms)
         let mut ongoing_alarms = self.ongoing_alarms.clone();
 
+        ongoing_alarms.extend(self.ui_alarms.iter());
+
         // Map adjacent alarm codes
         let mut adjacent_codes = Vec::new();
 
@@ -307,6 +429,335 @@ impl Chip {
         alarm_list
     }
 
+    pub fn latched_alarms_sorted(&self) -> Vec<(AlarmCode, AlarmPriority)> {
+        // This acquires a sorted list of resolved alarms, that were not acknowledged yet
+        let mut alarm_list: Vec<(AlarmCode, AlarmPriority)> = self
+            .latched_alarms
+            .iter()
+            .map(|(code, latch)| (*code, latch.priority))
+            .collect();
+
+        alarm_list.sort_by(|(code1, _), (code2, _)| code1.cmp(&code2));
+        alarm_list.sort_by(|(_, priority1), (_, priority2)| priority2.cmp(&priority1));
+
+        alarm_list
+    }
+
+    pub fn acknowledge_alarm(&mut self, code: AlarmCode) {
+        if let Some(latch) = self.latched_alarms.remove(&code) {
+            // Record the acknowledgement, so that it can be traced back which resolved alarm got \
+            //   acknowledged, and how long after it got cleared
+            let details = format!(
+                "code={},priority={:?},cleared_at={},acknowledged_at={}",
+                code.code(),
+                latch.priority,
+                latch.cleared_at.to_rfc3339(),
+                Utc::now().to_rfc3339()
+            );
+
+            info!("acknowledged resolved alarm: {}", details);
+
+            self.record_event_details(RecordEventKind::AlarmAcknowledge, details);
+        }
+    }
+
+    pub fn record_modal_open(&mut self, modal: &str) {
+        // Record modal openings, so that operator actions can be followed in the record file \
+        //   (even when the modal gets closed without submitting anything)
+        self.record_event_details(RecordEventKind::ModalOpen, modal.to_string());
+    }
+
+    pub fn watch_stale_data(&mut self) {
+        // Raise the 'telemetry lost' alarm if data is stale, or clear it if data resumed
+        let is_stale = self.stale_data_elapsed().is_some();
+
+        self.new_ui_alarm(
+            AlarmCode::from(ALARM_CODE_UI_TELEMETRY_LOST),
+            AlarmPriority::High,
+            is_stale,
+        );
+    }
+
+    pub fn watch_snooze(&mut self, max_duration: StdDuration) {
+        // Track snooze countdown, and un-snooze alarms once snooze expired
+        let alarms = self.ongoing_alarms_sorted();
+
+        if self.settings.snooze.watch(&alarms, max_duration) {
+            info!("alarms snooze expired, un-snoozing alarms");
+
+            // Notice: this is not an operator action, thus it is recorded under its own kind.
+            self.dispatch_settings_event(
+                ChipSettingsEvent::Snooze(SettingsSnoozeEvent::AlarmSnooze),
+                RecordEventKind::SnoozeExpire,
+            );
+        }
+    }
+
+    pub fn ui_alarms_sound_priority(&self) -> Option<AlarmPriority> {
+        // UI-originated alarms are not known to the motherboard, thus they cannot be sounded by \
+        //   its buzzer, and must be sounded by the Control UI itself (unless alarms are snoozed)
+        if self.settings.snooze.alarms == SettingActionState::Enabled {
+            return None;
+        }
+
+        self.ui_alarms.values().max().copied()
+    }
+
+    pub fn watch_mechanics(&mut self, thresholds: &ChipMechanicsThresholds) {
+        // Raise respiratory mechanics alarms if thresholds are exceeded, or clear them otherwise \
+        //   (those alarms are only relevant while running)
+        let (driving_pressure_high, compliance_low, resistance_high) =
+            if self.state == ChipState::Running {
+                self.mechanics.exceeded(thresholds)
+            } else {
+                (false, false, false)
+            };
+
+        self.new_ui_alarm(
+            AlarmCode::from(ALARM_CODE_UI_DRIVING_PRESSURE_HIGH),
+            AlarmPriority::Medium,
+            driving_pressure_high,
+        );
+        self.new_ui_alarm(
+            AlarmCode::from(ALARM_CODE_UI_COMPLIANCE_LOW),
+            AlarmPriority::Medium,
+            compliance_low,
+        );
+        self.new_ui_alarm(
+            AlarmCode::from(ALARM_CODE_UI_RESISTANCE_HIGH),
+            AlarmPriority::Medium,
+            resistance_high,
+        );
+    }
+
+    pub fn toggle_freeze(&mut self) {
+        // Snapshot graph data as it is now, or resume live graph data if already frozen
+        if self.freeze.take().is_none() {
+            if let Some(boot_time) = self.boot_time {
+                self.freeze = Some(ChipFreeze::new(
+                    boot_time,
+                    self.last_tick,
+                    &self.data_pressure,
+                    &self.data_flow,
+                ));
+            }
+        }
+    }
+
+    pub fn save_freeze(&mut self) {
+        // Notice: the record file already holds all telemetry data, thus the frozen view is saved \
+        //   as a reference to its time range, along with the values read at the cursor. Values \
+        //   are converted to the preferred units, which are appended to their keys (eg. \
+        //   'pressure_mbar'), so that the saved view reads the same as the screen did.
+        let details = if let Some(freeze) = &self.freeze {
+            let (pressure, flow) = freeze.cursor_values();
+            let (pressure_unit, flow_unit) = {
+                let settings = APP_SETTINGS.read().unwrap();
+
+                (settings.pressure_unit, settings.flow_unit)
+            };
+
+            format!(
+                "from={},to={},cursor={},pressure_{}={},flow_{}={}",
+                freeze.first_tick(),
+                freeze.last_tick,
+                freeze.cursor_tick(),
+                pressure_unit.to_code(),
+                pressure
+                    .map(|value| format!(
+                        "{:.1}",
+                        convert_cmh2o_to_pressure_unit(
+                            ConvertMode::WithDecimals,
+                            value as f64 / TELEMETRY_POINTS_PRESSURE_PRECISION_DIVIDE as f64,
+                            pressure_unit,
+                        )
+                    ))
+                    .unwrap_or_else(|| "".to_string()),
+                flow_unit.to_code(),
+                flow.map(|value| format!(
+                    "{:.1}",
+                    convert_lpm_to_flow_unit(
+                        ConvertMode::WithDecimals,
+                        value as f64 / TELEMETRY_POINTS_FLOW_PRECISION_DIVIDE as f64,
+                        flow_unit,
+                    )
+                ))
+                .unwrap_or_else(|| "".to_string()),
+            )
+        } else {
+            return;
+        };
+
+        info!("saving frozen graph view: {}", details);
+
+        self.record_event_details(RecordEventKind::GraphFreeze, details);
+    }
+
+    pub fn auto_set_alarms(&mut self, band_percent: usize) {
+        if let Some(measures) = self.average_cycle_measures() {
+            info!(
+                "auto-setting alarm thresholds with a {}% band around: {:?}",
+                band_percent, measures
+            );
+
+            self.settings.mode.auto_set_alarms(&measures, band_percent);
+        } else {
+            warn!("cannot auto-set alarm thresholds, as no cycle was measured yet");
+        }
+    }
+
+    pub fn average_cycle_measures(&self) -> Option<SettingsModeMeasures> {
+        let count = self.cycle_measures.len() as f64;
+
+        if count > 0.0 {
+            let mut average = SettingsModeMeasures::default();
+
+            for measures in self.cycle_measures.iter() {
+                average.respiratory_rate += measures.respiratory_rate / count;
+                average.tidal_volume += measures.tidal_volume / count;
+                average.minute_volume += measures.minute_volume / count;
+                average.peak_pressure += measures.peak_pressure / count;
+            }
+
+            Some(average)
+        } else {
+            None
+        }
+    }
+
+    pub fn toggle_weaning_trial(&mut self, criteria: &ChipWeaningCriteria) {
+        if self.weaning.trial.is_some() {
+            self.stop_weaning_trial(criteria);
+        } else if self.is_weaning_available() {
+            info!(
+                "starting weaning trial for {} seconds",
+                self.weaning.duration().as_secs()
+            );
+
+            self.weaning.start();
+
+            self.record_event_details(
+                RecordEventKind::WeaningStart,
+                format!("duration={}", self.weaning.duration().as_secs()),
+            );
+        } else {
+            warn!("cannot start weaning trial, as ventilation is not running in pc-vsai mode");
+        }
+    }
+
+    pub fn watch_weaning(&mut self, criteria: &ChipWeaningCriteria) {
+        // Stop the ongoing weaning trial if it is over, or if spontaneous ventilation stopped \
+        //   in the middle of the trial (which makes the trial fail)
+        if self.weaning.trial.is_some() {
+            if !self.is_weaning_available() {
+                warn!("stopping weaning trial, as ventilation is not running in pc-vsai mode");
+
+                self.stop_weaning_trial(criteria);
+            } else if let Some(summary) = self.weaning.watch(criteria) {
+                let details = summary.to_details();
+
+                info!("weaning trial completed: {}", details);
+
+                self.record_event_details(RecordEventKind::WeaningEnd, details);
+            }
+        }
+    }
+
+    pub fn start_session(&mut self, patient_id: Option<String>, record_directory: Option<&str>) {
+        // End the ongoing session first (if any), as only one patient may be ventilated at a time
+        // Notice: history is kept there, as restarting a session is most likely done for the \
+        //   same patient. It only gets cleared when starting a session for a new patient.
+        self.end_session();
+
+        self.session.start(patient_id, record_directory);
+
+        if let Some(session) = &self.session.current {
+            let details = session.to_details();
+
+            info!("started patient session: {}", details);
+
+            // Switch to the record file of the new session, before recording anything for it
+            if let (Some(tx), Some(record_path)) = (&self.channel_for_records, &session.record_path)
+            {
+                if let Err(err) = tx.send(RecordCommand::Rotate(record_path.to_owned())) {
+                    error!("error sending rotation to the recorder: {:?}", err);
+                }
+            }
+
+            self.record_event_details(RecordEventKind::SessionStart, details);
+        }
+    }
+
+    pub fn restart_session(&mut self, record_directory: Option<&str>) {
+        // Use the patient identifier confirmed from the session tab (defaults to the identifier \
+        //   of the last session)
+        let patient_id = self.session.patient_id_input.to_owned();
+
+        self.start_session(Some(patient_id), record_directory);
+    }
+
+    pub fn start_new_patient_session(&mut self, record_directory: Option<&str>) {
+        self.end_session();
+
+        // Clear history displayed for the previous patient (only live values are kept)
+        self.clear_session_history();
+
+        // Use the patient identifier that was typed or scanned for the new patient
+        let patient_id = self.session.patient_id_input.to_owned();
+
+        self.start_session(Some(patient_id), record_directory);
+
+        // The patient preset must be submitted again for the new patient (starting from the \
+        //   default preset saved in settings)
+        self.settings.preset = SettingsPreset::new();
+        self.session.preset_pending = true;
+    }
+
+    pub fn end_session(&mut self) {
+        if let Some(summary) = self.session.end() {
+            let details = summary.to_details();
+
+            info!("ended patient session: {}", details);
+
+            self.record_event_details(RecordEventKind::SessionEnd, details);
+
+            // Stop recording to the record file of the ended session (if a new session gets \
+            //   started, recording will resume in the record file of the new session)
+            if let Some(tx) = &self.channel_for_records {
+                if let Err(err) = tx.send(RecordCommand::Stop) {
+                    error!("error sending stop to the recorder: {:?}", err);
+                }
+            }
+        }
+    }
+
+    pub fn is_weaning_available(&self) -> bool {
+        self.state == ChipState::Running && self.settings.mode.live.mode == VentilationMode::PC_VSAI
+    }
+
+    pub fn stale_data_elapsed(&self) -> Option<StdDuration> {
+        // Data can only be stale while running, as the motherboard is expected to send both \
+        //   data snapshots (very frequently) and machine state snapshots (at the end of each \
+        //   cycle). If any of those has not been received for a while, then the values that are \
+        //   shown on screen cannot be trusted anymore.
+        if self.state != ChipState::Running {
+            return None;
+        }
+
+        let data_elapsed = self.last_data_snapshot_time.map(|time| time.elapsed());
+        let machine_elapsed = self.last_machine_snapshot_time.map(|time| time.elapsed());
+
+        match (data_elapsed, machine_elapsed) {
+            (Some(data_elapsed), _) if data_elapsed >= STALE_DATA_SNAPSHOT_AFTER => {
+                Some(data_elapsed)
+            }
+            (_, Some(machine_elapsed)) if machine_elapsed >= STALE_MACHINE_SNAPSHOT_AFTER => {
+                Some(machine_elapsed)
+            }
+            _ => None,
+        }
+    }
+
     pub fn clean_expired_data(&mut self) {
         let time_now = Utc::now();
 
@@ -322,6 +773,18 @@ impl Chip {
         channel.1
     }
 
+    pub fn init_records_receiver(&mut self) -> Receiver<RecordCommand> {
+        let channel = mpsc::channel();
+
+        self.channel_for_records = Some(channel.0);
+
+        channel.1
+    }
+
+    pub fn init_replay_events(&mut self, events: Vec<RecordEvent>) {
+        self.replay_events = events;
+    }
+
     pub fn new_core_error(&mut self, error: makair_telemetry::error::Error) {
         use makair_telemetry::error::Error;
 
@@ -398,8 +861,26 @@ impl Chip {
                 self.add_data_pressure(&snapshot);
                 self.add_data_flow(&snapshot);
 
+                // Track respiratory mechanics values over the ongoing cycle
+                self.mechanics.update_from_data(&snapshot);
+
+                // Detect breaths triggered by the patient (marked on the pressure graph only)
+                let triggered = self.breaths.update_from_data(
+                    &snapshot,
+                    self.settings.mode.live.mode,
+                    self.last_machine_snapshot.cpm_command,
+                );
+
+                if let (Some(true), Some(boot_time)) = (triggered, self.boot_time) {
+                    self.data_pressure.add_event(
+                        boot_time + Duration::microseconds(snapshot.systick as i64),
+                        ChipDataEventKind::TriggeredBreath,
+                    );
+                }
+
                 // Store last data snapshot
                 self.last_data_snapshot = Some(snapshot);
+                self.last_data_snapshot_time = Some(Instant::now());
 
                 self.update_state_running();
 
@@ -414,7 +895,15 @@ impl Chip {
                 self.update_alarms_from_snapshot(&snapshot);
                 self.update_estimated_soc(snapshot.battery_level, true);
 
+                // Compute respiratory mechanics values from the cycle that just ended
+                self.mechanics.update_from_cycle(&snapshot);
+                self.weaning.update_from_cycle(&snapshot);
+                self.add_cycle_measures(&snapshot);
+
+                self.session.count_cycle();
+
                 self.last_machine_snapshot = snapshot;
+                self.last_machine_snapshot_time = Some(Instant::now());
 
                 self.update_state_running();
 
@@ -432,6 +921,15 @@ impl Chip {
                 // Last data snapshot is not relevant when the state went from running to stopped
                 self.last_data_snapshot = None;
 
+                // Respiratory mechanics values are not relevant anymore once stopped
+                self.mechanics.reset();
+                self.breaths.reset();
+                self.cycle_measures.clear();
+
+                // Reset stale data watchdog timers, as no data is expected while stopped
+                self.last_data_snapshot_time = None;
+                self.last_machine_snapshot_time = None;
+
                 // Clear saved data bounds, as to avoid bounds to show in some cases when \
                 //   resuming a stopped ventilation that was overflowing.
                 self.clear_data_bounds();
@@ -477,10 +975,123 @@ impl Chip {
 
     fn new_alarm(&mut self, code: AlarmCode, priority: AlarmPriority, triggered: bool) {
         if triggered {
+            // Mark newly fired alarm on graphs (and account for it in the patient session)
+            if !self.ongoing_alarms.contains_key(&code) {
+                self.add_data_event(ChipDataEventKind::AlarmTriggered(code));
+
+                self.session.count_alarm();
+            }
+
             // If we ever receive the same alarm, just replace the one we have
             self.ongoing_alarms.insert(code, priority);
+
+            // The alarm is ongoing again, thus it is not resolved anymore
+            self.latched_alarms.remove(&code);
         } else {
-            self.ongoing_alarms.remove(&code);
+            self.clear_alarm(code);
+        }
+    }
+
+    fn clear_alarm(&mut self, code: AlarmCode) {
+        // Latch cleared alarm? (so that it stays visible until it gets acknowledged, as an alarm \
+        //   that gets raised and cleared in-between two glances at the screen would be missed)
+        if let Some(priority) = self.ongoing_alarms.remove(&code) {
+            self.latch_alarm(code, priority);
+        }
+    }
+
+    fn latch_alarm(&mut self, code: AlarmCode, priority: AlarmPriority) {
+        if priority >= self.latch_priority {
+            self.latched_alarms.insert(
+                code,
+                ChipAlarmLatch {
+                    priority,
+                    cleared_at: Utc::now(),
+                },
+            );
+        }
+    }
+
+    fn record_event(&mut self, kind: RecordEventKind, messages: &[ControlMessage]) {
+        if let Some(tx) = &self.channel_for_records {
+            if !messages.is_empty() {
+                if let Err(err) = tx.send(RecordCommand::Event(RecordEvent::new(
+                    self.last_tick,
+                    kind,
+                    messages,
+                ))) {
+                    error!("error sending event to the recorder: {:?}", err);
+                }
+            }
+        }
+    }
+
+    fn add_cycle_measures(&mut self, snapshot: &MachineStateSnapshot) {
+        // Keep measured values from the last cycles, which alarm thresholds can be auto-set from \
+        //   (only complete measurements are kept)
+        if let (Some(cpm), Some(volume)) = (snapshot.previous_cpm, snapshot.previous_volume) {
+            if cpm > 0 && volume > 0 {
+                if self.cycle_measures.len() >= ALARMS_AUTO_SET_CYCLES {
+                    self.cycle_measures.pop_front();
+                }
+
+                self.cycle_measures.push_back(SettingsModeMeasures {
+                    respiratory_rate: cpm as f64,
+                    tidal_volume: volume as f64,
+                    minute_volume: cpm as f64 * volume as f64 / 1000.0,
+                    peak_pressure: snapshot.previous_peak_pressure as f64,
+                });
+            }
+        }
+    }
+
+    fn record_event_details(&mut self, kind: RecordEventKind, details: String) {
+        if let Some(tx) = &self.channel_for_records {
+            if let Err(err) = tx.send(RecordCommand::Event(RecordEvent::with_details(
+                self.last_tick,
+                kind,
+                details,
+            ))) {
+                error!("error sending event to the recorder: {:?}", err);
+            }
+        }
+    }
+
+    fn stop_weaning_trial(&mut self, criteria: &ChipWeaningCriteria) {
+        if let Some(summary) = self.weaning.stop(criteria) {
+            let details = summary.to_details();
+
+            info!("weaning trial stopped: {}", details);
+
+            self.record_event_details(RecordEventKind::WeaningEnd, details);
+        }
+    }
+
+    fn clear_session_history(&mut self) {
+        self.reset_data();
+
+        self.mechanics.reset();
+        self.breaths.reset();
+        self.cycle_measures.clear();
+
+        self.latched_alarms.clear();
+
+        // Notice: an ongoing weaning trial is not relevant to the new patient, thus it is dropped
+        self.weaning.trial = None;
+        self.weaning.summary = None;
+
+        self.freeze = None;
+    }
+
+    fn new_ui_alarm(&mut self, code: AlarmCode, priority: AlarmPriority, triggered: bool) {
+        if triggered {
+            self.ui_alarms.insert(code, priority);
+
+            self.latched_alarms.remove(&code);
+        } else if let Some(priority) = self.ui_alarms.remove(&code) {
+            // Notice: UI-originated alarms are latched as well, as eg. a telemetry loss that \
+            //   resumed in-between two glances at the screen must not go unnoticed.
+            self.latch_alarm(code, priority);
         }
     }
 
@@ -557,6 +1168,16 @@ impl Chip {
         );
     }
 
+    fn add_data_event(&mut self, kind: ChipDataEventKind) {
+        // Events are marked at the current time on both graphs (which is only known once booted)
+        if let Some(boot_time) = self.boot_time {
+            let event_time = boot_time + Duration::microseconds(self.last_tick as i64);
+
+            self.data_pressure.add_event(event_time, kind);
+            self.data_flow.add_event(event_time, kind);
+        }
+    }
+
     fn clean_expired_data_pressure_from_time(&mut self, front_time: DateTime<Utc>) {
         gen_clean_expired_data_from_time_generic!(self, data_pressure, front_time);
     }
@@ -615,6 +1236,13 @@ impl Chip {
         //   jitter when resuming from a stopped ventilation session)
         if self.state != ChipState::Running {
             self.clean_expired_data();
+
+            // Initialize stale data watchdog timers (as the first machine state snapshot will \
+            //   only be received at the end of the first ventilation cycle)
+            let now = Instant::now();
+
+            self.last_data_snapshot_time.get_or_insert(now);
+            self.last_machine_snapshot_time.get_or_insert(now);
         }
 
         self.settings.run.state = SettingActionState::Enabled;
@@ -826,7 +1454,14 @@ impl Chip {
         }
 
         // Update ventilation mode value
+        // Notice: only mark mode changes that happen while running, as the mode is otherwise \
+        //   initialized from its default value upon receiving the first snapshot.
         if let Some(ventilation_mode) = update.ventilation_mode {
+            if self.state == ChipState::Running && self.settings.mode.live.mode != ventilation_mode
+            {
+                self.add_data_event(ChipDataEventKind::ModeChange(ventilation_mode));
+            }
+
             self.settings.mode.live.mode = ventilation_mode;
         }
 
@@ -981,6 +1616,13 @@ impl Chip {
         //   control message has been accepted and applied in the firmware. Updating all internal \
         //   values straight away live-refreshes the UI w/ the up-to-date values, instead of \
         //   requiring a full wait until the next data snapshot comes (at the end of each cycle).
+        match ack.setting {
+            ControlSetting::Heartbeat | ControlSetting::VentilationMode => {
+                // Heartbeats are not user settings, and mode changes get their own graph event
+            }
+            _ => self.add_data_event(ChipDataEventKind::SettingsAck),
+        }
+
         match ack.setting {
             ControlSetting::Heartbeat => {
                 // Ignore heartbeat acknowledgements (stateless)
@@ -1040,6 +1682,10 @@ impl Chip {
 
             ControlSetting::VentilationMode => {
                 if let Ok(ventilation_mode) = VentilationMode::try_from(ack.value as u8) {
+                    if self.settings.mode.live.mode != ventilation_mode {
+                        self.add_data_event(ChipDataEventKind::ModeChange(ventilation_mode));
+                    }
+
                     self.settings.mode.live.mode = ventilation_mode;
                     self.last_machine_snapshot.ventilation_mode = ventilation_mode;
                 }
@@ -1227,8 +1873,8 @@ impl Chip {
                         self.ongoing_alarms.len()
                     );
 
-                    for cleared_alarm_code in &cleared_alarm_codes {
-                        self.ongoing_alarms.remove(cleared_alarm_code);
+                    for cleared_alarm_code in cleared_alarm_codes {
+                        self.clear_alarm(cleared_alarm_code);
                     }
                 }
             }
@@ -1249,3 +1895,94 @@ impl Chip {
         self.update_alarms_from_parameters(message.current_alarm_codes.as_ref());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latches_cleared_ui_alarms() {
+        let mut chip = Chip::new(None, AlarmPriority::Medium);
+        let code = AlarmCode::from(ALARM_CODE_UI_TELEMETRY_LOST);
+
+        // Raise then clear the telemetry lost alarm (it stays latched)
+        chip.new_ui_alarm(code, AlarmPriority::High, true);
+
+        assert!(chip.ui_alarms.contains_key(&code));
+        assert!(chip.latched_alarms.is_empty());
+
+        chip.new_ui_alarm(code, AlarmPriority::High, false);
+
+        assert!(chip.ui_alarms.is_empty());
+        assert_eq!(
+            chip.latched_alarms.get(&code).map(|latch| latch.priority),
+            Some(AlarmPriority::High)
+        );
+
+        // Clearing it again (eg. on the next frame) does not latch it twice
+        chip.new_ui_alarm(code, AlarmPriority::High, false);
+
+        assert_eq!(chip.latched_alarms_sorted().len(), 1);
+
+        // Raising it again unlatches it, as it is ongoing again
+        chip.new_ui_alarm(code, AlarmPriority::High, true);
+
+        assert!(chip.latched_alarms.is_empty());
+
+        // Acknowledging it once resolved removes it
+        chip.new_ui_alarm(code, AlarmPriority::High, false);
+        chip.acknowledge_alarm(code);
+
+        assert!(chip.latched_alarms.is_empty());
+    }
+
+    #[test]
+    fn does_not_latch_low_priority_ui_alarms() {
+        let mut chip = Chip::new(None, AlarmPriority::Medium);
+        let code = AlarmCode::from(ALARM_CODE_UI_COMPLIANCE_LOW);
+
+        chip.new_ui_alarm(code, AlarmPriority::Low, true);
+        chip.new_ui_alarm(code, AlarmPriority::Low, false);
+
+        assert!(chip.ui_alarms.is_empty());
+        assert!(chip.latched_alarms.is_empty());
+    }
+
+    #[test]
+    fn keeps_history_when_restarting_session() {
+        let mut chip = Chip::new(None, AlarmPriority::Medium);
+        let code = AlarmCode::from(ALARM_CODE_UI_TELEMETRY_LOST);
+
+        chip.start_session(Some("p1".to_string()), None);
+
+        chip.new_ui_alarm(code, AlarmPriority::High, true);
+        chip.new_ui_alarm(code, AlarmPriority::High, false);
+
+        // Restarting the session keeps the patient identifier and history
+        chip.end_session();
+        chip.restart_session(None);
+
+        assert_eq!(
+            chip.session
+                .current
+                .as_ref()
+                .and_then(|session| session.patient_id.as_deref()),
+            Some("p1")
+        );
+        assert_eq!(chip.latched_alarms.len(), 1);
+
+        // Starting a session for a new patient uses the entered identifier and clears history
+        chip.session.patient_id_input = "p2".to_string();
+        chip.start_new_patient_session(None);
+
+        assert_eq!(
+            chip.session
+                .current
+                .as_ref()
+                .and_then(|session| session.patient_id.as_deref()),
+            Some("p2")
+        );
+        assert!(chip.latched_alarms.is_empty());
+        assert!(chip.session.preset_pending);
+    }
+}
@@ -0,0 +1,302 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use chrono::{offset::Local, DateTime};
+
+pub const PATIENT_ID_INPUT_MAX_LENGTH: usize = 32;
+
+#[derive(Debug, Default)]
+pub struct ChipSession {
+    pub current: Option<ChipSessionOngoing>,
+    pub summary: Option<ChipSessionSummary>,
+    // Whether the patient preset still has to be submitted for the ongoing session
+    pub preset_pending: bool,
+    // Patient identifier for the next session to be started (defaults to the identifier of the \
+    //   last started session, and can be typed or scanned from the advanced settings modal)
+    pub patient_id_input: String,
+}
+
+#[derive(Debug)]
+pub struct ChipSessionOngoing {
+    pub identifier: String,
+    pub patient_id: Option<String>,
+    pub started_at: DateTime<Local>,
+    pub record_path: Option<String>,
+    pub cycles: u32,
+    pub alarms: u32,
+    pub settings_commits: u32,
+}
+
+#[derive(Debug)]
+pub struct ChipSessionSummary {
+    pub identifier: String,
+    pub patient_id: Option<String>,
+    pub started_at: DateTime<Local>,
+    pub ended_at: DateTime<Local>,
+    pub record_path: Option<String>,
+    pub cycles: u32,
+    pub alarms: u32,
+    pub settings_commits: u32,
+}
+
+impl ChipSession {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn start(&mut self, patient_id: Option<String>, record_directory: Option<&str>) {
+        let started_at = Local::now();
+
+        let patient_id = patient_id
+            .map(|patient_id| Self::sanitize_patient_id(&patient_id))
+            .filter(|patient_id| !patient_id.is_empty());
+
+        // Confirm this identifier for the next session, as restarting a session is most likely \
+        //   done for the same patient
+        self.patient_id_input = patient_id.clone().unwrap_or_default();
+
+        // Generate the session identifier, which names all files tied to this session (eg. the \
+        //   record file), so that they can be matched with the patient later on
+        let identifier = match &patient_id {
+            Some(patient_id) => format!("{}-{}", started_at.format("%Y%m%d-%H%M%S"), patient_id),
+            None => started_at.format("%Y%m%d-%H%M%S").to_string(),
+        };
+
+        let record_path = record_directory.map(|directory| {
+            // Notice: the number of files in the directory is appended, as to ensure that names \
+            //   are unique even if the clock of the system got reset.
+            let file_count = std::fs::read_dir(directory)
+                .map(|entries| entries.count())
+                .unwrap_or(0);
+
+            format!("{}/{}-{}.record", directory, identifier, file_count + 1)
+        });
+
+        self.current = Some(ChipSessionOngoing {
+            identifier,
+            patient_id,
+            started_at,
+            record_path,
+            cycles: 0,
+            alarms: 0,
+            settings_commits: 0,
+        });
+    }
+
+    pub fn input_patient_id(&mut self, text: &str) {
+        let remaining_length = PATIENT_ID_INPUT_MAX_LENGTH - self.patient_id_input.len();
+
+        self.patient_id_input.extend(
+            Self::sanitize_patient_id(text)
+                .chars()
+                .take(remaining_length),
+        );
+    }
+
+    pub fn erase_patient_id(&mut self) {
+        self.patient_id_input.pop();
+    }
+
+    pub fn end(&mut self) -> Option<&ChipSessionSummary> {
+        let session = self.current.take()?;
+
+        self.preset_pending = false;
+
+        self.summary = Some(ChipSessionSummary {
+            identifier: session.identifier,
+            patient_id: session.patient_id,
+            started_at: session.started_at,
+            ended_at: Local::now(),
+            record_path: session.record_path,
+            cycles: session.cycles,
+            alarms: session.alarms,
+            settings_commits: session.settings_commits,
+        });
+
+        self.summary.as_ref()
+    }
+
+    pub fn count_cycle(&mut self) {
+        if let Some(session) = self.current.as_mut() {
+            session.cycles += 1;
+        }
+    }
+
+    pub fn count_alarm(&mut self) {
+        if let Some(session) = self.current.as_mut() {
+            session.alarms += 1;
+        }
+    }
+
+    pub fn count_settings_commit(&mut self) {
+        if let Some(session) = self.current.as_mut() {
+            session.settings_commits += 1;
+        }
+    }
+
+    fn sanitize_patient_id(patient_id: &str) -> String {
+        // Patient identifiers end up in file names and in record details, thus only keep safe \
+        //   characters
+        patient_id
+            .chars()
+            .filter(|character| character.is_ascii_alphanumeric() || *character == '-')
+            .collect()
+    }
+}
+
+impl ChipSessionOngoing {
+    pub fn to_details(&self) -> String {
+        format!(
+            "id={},patient={}",
+            self.identifier,
+            self.patient_id.as_deref().unwrap_or("")
+        )
+    }
+}
+
+impl ChipSessionSummary {
+    pub fn duration_seconds(&self) -> i64 {
+        (self.ended_at - self.started_at).num_seconds().max(0)
+    }
+
+    pub fn to_details(&self) -> String {
+        format!(
+            "id={},patient={},duration={},cycles={},alarms={},settings={}",
+            self.identifier,
+            self.patient_id.as_deref().unwrap_or(""),
+            self.duration_seconds(),
+            self.cycles,
+            self.alarms,
+            self.settings_commits,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitizes_patient_id() {
+        let mut session = ChipSession::new();
+
+        session.start(Some("ab/c d-12_3".to_string()), None);
+
+        let current = session.current.as_ref().unwrap();
+
+        assert_eq!(current.patient_id.as_deref(), Some("abcd-123"));
+        assert!(current.identifier.ends_with("-abcd-123"));
+    }
+
+    #[test]
+    fn drops_empty_patient_id() {
+        let mut session = ChipSession::new();
+
+        for patient_id in ["", "../ _"].iter() {
+            session.start(Some(patient_id.to_string()), None);
+
+            let current = session.current.as_ref().unwrap();
+
+            assert_eq!(current.patient_id, None);
+            assert_eq!(
+                current.identifier,
+                current.started_at.format("%Y%m%d-%H%M%S").to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn inputs_patient_id() {
+        let mut session = ChipSession::new();
+
+        // The identifier of the last started session is kept for the next session
+        session.start(Some("p1".to_string()), None);
+
+        assert_eq!(session.patient_id_input, "p1");
+
+        session.erase_patient_id();
+        session.input_patient_id("2/3 4");
+
+        assert_eq!(session.patient_id_input, "p234");
+
+        session.input_patient_id(&"x".repeat(PATIENT_ID_INPUT_MAX_LENGTH));
+
+        assert_eq!(session.patient_id_input.len(), PATIENT_ID_INPUT_MAX_LENGTH);
+
+        session.start(None, None);
+
+        assert!(session.patient_id_input.is_empty());
+    }
+
+    #[test]
+    fn generates_record_path() {
+        let directory = std::env::temp_dir().join("makair-control-session-test");
+
+        let _ = std::fs::remove_dir_all(&directory);
+
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("previous.record"), "").unwrap();
+
+        let directory = directory.to_str().unwrap();
+
+        let mut session = ChipSession::new();
+
+        session.start(Some("p1".to_string()), Some(directory));
+
+        let current = session.current.as_ref().unwrap();
+
+        assert_eq!(
+            current.record_path.as_deref(),
+            Some(
+                format!(
+                    "{}/{}-p1-2.record",
+                    directory,
+                    current.started_at.format("%Y%m%d-%H%M%S")
+                )
+                .as_str()
+            )
+        );
+
+        session.start(None, None);
+
+        assert_eq!(session.current.as_ref().unwrap().record_path, None);
+
+        let _ = std::fs::remove_dir_all(directory);
+    }
+
+    #[test]
+    fn summarizes_ended_session() {
+        let mut session = ChipSession::new();
+
+        // Nothing gets counted nor summarized without an ongoing session
+        session.count_cycle();
+
+        assert!(session.end().is_none());
+
+        session.start(Some("p1".to_string()), None);
+        session.preset_pending = true;
+
+        for _ in 0..3 {
+            session.count_cycle();
+        }
+
+        session.count_alarm();
+        session.count_settings_commit();
+        session.count_settings_commit();
+
+        let summary = session.end().unwrap();
+
+        assert_eq!(summary.cycles, 3);
+        assert_eq!(summary.alarms, 1);
+        assert_eq!(summary.settings_commits, 2);
+        assert!(summary
+            .to_details()
+            .ends_with(",cycles=3,alarms=1,settings=2"));
+
+        assert!(session.current.is_none());
+        assert!(!session.preset_pending);
+        assert!(session.end().is_none());
+    }
+}
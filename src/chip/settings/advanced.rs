@@ -3,8 +3,15 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use crate::chip::settings::preset::{SettingsPresetAge, SettingsPresetGender};
 use crate::chip::settings::SettingActionRange;
+use crate::config::environment::*;
+use crate::config::settings::{
+    ConfigSettings, ConfigSettingsFlowUnit, ConfigSettingsPressureUnit, ConfigSettingsTheme,
+    ConfigSettingsUpdateMay, ConfigSettingsVolumeUnit,
+};
 use crate::locale::{loader::LocaleLoader, locales::LocaleCode};
+use crate::utilities::backlight::apply_brightness;
 use crate::{APP_I18N, APP_SETTINGS};
 
 #[derive(Debug)]
@@ -16,6 +23,10 @@ pub struct SettingsAdvanced {
 #[derive(Debug, PartialEq)]
 pub enum SettingsAdvancedGroupTab {
     Statistics,
+    Mechanics,
+    Weaning,
+    Session,
+    Preferences,
     Settings,
 }
 
@@ -23,7 +34,11 @@ impl SettingsAdvancedGroupTab {
     pub fn from_index(index: usize) -> Option<Self> {
         match index {
             0 => Some(Self::Statistics),
-            1 => Some(Self::Settings),
+            1 => Some(Self::Mechanics),
+            2 => Some(Self::Weaning),
+            3 => Some(Self::Session),
+            4 => Some(Self::Preferences),
+            5 => Some(Self::Settings),
             _ => None,
         }
     }
@@ -71,19 +86,128 @@ impl SettingsAdvanced {
                 self.locale = next_locale;
 
                 // Save new locale value to settings
-                APP_SETTINGS
-                    .write()
-                    .unwrap()
-                    .set_locale(self.locale.to_code().to_string());
-
-                match APP_SETTINGS.read().unwrap().save() {
-                    Ok(_) => info!("saved locale in settings: {}", self.locale.to_code()),
-                    Err(err) => error!("error saving locale in settings: {:?}", err),
-                }
+                let locale = self.locale.to_code().to_string();
+
+                Self::update_settings(|settings| settings.set_locale(locale));
 
                 // Replace current locale with new locale
                 APP_I18N.replace(LocaleLoader::new(&self.locale.to_code()).into_bundle());
             }
         }
     }
+
+    pub fn switch_theme(&mut self, action: SettingActionRange) {
+        let theme = APP_SETTINGS.read().unwrap().theme;
+        let theme = Self::next_in_list(&ConfigSettingsTheme::list_all(), theme, action);
+
+        Self::update_settings(|settings| settings.set_theme(theme));
+    }
+
+    pub fn switch_graph_seconds(&mut self, action: SettingActionRange) {
+        let graph_seconds = APP_SETTINGS.read().unwrap().graph_seconds;
+        let graph_seconds =
+            Self::next_in_list(&SETTINGS_GRAPH_SECONDS_OPTIONS, graph_seconds, action);
+
+        Self::update_settings(|settings| settings.set_graph_seconds(graph_seconds));
+    }
+
+    pub fn switch_pressure_unit(&mut self, action: SettingActionRange) {
+        let pressure_unit = APP_SETTINGS.read().unwrap().pressure_unit;
+        let pressure_unit = Self::next_in_list(
+            &ConfigSettingsPressureUnit::list_all(),
+            pressure_unit,
+            action,
+        );
+
+        Self::update_settings(|settings| settings.set_pressure_unit(pressure_unit));
+    }
+
+    pub fn switch_flow_unit(&mut self, action: SettingActionRange) {
+        let flow_unit = APP_SETTINGS.read().unwrap().flow_unit;
+        let flow_unit = Self::next_in_list(&ConfigSettingsFlowUnit::list_all(), flow_unit, action);
+
+        Self::update_settings(|settings| settings.set_flow_unit(flow_unit));
+    }
+
+    pub fn switch_volume_unit(&mut self, action: SettingActionRange) {
+        let volume_unit = APP_SETTINGS.read().unwrap().volume_unit;
+        let volume_unit =
+            Self::next_in_list(&ConfigSettingsVolumeUnit::list_all(), volume_unit, action);
+
+        Self::update_settings(|settings| settings.set_volume_unit(volume_unit));
+    }
+
+    pub fn change_snooze_max(&mut self, action: SettingActionRange) {
+        let snooze_max_seconds = action.to_new_value_bounds(
+            APP_SETTINGS.read().unwrap().snooze_max_seconds as usize,
+            SETTINGS_SNOOZE_MAX_SECONDS_STEP as usize,
+            SETTINGS_SNOOZE_MAX_SECONDS_MIN as usize..=SETTINGS_SNOOZE_MAX_SECONDS_MAX as usize,
+        ) as u64;
+
+        Self::update_settings(|settings| settings.set_snooze_max_seconds(snooze_max_seconds));
+    }
+
+    pub fn change_brightness(&mut self, action: SettingActionRange) {
+        let brightness = action.to_new_value_bounds(
+            APP_SETTINGS.read().unwrap().brightness as usize,
+            SETTINGS_BRIGHTNESS_STEP as usize,
+            SETTINGS_BRIGHTNESS_MIN as usize..=SETTINGS_BRIGHTNESS_MAX as usize,
+        ) as u8;
+
+        if Self::update_settings(|settings| settings.set_brightness(brightness)) {
+            apply_brightness(brightness);
+        }
+    }
+
+    pub fn switch_preset_age(&mut self, action: SettingActionRange) {
+        let preset_age = APP_SETTINGS.read().unwrap().preset_age.clone();
+        let preset_age = Self::next_in_list(&SettingsPresetAge::list_all(), preset_age, action);
+
+        Self::update_settings(|settings| settings.set_preset_age(preset_age));
+    }
+
+    pub fn switch_preset_gender(&mut self, action: SettingActionRange) {
+        let preset_gender = APP_SETTINGS.read().unwrap().preset_gender;
+        let preset_gender =
+            Self::next_in_list(&SettingsPresetGender::list_all(), preset_gender, action);
+
+        Self::update_settings(|settings| settings.set_preset_gender(preset_gender));
+    }
+
+    fn next_in_list<T: PartialEq + Clone>(list: &[T], current: T, action: SettingActionRange) -> T {
+        // Get index of current value in list (the value is kept as-is at the list boundaries)
+        let current_index = list.iter().position(|value| value == &current).unwrap_or(0);
+        let next_index = action.to_next_index(current_index as _);
+
+        if next_index >= 0 && (next_index as usize) < list.len() {
+            list[next_index as usize].to_owned()
+        } else {
+            current
+        }
+    }
+
+    fn update_settings<F>(update: F) -> bool
+    where
+        F: FnOnce(&mut ConfigSettings) -> ConfigSettingsUpdateMay,
+    {
+        let mut settings = APP_SETTINGS.write().unwrap();
+
+        match update(&mut *settings) {
+            ConfigSettingsUpdateMay::ShouldSave => {
+                match settings.save() {
+                    Ok(_) => {
+                        info!("saved updated settings");
+
+                        // Settings were saved from a valid state, thus any warning about the \
+                        //   settings file that was loaded on start is not relevant anymore
+                        settings.warning = None;
+                    }
+                    Err(err) => error!("error saving updated settings: {:?}", err),
+                }
+
+                true
+            }
+            ConfigSettingsUpdateMay::NoChange => false,
+        }
+    }
 }
@@ -30,6 +30,22 @@ macro_rules! gen_set_mode_draft {
     }};
 }
 
+macro_rules! gen_auto_set_mode_alarms {
+    ($self:ident, { $($setting:tt |-> $field:tt [$value:expr, $step:ident],)+ }) => {{
+        $(
+            let old_value = gen_get_mode_value!($self, $field);
+
+            // Round value to the closest step, and keep it within setting bounds
+            let bounds = ControlSetting::$setting.bounds();
+            let new_value = ((($value / $step as f64).round() as usize) * $step)
+                .max(*bounds.start())
+                .min(*bounds.end());
+
+            gen_set_mode_draft!($self, $field, old_value, new_value);
+        )+
+    }};
+}
+
 macro_rules! gen_commit_mode_events_numeric {
     ($self:ident, $draft:ident, $events:ident, { $($setting:tt -> $field:tt,)+ }) => {{
         $(
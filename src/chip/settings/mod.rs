@@ -104,7 +104,7 @@ impl SettingActionRange {
         }
     }
 
-    fn to_next_index(&self, current_index: i16) -> i16 {
+    pub fn to_next_index(&self, current_index: i16) -> i16 {
         current_index as i16
             + match self {
                 SettingActionRange::Less => -1,
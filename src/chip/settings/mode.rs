@@ -23,6 +23,18 @@ pub enum SettingsModeEvent {
     Commit,
 }
 
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SettingsModeMeasures {
+    // Respiratory rate (in cycles per minute)
+    pub respiratory_rate: f64,
+    // Tidal volume (in mL)
+    pub tidal_volume: f64,
+    // Minute volume (in L/min)
+    pub minute_volume: f64,
+    // Peak pressure (in mmH2O)
+    pub peak_pressure: f64,
+}
+
 #[derive(Debug)]
 pub struct SettingsMode {
     // Group
@@ -70,6 +82,47 @@ impl SettingsMode {
         }
     }
 
+    pub fn auto_set_alarms(&mut self, measures: &SettingsModeMeasures, band_percent: usize) {
+        // Fill alarm thresholds in draft with a band around measured values, which the \
+        //   operator may then review and commit (or discard) as any other draft value
+        // Notice: the telemetry does not tell apart inspiratory and expiratory minute volumes, \
+        //   thus both use the same measured minute volume.
+        let (band_low, band_high) = (
+            1.0 - band_percent as f64 / 100.0,
+            1.0 + band_percent as f64 / 100.0,
+        );
+
+        gen_auto_set_mode_alarms!(self, {
+            LowInspiratoryMinuteVolumeAlarmThreshold
+                |-> alarm_threshold_low_inspiratory_minute_volume
+                [measures.minute_volume * band_low, TRIGGER_FLOW_STEP],
+            HighInspiratoryMinuteVolumeAlarmThreshold
+                |-> alarm_threshold_high_inspiratory_minute_volume
+                [measures.minute_volume * band_high, TRIGGER_FLOW_STEP],
+            LowExpiratoryMinuteVolumeAlarmThreshold
+                |-> alarm_threshold_low_expiratory_minute_volume
+                [measures.minute_volume * band_low, TRIGGER_FLOW_STEP],
+            HighExpiratoryMinuteVolumeAlarmThreshold
+                |-> alarm_threshold_high_expiratory_minute_volume
+                [measures.minute_volume * band_high, TRIGGER_FLOW_STEP],
+            LowRespiratoryRateAlarmThreshold
+                |-> alarm_threshold_low_respiratory_rate
+                [measures.respiratory_rate * band_low, CYCLES_PER_MINUTE_STEP],
+            HighRespiratoryRateAlarmThreshold
+                |-> alarm_threshold_high_respiratory_rate
+                [measures.respiratory_rate * band_high, CYCLES_PER_MINUTE_STEP],
+            LowTidalVolumeAlarmThreshold
+                |-> alarm_threshold_low_tidal_volume
+                [measures.tidal_volume * band_low, VOLUME_STEP],
+            HighTidalVolumeAlarmThreshold
+                |-> alarm_threshold_high_tidal_volume
+                [measures.tidal_volume * band_high, VOLUME_STEP],
+            PeakPressureAlarmThreshold
+                |-> alarm_threshold_peak_pressure
+                [measures.peak_pressure * band_high, PRESSURE_STEP],
+        });
+    }
+
     fn clear_draft(&mut self) {
         // Ensure draft is cleared
         self.draft = None;
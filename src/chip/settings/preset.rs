@@ -7,6 +7,7 @@ use makair_telemetry::control::{ControlMessage, ControlSetting};
 use makair_telemetry::structures::PatientGender;
 
 use crate::chip::settings::SettingActionRange;
+use crate::APP_SETTINGS;
 
 const SIZE_STEP: usize = 1;
 
@@ -14,8 +15,6 @@ const SIZE_BASE_CHILD: usize = 110;
 const SIZE_BASE_TEENAGER: usize = 150;
 const SIZE_BASE_ADULT: usize = 170;
 
-const SIZE_FALLBACK_DEFAULT: usize = SIZE_BASE_ADULT;
-
 #[derive(Debug)]
 pub enum SettingsPresetEvent {
     CommitIgnore,
@@ -29,13 +28,15 @@ pub struct SettingsPreset {
     pub size: usize,
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
 pub enum SettingsPresetGender {
     Male,
     Female,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "lowercase")]
 pub enum SettingsPresetAge {
     Child,
     Teenager,
@@ -55,7 +56,7 @@ impl Default for SettingsPresetAge {
 }
 
 impl SettingsPresetGender {
-    fn list_all() -> [Self; 2] {
+    pub fn list_all() -> [Self; 2] {
         [Self::Male, Self::Female]
     }
 }
@@ -79,7 +80,7 @@ impl From<&SettingsPresetGender> for PatientGender {
 }
 
 impl SettingsPresetAge {
-    fn list_all() -> [Self; 3] {
+    pub fn list_all() -> [Self; 3] {
         [Self::Child, Self::Teenager, Self::Adult]
     }
 
@@ -94,14 +95,11 @@ impl SettingsPresetAge {
 
 impl SettingsPreset {
     pub fn new() -> SettingsPreset {
-        let age = SettingsPresetAge::default();
+        // Start from the default preset saved in settings
+        let (gender, age) = Self::saved_defaults();
         let size = age.base_size();
 
-        SettingsPreset {
-            gender: SettingsPresetGender::default(),
-            age,
-            size,
-        }
+        SettingsPreset { gender, age, size }
     }
 
     pub fn new_event(&self, event: SettingsPresetEvent) -> Vec<ControlMessage> {
@@ -118,7 +116,9 @@ impl SettingsPreset {
         let (patient_gender, patient_size) = if submit {
             (self.gender, self.size)
         } else {
-            (SettingsPresetGender::default(), SIZE_FALLBACK_DEFAULT)
+            let (gender, age) = Self::saved_defaults();
+
+            (gender, age.base_size())
         };
 
         // Acquire internal value for gender
@@ -181,4 +181,10 @@ impl SettingsPreset {
     pub fn change_size(&mut self, action: SettingActionRange) {
         self.size = action.to_new_value(&ControlSetting::PatientHeight, self.size, SIZE_STEP);
     }
+
+    fn saved_defaults() -> (SettingsPresetGender, SettingsPresetAge) {
+        let settings = APP_SETTINGS.read().unwrap();
+
+        (settings.preset_gender, settings.preset_age.clone())
+    }
 }
@@ -3,10 +3,16 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use std::time::{Duration, Instant};
+
+use makair_telemetry::alarm::AlarmCode;
 use makair_telemetry::control::{ControlMessage, ControlSetting};
+use makair_telemetry::structures::AlarmPriority;
 
 use crate::chip::settings::SettingActionState;
 
+const SNOOZE_EXPIRED_RESEND_AFTER: Duration = Duration::from_secs(2);
+
 #[derive(Debug)]
 pub enum SettingsSnoozeEvent {
     AlarmSnooze,
@@ -15,13 +21,75 @@ pub enum SettingsSnoozeEvent {
 #[derive(Debug)]
 pub struct SettingsSnooze {
     pub alarms: SettingActionState,
+    pub alarms_since: Option<Instant>,
+    pub alarms_escalated: bool,
+    alarms_known: Vec<AlarmCode>,
+    alarms_expired_at: Option<Instant>,
 }
 
 impl SettingsSnooze {
     pub fn new() -> SettingsSnooze {
         SettingsSnooze {
             alarms: SettingActionState::from_value(ControlSetting::AlarmSnooze.default()),
+            alarms_since: None,
+            alarms_escalated: false,
+            alarms_known: Vec::new(),
+            alarms_expired_at: None,
+        }
+    }
+
+    pub fn watch(&mut self, alarms: &[(AlarmCode, AlarmPriority)], max_duration: Duration) -> bool {
+        // Track when alarms got snoozed, and which high-priority alarms were known at this time
+        match (self.alarms, self.alarms_since) {
+            (SettingActionState::Enabled, None) => {
+                self.alarms_since = Some(Instant::now());
+                self.alarms_known = Self::high_priority_alarms(alarms).collect();
+                self.alarms_escalated = false;
+                self.alarms_expired_at = None;
+            }
+            (SettingActionState::Disabled, Some(_)) => {
+                self.alarms_since = None;
+                self.alarms_known.clear();
+                self.alarms_escalated = false;
+                self.alarms_expired_at = None;
+            }
+            _ => {}
+        }
+
+        if let Some(alarms_since) = self.alarms_since {
+            // Escalate if a new high-priority alarm got raised while alarms are snoozed
+            let alarms_known = &self.alarms_known;
+
+            if Self::high_priority_alarms(alarms).any(|code| !alarms_known.contains(&code)) {
+                self.alarms_escalated = true;
+            }
+
+            // Snooze expired? (this is reported again until the motherboard reports alarms as \
+            //   un-snoozed, as the un-snooze request might get lost, though not on every call, as \
+            //   it might take some time to be acknowledged)
+            if alarms_since.elapsed() >= max_duration {
+                let should_send = self
+                    .alarms_expired_at
+                    .map(|expired_at| expired_at.elapsed() >= SNOOZE_EXPIRED_RESEND_AFTER)
+                    .unwrap_or(true);
+
+                if should_send {
+                    self.alarms_expired_at = Some(Instant::now());
+
+                    return true;
+                }
+            }
         }
+
+        false
+    }
+
+    pub fn remaining(&self, max_duration: Duration) -> Option<Duration> {
+        self.alarms_since.map(|alarms_since| {
+            max_duration
+                .checked_sub(alarms_since.elapsed())
+                .unwrap_or_default()
+        })
     }
 
     pub fn new_event(&self, event: SettingsSnoozeEvent) -> Vec<ControlMessage> {
@@ -32,6 +100,15 @@ impl SettingsSnooze {
         vec![event]
     }
 
+    fn high_priority_alarms<'a>(
+        alarms: &'a [(AlarmCode, AlarmPriority)],
+    ) -> impl Iterator<Item = AlarmCode> + 'a {
+        alarms
+            .iter()
+            .filter(|(_, priority)| *priority == AlarmPriority::High)
+            .map(|(code, _)| *code)
+    }
+
     fn toggle_alarms(&self) -> ControlMessage {
         ControlMessage {
             setting: ControlSetting::AlarmSnooze,
@@ -39,3 +116,31 @@ impl SettingsSnooze {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resends_expiry_until_unsnoozed() {
+        let mut snooze = SettingsSnooze::new();
+
+        snooze.alarms = SettingActionState::Enabled;
+
+        assert!(snooze.watch(&[], Duration::from_secs(0)));
+
+        // The un-snooze request is not sent again right away
+        assert!(!snooze.watch(&[], Duration::from_secs(0)));
+
+        // Though it is sent again if still not acknowledged after a while
+        snooze.alarms_expired_at = Some(Instant::now() - SNOOZE_EXPIRED_RESEND_AFTER);
+
+        assert!(snooze.watch(&[], Duration::from_secs(0)));
+
+        // Nothing is sent anymore once alarms are reported as un-snoozed
+        snooze.alarms = SettingActionState::Disabled;
+
+        assert!(!snooze.watch(&[], Duration::from_secs(0)));
+        assert!(snooze.alarms_expired_at.is_none());
+    }
+}
@@ -0,0 +1,330 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::time::{Duration, Instant};
+
+use makair_telemetry::structures::{MachineStateSnapshot, VentilationMode};
+
+use crate::chip::settings::SettingActionRange;
+use crate::config::environment::*;
+
+#[derive(Debug, Default)]
+pub struct ChipWeaning {
+    pub duration_index: usize,
+    pub trial: Option<ChipWeaningTrial>,
+    pub summary: Option<ChipWeaningSummary>,
+}
+
+#[derive(Debug)]
+pub struct ChipWeaningTrial {
+    pub started_at: Instant,
+    pub duration: Duration,
+    pub cycles: u32,
+    // Last cycle rapid shallow breathing index (in breaths/min/L)
+    pub rsbi: Option<f64>,
+    // Last cycle spontaneous rate (in breaths/min)
+    pub rate: Option<f64>,
+    // Last cycle minute volume (in L/min)
+    pub minute_volume: Option<f64>,
+    rsbi_sum: f64,
+    rate_sum: f64,
+    minute_volume_sum: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChipWeaningSummary {
+    pub duration: Duration,
+    pub elapsed: Duration,
+    pub completed: bool,
+    pub passed: bool,
+    pub cycles: u32,
+    // Average values over the whole trial (same units as the trial values)
+    pub rsbi: Option<f64>,
+    pub rate: Option<f64>,
+    pub minute_volume: Option<f64>,
+}
+
+#[derive(Debug)]
+pub struct ChipWeaningCriteria {
+    pub rsbi_max: f64,
+    pub rate_max: f64,
+    pub minute_volume_max: f64,
+}
+
+impl ChipWeaning {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(WEANING_TRIAL_DURATIONS_MINUTES[self.duration_index] * 60)
+    }
+
+    pub fn switch_duration(&mut self, action: SettingActionRange) {
+        // The duration cannot be changed once a trial has started
+        if self.trial.is_none() {
+            let next_index = action.to_next_index(self.duration_index as _);
+
+            if next_index >= 0 && (next_index as usize) < WEANING_TRIAL_DURATIONS_MINUTES.len() {
+                self.duration_index = next_index as usize;
+            }
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.trial = Some(ChipWeaningTrial {
+            started_at: Instant::now(),
+            duration: self.duration(),
+            cycles: 0,
+            rsbi: None,
+            rate: None,
+            minute_volume: None,
+            rsbi_sum: 0.0,
+            rate_sum: 0.0,
+            minute_volume_sum: 0.0,
+        });
+
+        self.summary = None;
+    }
+
+    pub fn stop(&mut self, criteria: &ChipWeaningCriteria) -> Option<&ChipWeaningSummary> {
+        let trial = self.trial.take()?;
+
+        self.summary = Some(trial.summarize(criteria));
+        self.summary.as_ref()
+    }
+
+    pub fn watch(&mut self, criteria: &ChipWeaningCriteria) -> Option<&ChipWeaningSummary> {
+        // Stop the trial once its duration has elapsed
+        let trial = self.trial.as_ref()?;
+
+        if trial.started_at.elapsed() >= trial.duration {
+            self.stop(criteria)
+        } else {
+            None
+        }
+    }
+
+    pub fn update_from_cycle(&mut self, snapshot: &MachineStateSnapshot) {
+        // Only spontaneous breathing cycles are relevant to the trial (ie. PC-VSAI)
+        if snapshot.ventilation_mode != VentilationMode::PC_VSAI {
+            return;
+        }
+
+        if let Some(trial) = self.trial.as_mut() {
+            if let (Some(cpm), Some(volume)) = (snapshot.previous_cpm, snapshot.previous_volume) {
+                if cpm > 0 && volume > 0 {
+                    // Notice: volumes are expressed in mL, while RSBI uses liters
+                    let (rate, volume) = (cpm as f64, volume as f64 / 1000.0);
+
+                    trial.push(rate / volume, rate, rate * volume);
+                }
+            }
+        }
+    }
+}
+
+impl ChipWeaningTrial {
+    pub fn average_rsbi(&self) -> Option<f64> {
+        self.average(self.rsbi_sum)
+    }
+
+    pub fn average_rate(&self) -> Option<f64> {
+        self.average(self.rate_sum)
+    }
+
+    pub fn average_minute_volume(&self) -> Option<f64> {
+        self.average(self.minute_volume_sum)
+    }
+
+    fn push(&mut self, rsbi: f64, rate: f64, minute_volume: f64) {
+        self.cycles += 1;
+
+        self.rsbi = Some(rsbi);
+        self.rate = Some(rate);
+        self.minute_volume = Some(minute_volume);
+
+        self.rsbi_sum += rsbi;
+        self.rate_sum += rate;
+        self.minute_volume_sum += minute_volume;
+    }
+
+    fn average(&self, sum: f64) -> Option<f64> {
+        if self.cycles > 0 {
+            Some(sum / self.cycles as f64)
+        } else {
+            None
+        }
+    }
+
+    fn summarize(&self, criteria: &ChipWeaningCriteria) -> ChipWeaningSummary {
+        let elapsed = self.started_at.elapsed().min(self.duration);
+        let completed = elapsed >= self.duration;
+
+        let (rsbi, rate, minute_volume) = (
+            self.average_rsbi(),
+            self.average_rate(),
+            self.average_minute_volume(),
+        );
+
+        // A trial passes only if it went all the way, and if all averages are within criteria
+        let passed = completed
+            && matches!(rsbi, Some(rsbi) if rsbi <= criteria.rsbi_max)
+            && matches!(rate, Some(rate) if rate <= criteria.rate_max)
+            && matches!(
+                minute_volume,
+                Some(minute_volume) if minute_volume <= criteria.minute_volume_max
+            );
+
+        ChipWeaningSummary {
+            duration: self.duration,
+            elapsed,
+            completed,
+            passed,
+            cycles: self.cycles,
+            rsbi,
+            rate,
+            minute_volume,
+        }
+    }
+}
+
+impl ChipWeaningSummary {
+    pub fn to_details(&self) -> String {
+        let format_value = |value: Option<f64>| {
+            value
+                .map(|value| format!("{:.1}", value))
+                .unwrap_or_else(|| "".to_string())
+        };
+
+        format!(
+            "duration={},elapsed={},completed={},passed={},cycles={},rsbi={},rate={},minute_volume={}",
+            self.duration.as_secs(),
+            self.elapsed.as_secs(),
+            self.completed,
+            self.passed,
+            self.cycles,
+            format_value(self.rsbi),
+            format_value(self.rate),
+            format_value(self.minute_volume),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    const CRITERIA: ChipWeaningCriteria = ChipWeaningCriteria {
+        rsbi_max: 105.0,
+        rate_max: 35.0,
+        minute_volume_max: 10.0,
+    };
+
+    fn cycle(mode: VentilationMode, cpm: u8, volume: u16) -> MachineStateSnapshot {
+        MachineStateSnapshot {
+            ventilation_mode: mode,
+            previous_cpm: Some(cpm),
+            previous_volume: Some(volume),
+            ..MachineStateSnapshot::default()
+        }
+    }
+
+    fn complete_trial(weaning: &mut ChipWeaning) -> ChipWeaningSummary {
+        // Make the trial complete straight away
+        weaning.trial.as_mut().unwrap().duration = Duration::from_secs(0);
+
+        weaning
+            .watch(&CRITERIA)
+            .cloned()
+            .expect("trial should be over")
+    }
+
+    fn assert_close(value: Option<f64>, expected: f64) {
+        assert!((value.expect("value should be known") - expected).abs() < EPSILON);
+    }
+
+    #[test]
+    fn averages_trial_cycles() {
+        let mut weaning = ChipWeaning::new();
+
+        weaning.start();
+        weaning.update_from_cycle(&cycle(VentilationMode::PC_VSAI, 20, 400));
+        weaning.update_from_cycle(&cycle(VentilationMode::PC_VSAI, 30, 500));
+
+        // Cycles that are not spontaneous, or that are incomplete, are ignored
+        weaning.update_from_cycle(&cycle(VentilationMode::PC_CMV, 40, 100));
+        weaning.update_from_cycle(&cycle(VentilationMode::PC_VSAI, 0, 400));
+
+        let trial = weaning.trial.as_ref().unwrap();
+
+        assert_eq!(trial.cycles, 2);
+
+        // Last cycle values are kept as-is (RSBI is 30 / 0.5 L)
+        assert_close(trial.rsbi, 60.0);
+        assert_close(trial.rate, 30.0);
+        assert_close(trial.minute_volume, 15.0);
+
+        assert_close(trial.average_rsbi(), (50.0 + 60.0) / 2.0);
+        assert_close(trial.average_rate(), 25.0);
+        assert_close(trial.average_minute_volume(), (8.0 + 15.0) / 2.0);
+    }
+
+    #[test]
+    fn passes_trial_within_criteria() {
+        let mut weaning = ChipWeaning::new();
+
+        weaning.start();
+
+        for _ in 0..3 {
+            weaning.update_from_cycle(&cycle(VentilationMode::PC_VSAI, 20, 400));
+        }
+
+        let summary = complete_trial(&mut weaning);
+
+        assert!(summary.completed);
+        assert!(summary.passed);
+        assert_eq!(summary.cycles, 3);
+        assert!(weaning.trial.is_none());
+    }
+
+    #[test]
+    fn fails_trial_out_of_criteria() {
+        let mut weaning = ChipWeaning::new();
+
+        // Rapid shallow breathing (RSBI is 40 / 0.2 L, over the maximum)
+        weaning.start();
+        weaning.update_from_cycle(&cycle(VentilationMode::PC_VSAI, 40, 200));
+
+        let summary = complete_trial(&mut weaning);
+
+        assert!(summary.completed);
+        assert!(!summary.passed);
+
+        // A trial without any cycle cannot pass
+        weaning.start();
+
+        assert!(!complete_trial(&mut weaning).passed);
+    }
+
+    #[test]
+    fn fails_stopped_trial() {
+        let mut weaning = ChipWeaning::new();
+
+        weaning.start();
+        weaning.update_from_cycle(&cycle(VentilationMode::PC_VSAI, 20, 400));
+
+        // The trial is still ongoing, thus it does not stop by itself
+        assert!(weaning.watch(&CRITERIA).is_none());
+
+        let summary = weaning.stop(&CRITERIA).cloned().unwrap();
+
+        assert!(!summary.completed);
+        assert!(!summary.passed);
+        assert_eq!(summary.cycles, 1);
+    }
+}
@@ -3,14 +3,20 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
-use clap::{App, Arg};
+use std::path::Path;
+use std::time::Duration;
 
+use clap::{App, Arg, SubCommand};
+use makair_telemetry::structures::AlarmPriority;
+
+use crate::chip::mechanics::ChipMechanicsThresholds;
+use crate::chip::weaning::ChipWeaningCriteria;
+use crate::locale::checker::LocaleChecker;
+use crate::locale::overrides::LocaleOverrides;
 use crate::APP_SETTINGS;
 
-#[cfg(debug_assertions)]
-const LOG_LEVEL_DEFAULT: &str = "debug";
-#[cfg(not(debug_assertions))]
-const LOG_LEVEL_DEFAULT: &str = "warn";
+use super::layers::{ConfigLayerSource, ConfigLayers};
+use super::settings::ConfigSettings;
 
 pub enum RunMode {
     Port {
@@ -18,12 +24,27 @@ pub enum RunMode {
         output_dir: Option<String>,
     },
     Input(String),
+    Tcp(String),
+    Pipe(String),
+}
+
+pub enum SoundOutput {
+    None,
+    Aplay,
+    Wav(String),
 }
 
 pub struct ConfigArguments {
     pub log: String,
     pub translation: String,
     pub mode: RunMode,
+    pub alarm_sound: SoundOutput,
+    pub snooze_max: Option<Duration>,
+    pub alarms_latch: AlarmPriority,
+    pub alarms_auto_band: usize,
+    pub mechanics_thresholds: ChipMechanicsThresholds,
+    pub weaning_criteria: ChipWeaningCriteria,
+    pub patient_id: Option<String>,
     pub fullscreen: bool,
     #[cfg(feature = "lora")]
     pub lora: bool,
@@ -42,7 +63,6 @@ impl ConfigArguments {
                     .short("l")
                     .long("log")
                     .help("Log level")
-                    .default_value(LOG_LEVEL_DEFAULT)
                     .takes_value(true),
             )
             .arg(
@@ -59,12 +79,90 @@ impl ConfigArguments {
                     .help("Path to a recorded input file")
                     .takes_value(true),
             )
+            .arg(
+                Arg::with_name("tcp")
+                    .long("tcp")
+                    .help("Address of a TCP telemetry source (host:port)")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("pipe")
+                    .long("pipe")
+                    .help("Path to a named pipe telemetry source ('-' for stdin)")
+                    .takes_value(true),
+            )
             .arg(
                 Arg::with_name("output")
                     .short("o")
                     .long("output")
-                    .env("OUTPUT_DIR")
-                    .help("Path to a directory where to record telemetry")
+                    .help("Path to a directory where to record telemetry (overrides settings)")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("alarm-sound")
+                    .long("alarm-sound")
+                    .help("Output for UI alarm sounds ('none', 'aplay' or 'wav:{path}')")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("snooze-max")
+                    .long("snooze-max")
+                    .help("Maximum alarms snooze duration, in seconds (overrides settings)")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("alarms-latch")
+                    .long("alarms-latch")
+                    .help("Lowest priority of resolved alarms that must be acknowledged")
+                    .possible_values(&["high", "medium", "low"])
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("alarms-auto-band")
+                    .long("alarms-auto-band")
+                    .help("Band around measured values when auto-setting alarm thresholds, in %")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("alarm-driving-pressure-high")
+                    .long("alarm-driving-pressure-high")
+                    .help("Driving pressure alarm threshold, in cmH2O (disabled by default)")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("alarm-compliance-low")
+                    .long("alarm-compliance-low")
+                    .help("Static compliance alarm threshold, in mL/cmH2O (disabled by default)")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("alarm-resistance-high")
+                    .long("alarm-resistance-high")
+                    .help("Airway resistance alarm threshold, in cmH2O/L/s (disabled by default)")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("weaning-rsbi-max")
+                    .long("weaning-rsbi-max")
+                    .help("Weaning trial pass criteria on RSBI, in breaths/min/L")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("weaning-rate-max")
+                    .long("weaning-rate-max")
+                    .help("Weaning trial pass criteria on spontaneous rate, in breaths/min")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("weaning-minute-volume-max")
+                    .long("weaning-minute-volume-max")
+                    .help("Weaning trial pass criteria on minute volume, in L/min")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::with_name("patient-id")
+                    .long("patient-id")
+                    .help("Identifier of the patient for the first session")
                     .takes_value(true),
             )
             .arg(
@@ -85,53 +183,217 @@ impl ConfigArguments {
                     .long("disable-lora")
                     .help("Disable LORA support"),
             )
+            .arg(
+                Arg::with_name("print-config")
+                    .long("print-config")
+                    .help("Print effective configuration values and their origin, then exit"),
+            )
             .arg(
                 Arg::with_name("lora-device")
                     .long("lora-device")
-                    .default_value("/dev/ttyAMA0")
-                    .help("Path to the LORA device"),
+                    .help("Path to the LORA device")
+                    .takes_value(true),
+            )
+            .subcommand(
+                SubCommand::with_name("check-locales")
+                    .about("Check that all locales are complete, then exit")
+                    .arg(
+                        Arg::with_name("root")
+                            .long("root")
+                            .help("Path to the source repository (defaults to the build one)")
+                            .takes_value(true),
+                    )
+                    .arg(
+                        Arg::with_name("overrides")
+                            .long("overrides")
+                            .help("Path to a locale overrides directory to check instead")
+                            .takes_value(true),
+                    ),
             )
             .get_matches();
 
+        // Check locales? (this is a development tool, that reads locales and sources from the \
+        //   repository, thus it does not need any other option)
+        if let Some(check_matches) = matches.subcommand_matches("check-locales") {
+            let report = match check_matches.value_of("overrides") {
+                Some(overrides) => LocaleOverrides::check_directory(Path::new(overrides)),
+                None => LocaleChecker::check(Path::new(
+                    check_matches
+                        .value_of("root")
+                        .unwrap_or(env!("CARGO_MANIFEST_DIR")),
+                )),
+            };
+
+            print!("{}", report);
+
+            std::process::exit(if report.is_ok() { 0 } else { 1 });
+        }
+
+        // Print layered configuration? (user settings are read without any side effect, as \
+        //   reading them for good may migrate or reset the user settings file)
+        if matches.is_present("print-config") {
+            ConfigLayers::resolve(&matches, &ConfigSettings::peek()).print();
+
+            std::process::exit(0);
+        }
+
+        // Resolve layered configuration (arguments take precedence over environment variables, \
+        //   which take precedence over configuration files and user settings, and then over \
+        //   defaults)
+        let layers = ConfigLayers::resolve(&matches, &APP_SETTINGS.read().unwrap());
+
         // Parse input mode
-        let mode = match (matches.value_of("port"), matches.value_of("input")) {
-            (Some(p), _) => RunMode::Port {
+        // Notice: as input modes may be set from different layers (eg. a port in the system \
+        //   configuration file, and an input file in arguments), the mode that is set from the \
+        //   layer with the highest precedence is used.
+        let mode = match layers.value_of_highest(&["port", "input", "tcp", "pipe"]) {
+            Some(("port", p)) => RunMode::Port {
                 port: p.to_string(),
-                output_dir: matches.value_of("output").map(|str| str.to_string()),
+                output_dir: layers.value_of("output").map(|str| str.to_string()),
+            },
+            Some(("input", i)) => RunMode::Input(i.to_string()),
+            Some(("tcp", t)) => RunMode::Tcp(t.to_string()),
+            Some(("pipe", p)) => RunMode::Pipe(p.to_string()),
+            _ => {
+                eprintln!(
+                    "You should provide either a serial port (-p), an input file (-i), a TCP \
+                    source (--tcp) or a pipe source (--pipe)"
+                );
+
+                std::process::exit(1);
+            }
+        };
+
+        // Parse alarm sound output
+        let alarm_sound = match layers.value_of("alarm-sound").unwrap_or("none") {
+            "none" => SoundOutput::None,
+            "aplay" => SoundOutput::Aplay,
+            value => match value.strip_prefix("wav:") {
+                Some(path) => SoundOutput::Wav(path.to_string()),
+                None => {
+                    eprintln!("Invalid alarm sound output: {}", value);
+
+                    std::process::exit(1);
+                }
             },
-            (None, Some(i)) => RunMode::Input(i.to_string()),
-            (None, None) => {
-                eprintln!("You should provide either a serial port (-p) or an input file (-i)");
+        };
+
+        // Parse alarms latch priority
+        // Notice: possible values are also checked there, as they are only checked by the \
+        //   arguments parser when passed as arguments (not when set from other layers).
+        let alarms_latch = match layers.value_of("alarms-latch").unwrap_or("high") {
+            "low" => AlarmPriority::Low,
+            "medium" => AlarmPriority::Medium,
+            "high" => AlarmPriority::High,
+            value => {
+                eprintln!("Invalid alarms latch priority: {}", value);
 
                 std::process::exit(1);
             }
         };
 
+        // Parse alarm thresholds auto-setting band
+        let alarms_auto_band = layers
+            .value_of("alarms-auto-band")
+            .map(|value| {
+                value.parse::<usize>().unwrap_or_else(|_| {
+                    eprintln!("Invalid alarms auto band: {}", value);
+
+                    std::process::exit(1);
+                })
+            })
+            .unwrap_or_else(|| {
+                eprintln!("Missing alarms auto band");
+
+                std::process::exit(1);
+            });
+
+        // Parse respiratory mechanics alarm thresholds
+        let parse_threshold = |name: &str| {
+            layers.value_of(name).map(|value| {
+                value.parse::<f64>().unwrap_or_else(|_| {
+                    eprintln!("Invalid {} threshold: {}", name, value);
+
+                    std::process::exit(1);
+                })
+            })
+        };
+
+        let mechanics_thresholds = ChipMechanicsThresholds {
+            driving_pressure_high: parse_threshold("alarm-driving-pressure-high"),
+            compliance_low: parse_threshold("alarm-compliance-low"),
+            resistance_high: parse_threshold("alarm-resistance-high"),
+        };
+
+        // Parse weaning trial pass criteria
+        let parse_criteria = |name: &str| {
+            parse_threshold(name).unwrap_or_else(|| {
+                eprintln!("Missing {} criteria", name);
+
+                std::process::exit(1);
+            })
+        };
+
+        let weaning_criteria = ChipWeaningCriteria {
+            rsbi_max: parse_criteria("weaning-rsbi-max"),
+            rate_max: parse_criteria("weaning-rate-max"),
+            minute_volume_max: parse_criteria("weaning-minute-volume-max"),
+        };
+
         // Generate owned app arguments
         ConfigArguments {
-            log: String::from(matches.value_of("log").expect("invalid log value")),
+            log: String::from(layers.value_of("log").expect("invalid log value")),
             translation: String::from(
-                matches
+                layers
                     .value_of("translation")
-                    .unwrap_or(&APP_SETTINGS.read().unwrap().locale),
+                    .expect("invalid translation value"),
             ),
             mode,
-            fullscreen: matches.is_present("fullscreen"),
+            alarm_sound,
+            alarms_latch,
+            alarms_auto_band,
+            mechanics_thresholds,
+            weaning_criteria,
+            patient_id: layers.value_of("patient-id").map(|value| value.to_string()),
+            // Notice: the maximum snooze duration from user settings is not kept as an override, \
+            //   as it can be changed at runtime from the UI.
+            snooze_max: layers
+                .value_of("snooze-max")
+                .filter(|_| layers.source_of("snooze-max") != Some(&ConfigLayerSource::Settings))
+                .map(|value| {
+                    Duration::from_secs(value.parse().unwrap_or_else(|_| {
+                        eprintln!("Invalid snooze max duration: {}", value);
+
+                        std::process::exit(1);
+                    }))
+                }),
+            fullscreen: layers.is_present("fullscreen"),
             #[cfg(feature = "lora")]
-            lora: !matches.is_present("disable-lora"),
+            lora: !layers.is_present("disable-lora"),
             #[cfg(feature = "lora")]
             lora_device: String::from(
-                matches
+                layers
                     .value_of("lora-device")
                     .expect("invalid lora-device value"),
             ),
         }
     }
 
+    pub fn snooze_max(&self) -> Duration {
+        // Notice: the maximum snooze duration passed as an argument takes precedence over the \
+        //   one saved in settings, which can be changed at runtime.
+        self.snooze_max
+            .unwrap_or_else(|| APP_SETTINGS.read().unwrap().snooze_max())
+    }
+
     pub fn is_recording(&self) -> bool {
+        self.record_directory().is_some()
+    }
+
+    pub fn record_directory(&self) -> Option<&str> {
         match &self.mode {
-            RunMode::Port { output_dir, .. } => output_dir.is_some(),
-            _ => false,
+            RunMode::Port { output_dir, .. } => output_dir.as_deref(),
+            _ => None,
         }
     }
 }
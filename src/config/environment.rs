@@ -6,6 +6,12 @@
 pub const RUNTIME_NAME: &str = env!("CARGO_PKG_NAME");
 pub const RUNTIME_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub const CONFIG_SYSTEM_OPTIONS_PATH: &str =
+    concat!("/etc/", env!("CARGO_PKG_NAME"), "/options.toml");
+pub const CONFIG_OPTIONS_FILE_NAME: &str = "options.toml";
+pub const CONFIG_SYSTEM_LOCALES_PATH: &str = concat!("/etc/", env!("CARGO_PKG_NAME"), "/locales");
+pub const CONFIG_LOCALES_DIRECTORY_NAME: &str = "locales";
+
 pub const WINDOW_ICON_WIDTH: u32 = 512;
 pub const WINDOW_ICON_HEIGHT: u32 = 512;
 
@@ -107,6 +113,8 @@ pub const STATUS_BOX_RECORDING_PADDING_LEFT: f64 = 12.0;
 pub const STATUS_BOX_RECORDING_PADDING_RIGHT: f64 = 5.0;
 pub const STATUS_RECORDING_OUTER_RADIUS: f64 = 6.0;
 pub const STATUS_RECORDING_INNER_RADIUS: f64 = 5.0;
+pub const STATUS_SNOOZE_WIDTH: f64 = 120.0;
+pub const STATUS_SNOOZE_MARGIN_RIGHT: f64 = 8.0;
 
 pub const HEARTBEAT_GROUND_DIAMETER: f64 = 14.0;
 pub const HEARTBEAT_SURROUND_MARGIN_TOP: f64 = 4.0;
@@ -136,6 +144,7 @@ pub const DISPLAY_ALARM_MESSAGE_SPACING_TOP_INITIAL_OFFSET: f64 = 5.0;
 pub const DISPLAY_ALARM_MESSAGE_SPACING_TOP_INITIAL: f64 = 10.0;
 pub const DISPLAY_ALARM_MESSAGE_SPACING_BOTTOM_INITIAL: f64 = 5.0;
 pub const DISPLAY_ALARM_MESSAGE_FONT_SIZE: u32 = 17;
+pub const DISPLAY_ALARM_MESSAGE_RESOLVED_FONT_SIZE: u32 = 14;
 
 pub const DISPLAY_ALARM_CODE_WIDTH: f64 = 32.0;
 pub const DISPLAY_ALARM_CODE_HEIGHT: f64 = DISPLAY_ALARM_MESSAGE_HEIGHT;
@@ -152,8 +161,10 @@ pub const TELEMETRY_POINTS_PRESSURE_PRECISION_DIVIDE: i32 = 10;
 pub const TELEMETRY_POINTS_FLOW_PRECISION_DIVIDE: i32 = 100;
 pub const TELEMETRY_POINTS_PER_SECOND: usize = 40;
 pub const TELEMETRY_POINTS_LOW_PASS_DEGREE: i16 = 2;
+pub const TELEMETRY_STALE_DATA_SNAPSHOT_AFTER_MILLISECONDS: u64 = 2000;
+pub const TELEMETRY_STALE_MACHINE_SNAPSHOT_AFTER_MILLISECONDS: u64 = 20000;
 
-pub const TELEMETRY_WIDGET_BOTTOM_COUNT: f64 = 4.0;
+pub const TELEMETRY_WIDGET_BOTTOM_COUNT: f64 = 5.0;
 pub const TELEMETRY_WIDGET_RIGHT_COUNT: f64 = 3.0;
 pub const TELEMETRY_WIDGET_SPACING_SIDES: f64 = 4.0;
 pub const TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH: f64 = DISPLAY_WINDOW_SIZE_WIDTH as f64
@@ -187,6 +198,15 @@ pub const TELEMETRY_ARROW_LINE_WIDTH: u32 = 6;
 pub const TELEMETRY_ARROW_LINE_HEIGHT: u32 = 3;
 pub const TELEMETRY_ARROW_SPACING_SIDES: f64 = 5.0;
 
+pub const TELEMETRY_STALE_MESSAGE_WIDTH: f64 = 340.0;
+pub const TELEMETRY_STALE_MESSAGE_HEIGHT: f64 = 36.0;
+pub const TELEMETRY_STALE_MESSAGE_FONT_SIZE: u32 = 17;
+
+pub const SETTINGS_NOTICE_WIDTH: f64 = 480.0;
+pub const SETTINGS_NOTICE_HEIGHT: f64 = 30.0;
+pub const SETTINGS_NOTICE_MARGIN_TOP: f64 = 8.0;
+pub const SETTINGS_NOTICE_FONT_SIZE: u32 = 13;
+
 pub const GRAPH_DRAW_SECONDS: i64 = 5;
 pub const GRAPH_DRAW_PRESSURE_RANGE_LOW: i32 = -10;
 pub const GRAPH_DRAW_PRESSURE_RANGE_LOW_PRECISION_DIVIDED: i32 =
@@ -215,6 +235,13 @@ pub const GRAPH_DRAW_MARGIN_RIGHT: u32 = 0;
 pub const GRAPH_DRAW_LINE_SIZE: u32 = 2;
 pub const GRAPH_DRAW_AXIS_SIZE: u32 = 1;
 pub const GRAPH_DRAW_AXIS_FONT_SIZE: u32 = 14;
+pub const GRAPH_DRAW_MARKER_SIZE: u32 = 1;
+pub const GRAPH_DRAW_MARKER_FONT_SIZE: u32 = 12;
+pub const GRAPH_DRAW_EVENT_MERGE_MILLISECONDS: i64 = 1000;
+pub const GRAPH_DRAW_GLYPH_SIZE: u32 = 5;
+pub const GRAPH_DRAW_GLYPH_OFFSET_RATIO: i32 = 20;
+pub const GRAPH_DRAW_LIMIT_SIZE: u32 = 1;
+pub const GRAPH_DRAW_LIMIT_DASHES: i32 = 60;
 pub const GRAPH_DRAW_LABEL_WIDTH: u32 = 56;
 pub const GRAPH_DRAW_LABEL_NUMBER_MAX: usize = 5;
 pub const GRAPH_LABEL_BOX_WIDTH: f64 = (GRAPH_DRAW_LABEL_WIDTH - 2 * GRAPH_DRAW_AXIS_SIZE) as f64;
@@ -265,8 +292,9 @@ pub const ADVANCED_SETTINGS_LINES_COUNT: usize = 15;
 pub const ADVANCED_SETTINGS_LINE_MARGIN_TOP: f64 = 8.0;
 pub const ADVANCED_SETTINGS_LINE_FONT_SIZE: u32 = 14;
 pub const ADVANCED_SETTINGS_LINE_VALUE_PADDING_LEFT: f64 = 240.0;
-pub const ADVANCED_SETTINGS_GROUP_TABS_COUNT: usize = 2;
+pub const ADVANCED_SETTINGS_GROUP_TABS_COUNT: usize = 6;
 pub const ADVANCED_SETTINGS_LINE_VALUE_EMPTY: &str = "--";
+pub const ADVANCED_SETTINGS_WEANING_BUTTON_WIDTH: f64 = 220.0;
 
 pub const MODE_SETTINGS_MODAL_PADDING: f64 = 20.0;
 pub const MODE_SETTINGS_MODAL_WIDTH: f64 = 740.0;
@@ -276,6 +304,10 @@ pub const MODE_SETTINGS_MODAL_FORM_FIELD_HEIGHT_PADDED: f64 = 42.0;
 pub const MODE_SETTINGS_SELECTOR_TABS_COUNT: usize = 5;
 pub const MODE_SETTINGS_SELECTOR_TABS_HEIGHT: f64 = 48.0;
 pub const MODE_SETTINGS_GROUP_TABS_COUNT: usize = 2;
+pub const MODE_SETTINGS_LEAK_MEASURED_MARGIN_LEFT: f64 = 16.0;
+pub const MODE_SETTINGS_LEAK_MEASURED_FONT_SIZE: u32 = 14;
+pub const MODE_SETTINGS_ALARMS_AUTO_MARGIN_TOP: f64 = 18.0;
+pub const MODE_SETTINGS_ALARMS_AUTO_FONT_SIZE: u32 = 14;
 
 pub const BUTTON_HEIGHT: f64 = 34.0;
 pub const BUTTON_BORDER_RADIUS: f64 = BUTTON_HEIGHT / 2.0;
@@ -313,3 +345,45 @@ pub const LAYOUT_TEXTURE_HEADER_HEIGHT: u32 = 52;
 
 #[cfg(feature = "lora")]
 pub const LORA_GPIO_PIN_NUMBER: u64 = 25;
+
+pub const SOUND_SAMPLE_RATE: u32 = 22050;
+pub const SOUND_CHUNK_MILLISECONDS: u32 = 50;
+pub const SOUND_PREROLL_CHUNKS: u32 = 4;
+pub const SOUND_TONE_FUNDAMENTAL_FREQUENCY: f64 = 523.25;
+pub const SOUND_TONE_HARMONICS: u32 = 5;
+pub const SOUND_TONE_VOLUME: f64 = 0.6;
+pub const SOUND_PULSE_RAMP_MILLISECONDS: u32 = 20;
+
+pub const MECHANICS_SMOOTHING_FACTOR: f64 = 0.3;
+pub const MECHANICS_INTEGRATE_MAXIMUM_GAP_MICROSECONDS: u64 = 200_000;
+
+pub const WEANING_TRIAL_DURATIONS_MINUTES: [u64; 3] = [30, 60, 120];
+
+pub const ALARMS_AUTO_SET_CYCLES: usize = 5;
+
+pub const BREATHS_TRIGGERED_CYCLES: usize = 20;
+pub const BREATHS_TRIGGERED_PERIOD_TOLERANCE: f64 = 0.05;
+
+pub const GRAPH_FREEZE_CURSOR_TOLERANCE_MILLISECONDS: i64 = 100;
+pub const GRAPH_FREEZE_BUTTON_WIDTH: f64 = 100.0;
+pub const GRAPH_FREEZE_BUTTON_MARGIN: f64 = 8.0;
+pub const GRAPH_FREEZE_BUTTON_FONT_SIZE: u32 = 14;
+pub const GRAPH_FREEZE_BUTTON_VALUE_TOP: f64 = 7.0;
+pub const GRAPH_CURSOR_LINE_THICKNESS: f64 = 2.0;
+pub const GRAPH_CURSOR_BOX_WIDTH: f64 = 300.0;
+pub const GRAPH_CURSOR_BOX_HEIGHT: f64 = 28.0;
+pub const GRAPH_CURSOR_BOX_MARGIN_TOP: f64 = 50.0;
+pub const GRAPH_CURSOR_BOX_FONT_SIZE: u32 = 13;
+
+pub const SETTINGS_VERSION: u32 = 2;
+pub const SETTINGS_GRAPH_SECONDS_OPTIONS: [u8; 3] = [5, 10, 15];
+pub const SETTINGS_SNOOZE_MAX_SECONDS_DEFAULT: u64 = 120;
+pub const SETTINGS_SNOOZE_MAX_SECONDS_MIN: u64 = 30;
+pub const SETTINGS_SNOOZE_MAX_SECONDS_MAX: u64 = 300;
+pub const SETTINGS_SNOOZE_MAX_SECONDS_STEP: u64 = 30;
+pub const SETTINGS_BRIGHTNESS_DEFAULT: u8 = 100;
+pub const SETTINGS_BRIGHTNESS_MIN: u8 = 10;
+pub const SETTINGS_BRIGHTNESS_MAX: u8 = 100;
+pub const SETTINGS_BRIGHTNESS_STEP: u8 = 10;
+
+pub const BACKLIGHT_DEVICES_PATH: &str = "/sys/class/backlight";
@@ -0,0 +1,584 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+
+use super::environment::*;
+use super::settings::ConfigSettings;
+
+#[cfg(debug_assertions)]
+const LOG_LEVEL_DEFAULT: &str = "debug";
+#[cfg(not(debug_assertions))]
+const LOG_LEVEL_DEFAULT: &str = "warn";
+
+const ENVIRONMENT_PREFIX: &str = "MAKAIR_CONTROL_";
+
+// Notice: this list holds all options that can be layered, in the order they get printed. Each \
+//   option is identified by its argument name, which is also used as its key in configuration \
+//   files (and, in upper snake case, as its environment variable name).
+const OPTIONS: &[ConfigOption] = &[
+    ConfigOption::value("log", Some(LOG_LEVEL_DEFAULT)),
+    ConfigOption::value("port", None),
+    ConfigOption::value("input", None),
+    ConfigOption::value("tcp", None),
+    ConfigOption::value("pipe", None),
+    ConfigOption::value("output", None),
+    ConfigOption::value("alarm-sound", Some("none")),
+    ConfigOption::value("snooze-max", None),
+    ConfigOption::value("alarms-latch", Some("high")),
+    ConfigOption::value("alarms-auto-band", Some("20")),
+    ConfigOption::value("alarm-driving-pressure-high", None),
+    ConfigOption::value("alarm-compliance-low", None),
+    ConfigOption::value("alarm-resistance-high", None),
+    ConfigOption::value("weaning-rsbi-max", Some("105")),
+    ConfigOption::value("weaning-rate-max", Some("35")),
+    ConfigOption::value("weaning-minute-volume-max", Some("10")),
+    ConfigOption::value("patient-id", None),
+    ConfigOption::flag("fullscreen"),
+    ConfigOption::value("translation", None),
+    ConfigOption::flag("disable-lora"),
+    ConfigOption::value("lora-device", Some("/dev/ttyAMA0")),
+];
+
+struct ConfigOption {
+    name: &'static str,
+    default: Option<&'static str>,
+    flag: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigLayerSource {
+    Default,
+    SystemFile(PathBuf),
+    Settings,
+    UserFile(PathBuf),
+    Environment(String),
+    Arguments,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum ConfigFileValue {
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+}
+
+pub struct ConfigLayers {
+    values: HashMap<&'static str, (String, ConfigLayerSource)>,
+}
+
+impl ConfigOption {
+    const fn value(name: &'static str, default: Option<&'static str>) -> Self {
+        ConfigOption {
+            name,
+            default,
+            flag: false,
+        }
+    }
+
+    const fn flag(name: &'static str) -> Self {
+        ConfigOption {
+            name,
+            default: None,
+            flag: true,
+        }
+    }
+
+    fn environment_names(&self) -> Vec<String> {
+        let mut names = vec![format!(
+            "{}{}",
+            ENVIRONMENT_PREFIX,
+            self.name.to_uppercase().replace('-', "_")
+        )];
+
+        // Notice: this name was used before options could be layered, and is still used by \
+        //   existing system images (where it is set by the USB recording setup).
+        if self.name == "output" {
+            names.push("OUTPUT_DIR".to_string());
+        }
+
+        names
+    }
+}
+
+impl ConfigLayerSource {
+    fn precedence(&self) -> u8 {
+        // Notice: only the layer matters there, not where the value was read from within the \
+        //   layer (eg. the name of the environment variable).
+        match self {
+            Self::Default => 0,
+            Self::SystemFile(_) => 1,
+            Self::Settings => 2,
+            Self::UserFile(_) => 3,
+            Self::Environment(_) => 4,
+            Self::Arguments => 5,
+        }
+    }
+}
+
+impl fmt::Display for ConfigLayerSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Settings => write!(f, "user settings"),
+            Self::SystemFile(path) => write!(f, "system file {}", path.display()),
+            Self::UserFile(path) => write!(f, "user file {}", path.display()),
+            Self::Environment(name) => write!(f, "environment {}", name),
+            Self::Arguments => write!(f, "arguments"),
+        }
+    }
+}
+
+impl fmt::Display for ConfigFileValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(value) => write!(f, "{}", value),
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Float(value) => write!(f, "{}", value),
+            Self::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl ConfigLayers {
+    pub fn resolve(matches: &ArgMatches, settings: &ConfigSettings) -> Self {
+        let mut layers = ConfigLayers {
+            values: HashMap::new(),
+        };
+
+        // Apply all layers, from the lowest precedence to the highest precedence (each layer \
+        //   overrides values set by the previous ones)
+        // Notice: user settings take precedence over the system file, as they hold values that \
+        //   were picked by the end-user from the UI (eg. the locale).
+        layers.apply_defaults();
+        layers.apply_file(
+            Path::new(CONFIG_SYSTEM_OPTIONS_PATH),
+            ConfigLayerSource::SystemFile,
+        );
+        layers.apply_settings(settings);
+
+        if let Some(path) = Self::user_file_path() {
+            layers.apply_file(&path, ConfigLayerSource::UserFile);
+        }
+
+        layers.apply_environment(|name| std::env::var(name).ok());
+        layers.apply_arguments(matches);
+
+        layers
+    }
+
+    pub fn value_of(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|(value, _)| value.as_str())
+    }
+
+    pub fn source_of(&self, name: &str) -> Option<&ConfigLayerSource> {
+        self.values.get(name).map(|(_, source)| source)
+    }
+
+    pub fn value_of_highest(&self, names: &[&'static str]) -> Option<(&'static str, &str)> {
+        // Pick the value that is set from the layer with the highest precedence, amongst \
+        //   exclusive options (if set from the same layer, the first option in the list wins)
+        let precedence = names
+            .iter()
+            .filter_map(|name| self.source_of(name))
+            .map(ConfigLayerSource::precedence)
+            .max()?;
+
+        names.iter().find_map(|name| match self.values.get(name) {
+            Some((value, source)) if source.precedence() == precedence => {
+                Some((*name, value.as_str()))
+            }
+            _ => None,
+        })
+    }
+
+    pub fn is_present(&self, name: &str) -> bool {
+        self.value_of(name)
+            .map(|value| Self::parse_flag(name, value))
+            .unwrap_or(false)
+    }
+
+    pub fn print(&self) {
+        for option in OPTIONS {
+            match self.values.get(option.name) {
+                Some((value, source)) => println!("{} = {} ({})", option.name, value, source),
+                None => println!("{} is not set", option.name),
+            }
+        }
+    }
+
+    fn apply_defaults(&mut self) {
+        for option in OPTIONS {
+            if let Some(default) = option.default {
+                self.set(option.name, default.to_string(), ConfigLayerSource::Default);
+            }
+        }
+    }
+
+    fn apply_settings(&mut self, settings: &ConfigSettings) {
+        // Some options are also user settings that can be changed from the UI, which override \
+        //   defaults and the system file (though user files, environment and arguments override \
+        //   them)
+
+        self.set(
+            "translation",
+            settings.locale.to_owned(),
+            ConfigLayerSource::Settings,
+        );
+        self.set(
+            "snooze-max",
+            settings.snooze_max_seconds.to_string(),
+            ConfigLayerSource::Settings,
+        );
+
+        // Notice: a missing record directory is kept in settings (it may only be missing for \
+        //   now), though it is not used, as recording to it would fail.
+        if let Some(record_directory) = &settings.record_directory {
+            if Path::new(record_directory).is_dir() {
+                self.set(
+                    "output",
+                    record_directory.to_owned(),
+                    ConfigLayerSource::Settings,
+                );
+            } else {
+                eprintln!(
+                    "Ignoring missing record directory from user settings: {}",
+                    record_directory
+                );
+            }
+        }
+    }
+
+    fn apply_file<F>(&mut self, path: &Path, source: F)
+    where
+        F: Fn(PathBuf) -> ConfigLayerSource,
+    {
+        // Notice: the file must exist before it is loaded, as loading a missing file would \
+        //   create it with default values (which is not wanted, especially for the system file).
+        if !path.is_file() {
+            return;
+        }
+
+        let entries = match confy::load_path::<HashMap<String, ConfigFileValue>>(path) {
+            Ok(entries) => entries,
+            Err(err) => {
+                // Notice: the logger is not initialized yet, thus errors are printed directly.
+                eprintln!(
+                    "Ignoring unreadable configuration file {}: {:?}",
+                    path.display(),
+                    err
+                );
+
+                return;
+            }
+        };
+
+        for (key, value) in entries {
+            match OPTIONS.iter().find(|option| option.name == key) {
+                Some(option) => self.set(option.name, value.to_string(), source(path.to_owned())),
+                None => eprintln!(
+                    "Ignoring unknown option in configuration file {}: {}",
+                    path.display(),
+                    key
+                ),
+            }
+        }
+    }
+
+    fn apply_environment<F>(&mut self, variable_of: F)
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        for option in OPTIONS {
+            // Notice: the first environment variable that is set wins, as alternative names are \
+            //   only kept for compatibility purposes.
+            let variable = option
+                .environment_names()
+                .into_iter()
+                .find_map(|name| variable_of(&name).map(|value| (name, value)));
+
+            if let Some((name, value)) = variable {
+                self.set(option.name, value, ConfigLayerSource::Environment(name));
+            }
+        }
+    }
+
+    fn apply_arguments(&mut self, matches: &ArgMatches) {
+        for option in OPTIONS {
+            if option.flag {
+                if matches.is_present(option.name) {
+                    self.set(
+                        option.name,
+                        "true".to_string(),
+                        ConfigLayerSource::Arguments,
+                    );
+                }
+            } else if let Some(value) = matches.value_of(option.name) {
+                self.set(option.name, value.to_string(), ConfigLayerSource::Arguments);
+            }
+        }
+    }
+
+    fn set(&mut self, name: &'static str, value: String, source: ConfigLayerSource) {
+        self.values.insert(name, (value, source));
+    }
+
+    fn parse_flag(name: &str, value: &str) -> bool {
+        match value.to_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => true,
+            "false" | "no" | "off" | "0" => false,
+            _ => {
+                eprintln!("Invalid {} flag value: {}", name, value);
+
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn user_file_path() -> Option<PathBuf> {
+        // The user options file lives next to the user settings file
+        confy::get_configuration_file_path(RUNTIME_NAME)
+            .ok()
+            .and_then(|path| {
+                path.parent()
+                    .map(|parent| parent.join(CONFIG_OPTIONS_FILE_NAME))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::{App, Arg};
+
+    use super::*;
+
+    fn empty_layers() -> ConfigLayers {
+        ConfigLayers {
+            values: HashMap::new(),
+        }
+    }
+
+    fn arguments(args: &[&'static str]) -> ArgMatches<'static> {
+        App::new("test")
+            .arg(Arg::with_name("port").long("port").takes_value(true))
+            .arg(Arg::with_name("input").long("input").takes_value(true))
+            .arg(
+                Arg::with_name("alarms-latch")
+                    .long("alarms-latch")
+                    .takes_value(true),
+            )
+            .arg(Arg::with_name("fullscreen").long("fullscreen"))
+            .get_matches_from(std::iter::once("test").chain(args.iter().cloned()))
+    }
+
+    fn options_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("makair-control-layers-{}.toml", name));
+
+        std::fs::write(&path, contents).unwrap();
+
+        path
+    }
+
+    fn environment(
+        variables: &'static [(&'static str, &'static str)],
+    ) -> impl Fn(&str) -> Option<String> {
+        move |name| {
+            variables
+                .iter()
+                .find(|(variable, _)| *variable == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn applies_layers_in_order() {
+        let path = options_file(
+            "order",
+            "port = \"/dev/file\"\nalarms-latch = \"low\"\nalarms-auto-band = 25\n",
+        );
+
+        let mut layers = empty_layers();
+
+        layers.apply_defaults();
+        layers.apply_file(&path, ConfigLayerSource::SystemFile);
+        layers.apply_environment(environment(&[
+            ("MAKAIR_CONTROL_ALARMS_LATCH", "medium"),
+            ("MAKAIR_CONTROL_PORT", "/dev/environment"),
+        ]));
+        layers.apply_arguments(&arguments(&["--port", "/dev/arguments"]));
+
+        // Defaults are overridden by the file, which is overridden by the environment, which \
+        //   is overridden by arguments
+        assert_eq!(layers.value_of("log"), Some(LOG_LEVEL_DEFAULT));
+        assert_eq!(layers.source_of("log"), Some(&ConfigLayerSource::Default));
+
+        assert_eq!(layers.value_of("alarms-auto-band"), Some("25"));
+        assert_eq!(
+            layers.source_of("alarms-auto-band"),
+            Some(&ConfigLayerSource::SystemFile(path.clone()))
+        );
+
+        assert_eq!(layers.value_of("alarms-latch"), Some("medium"));
+        assert_eq!(
+            layers.source_of("alarms-latch"),
+            Some(&ConfigLayerSource::Environment(
+                "MAKAIR_CONTROL_ALARMS_LATCH".to_string()
+            ))
+        );
+
+        assert_eq!(layers.value_of("port"), Some("/dev/arguments"));
+        assert_eq!(
+            layers.source_of("port"),
+            Some(&ConfigLayerSource::Arguments)
+        );
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn applies_settings_over_system_file() {
+        let system_path = options_file("settings-system", "translation = \"de\"\n");
+        let user_path = options_file("settings-user", "snooze-max = 60\n");
+
+        let settings = ConfigSettings {
+            locale: "fr".to_string(),
+            snooze_max_seconds: 180,
+            ..ConfigSettings::default()
+        };
+
+        let mut layers = empty_layers();
+
+        layers.apply_defaults();
+        layers.apply_file(&system_path, ConfigLayerSource::SystemFile);
+        layers.apply_settings(&settings);
+        layers.apply_file(&user_path, ConfigLayerSource::UserFile);
+
+        // The locale picked from the UI wins over the system file, though user files win over \
+        //   user settings
+        assert_eq!(layers.value_of("translation"), Some("fr"));
+        assert_eq!(
+            layers.source_of("translation"),
+            Some(&ConfigLayerSource::Settings)
+        );
+
+        assert_eq!(layers.value_of("snooze-max"), Some("60"));
+        assert_eq!(
+            layers.source_of("snooze-max"),
+            Some(&ConfigLayerSource::UserFile(user_path.clone()))
+        );
+
+        let _ = std::fs::remove_file(system_path);
+        let _ = std::fs::remove_file(user_path);
+    }
+
+    #[test]
+    fn supports_legacy_output_variable() {
+        let mut layers = empty_layers();
+
+        layers.apply_environment(environment(&[("OUTPUT_DIR", "/mnt/legacy")]));
+
+        assert_eq!(layers.value_of("output"), Some("/mnt/legacy"));
+        assert_eq!(
+            layers.source_of("output"),
+            Some(&ConfigLayerSource::Environment("OUTPUT_DIR".to_string()))
+        );
+
+        // The prefixed variable wins over the legacy one
+        layers.apply_environment(environment(&[
+            ("OUTPUT_DIR", "/mnt/legacy"),
+            ("MAKAIR_CONTROL_OUTPUT", "/mnt/prefixed"),
+        ]));
+
+        assert_eq!(layers.value_of("output"), Some("/mnt/prefixed"));
+    }
+
+    #[test]
+    fn resolves_flags() {
+        let path = options_file("flags", "fullscreen = true\n");
+
+        let mut layers = empty_layers();
+
+        assert!(!layers.is_present("fullscreen"));
+
+        layers.apply_file(&path, ConfigLayerSource::SystemFile);
+
+        assert!(layers.is_present("fullscreen"));
+
+        layers.apply_environment(environment(&[("MAKAIR_CONTROL_FULLSCREEN", "no")]));
+
+        assert!(!layers.is_present("fullscreen"));
+
+        // Flags not passed as arguments do not override other layers
+        layers.apply_arguments(&arguments(&[]));
+
+        assert!(!layers.is_present("fullscreen"));
+
+        layers.apply_arguments(&arguments(&["--fullscreen"]));
+
+        assert!(layers.is_present("fullscreen"));
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn selects_mode_from_highest_layer() {
+        let path = options_file("mode", "port = \"/dev/makair\"\n");
+
+        let mut layers = empty_layers();
+
+        assert_eq!(layers.value_of_highest(&["port", "input"]), None);
+
+        layers.apply_file(&path, ConfigLayerSource::SystemFile);
+
+        assert_eq!(
+            layers.value_of_highest(&["port", "input"]),
+            Some(("port", "/dev/makair"))
+        );
+
+        // An input set from a higher layer wins over a port set from the system file
+        layers.apply_arguments(&arguments(&["--input", "test.record"]));
+
+        assert_eq!(
+            layers.value_of_highest(&["port", "input"]),
+            Some(("input", "test.record"))
+        );
+
+        // The first option wins if both are set from the same layer
+        layers.apply_arguments(&arguments(&["--port", "/dev/other"]));
+
+        assert_eq!(
+            layers.value_of_highest(&["port", "input"]),
+            Some(("port", "/dev/other"))
+        );
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn selects_first_mode_from_environment() {
+        let mut layers = empty_layers();
+
+        // Both modes are set from the environment, though from different variables
+        layers.apply_environment(environment(&[
+            ("MAKAIR_CONTROL_TCP", "127.0.0.1:8080"),
+            ("MAKAIR_CONTROL_INPUT", "test.record"),
+        ]));
+
+        assert_eq!(
+            layers.value_of_highest(&["input", "tcp"]),
+            Some(("input", "test.record"))
+        );
+        assert_eq!(
+            layers.value_of_highest(&["tcp", "input"]),
+            Some(("tcp", "127.0.0.1:8080"))
+        );
+    }
+}
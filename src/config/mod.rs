@@ -6,5 +6,6 @@
 pub mod arguments;
 pub mod context;
 pub mod environment;
+pub mod layers;
 pub mod logger;
 pub mod settings;
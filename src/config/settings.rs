@@ -3,15 +3,76 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use std::io;
+use std::time::Duration;
+
 use confy::{self, ConfyError};
 
+use crate::chip::settings::preset::{SettingsPresetAge, SettingsPresetGender};
 use crate::locale::locales::LocaleCode;
 
-use super::environment::RUNTIME_NAME;
+use super::environment::*;
 
 #[derive(Serialize, Deserialize)]
+#[serde(default)]
 pub struct ConfigSettings {
+    // Notice: configuration files saved before the schema got versioned have no version, \
+    //   thus they are considered as version 1 files.
+    #[serde(default = "ConfigSettings::legacy_version")]
+    pub version: u32,
     pub locale: String,
+    pub graph_seconds: u8,
+    pub pressure_unit: ConfigSettingsPressureUnit,
+    pub flow_unit: ConfigSettingsFlowUnit,
+    pub volume_unit: ConfigSettingsVolumeUnit,
+    pub snooze_max_seconds: u64,
+    pub record_directory: Option<String>,
+    pub brightness: u8,
+    pub preset_age: SettingsPresetAge,
+    pub preset_gender: SettingsPresetGender,
+    pub theme: ConfigSettingsTheme,
+    #[serde(skip)]
+    pub warning: Option<ConfigSettingsWarning>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigSettingsPressureUnit {
+    CmH2O,
+    Mbar,
+    HPa,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub enum ConfigSettingsFlowUnit {
+    #[serde(rename = "lpm")]
+    LitersPerMinute,
+    #[serde(rename = "mlps")]
+    MillilitersPerSecond,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub enum ConfigSettingsVolumeUnit {
+    #[serde(rename = "ml")]
+    Milliliters,
+    #[serde(rename = "l")]
+    Liters,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSettingsTheme {
+    Default,
+    Night,
+    HighContrast,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ConfigSettingsWarning {
+    // The configuration file could not be read, and was reset to defaults
+    Corrupt,
+    // Some configuration values were not valid, and were reset to defaults
+    Invalid,
 }
 
 pub enum ConfigSettingsUpdateMay {
@@ -22,24 +83,177 @@ pub enum ConfigSettingsUpdateMay {
 impl Default for ConfigSettings {
     fn default() -> Self {
         Self {
+            version: SETTINGS_VERSION,
             locale: LocaleCode::default().to_code().to_string(),
+            graph_seconds: GRAPH_DRAW_SECONDS as u8,
+            pressure_unit: ConfigSettingsPressureUnit::default(),
+            flow_unit: ConfigSettingsFlowUnit::default(),
+            volume_unit: ConfigSettingsVolumeUnit::default(),
+            snooze_max_seconds: SETTINGS_SNOOZE_MAX_SECONDS_DEFAULT,
+            record_directory: None,
+            brightness: SETTINGS_BRIGHTNESS_DEFAULT,
+            preset_age: SettingsPresetAge::default(),
+            preset_gender: SettingsPresetGender::default(),
+            theme: ConfigSettingsTheme::default(),
+            warning: None,
+        }
+    }
+}
+
+impl Default for ConfigSettingsPressureUnit {
+    fn default() -> Self {
+        Self::CmH2O
+    }
+}
+
+impl Default for ConfigSettingsFlowUnit {
+    fn default() -> Self {
+        Self::LitersPerMinute
+    }
+}
+
+impl Default for ConfigSettingsVolumeUnit {
+    fn default() -> Self {
+        Self::Milliliters
+    }
+}
+
+impl Default for ConfigSettingsTheme {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+impl ConfigSettingsPressureUnit {
+    pub fn list_all() -> [Self; 3] {
+        [Self::CmH2O, Self::Mbar, Self::HPa]
+    }
+
+    pub fn to_locale_key(&self) -> &'static str {
+        match self {
+            Self::CmH2O => "telemetry-unit-cmh2o",
+            Self::Mbar => "telemetry-unit-mbar",
+            Self::HPa => "telemetry-unit-hpa",
+        }
+    }
+
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            Self::CmH2O => "cmh2o",
+            Self::Mbar => "mbar",
+            Self::HPa => "hpa",
+        }
+    }
+}
+
+impl ConfigSettingsFlowUnit {
+    pub fn list_all() -> [Self; 2] {
+        [Self::LitersPerMinute, Self::MillilitersPerSecond]
+    }
+
+    pub fn to_locale_key(&self) -> &'static str {
+        match self {
+            Self::LitersPerMinute => "telemetry-unit-lpm",
+            Self::MillilitersPerSecond => "telemetry-unit-mlps",
+        }
+    }
+
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            Self::LitersPerMinute => "lpm",
+            Self::MillilitersPerSecond => "mlps",
+        }
+    }
+}
+
+impl ConfigSettingsVolumeUnit {
+    pub fn list_all() -> [Self; 2] {
+        [Self::Milliliters, Self::Liters]
+    }
+
+    pub fn to_locale_key(&self) -> &'static str {
+        match self {
+            Self::Milliliters => "telemetry-unit-milliliters",
+            Self::Liters => "telemetry-unit-liters",
+        }
+    }
+
+    pub fn decimals(&self) -> usize {
+        // Volumes in liters need decimals to be readable (eg. 0.45 L for a tidal volume)
+        match self {
+            Self::Milliliters => 0,
+            Self::Liters => 2,
+        }
+    }
+}
+
+impl ConfigSettingsTheme {
+    pub fn list_all() -> [Self; 3] {
+        [Self::Default, Self::Night, Self::HighContrast]
+    }
+
+    pub fn to_locale_key(&self) -> &'static str {
+        match self {
+            Self::Default => "modal-advanced-theme-default",
+            Self::Night => "modal-advanced-theme-night",
+            Self::HighContrast => "modal-advanced-theme-high-contrast",
         }
     }
 }
 
 impl ConfigSettings {
     pub fn read() -> Self {
-        if let Ok(configuration) = confy::load::<Self>(RUNTIME_NAME) {
-            Self::check_configuration(configuration)
-        } else {
-            Self::default()
+        let (configuration, should_save) = Self::load(confy::load::<Self>(RUNTIME_NAME));
+
+        // Notice: the unreadable configuration file is kept aside rather than being overwritten \
+        //   with defaults, so that it can be inspected later on.
+        if configuration.warning == Some(ConfigSettingsWarning::Corrupt) {
+            Self::backup_corrupt_file();
+        }
+
+        // Save the configuration file back if it was upgraded, reset or if some of its values \
+        //   were fixed (so that it gets read as-is on next start)
+        if should_save {
+            if let Err(err) = configuration.save() {
+                error!("error saving upgraded settings: {:?}", err);
+            }
         }
+
+        configuration
+    }
+
+    pub fn peek() -> Self {
+        // Read settings without any side effect (ie. the configuration file does not get \
+        //   created if missing, nor saved back if upgraded, nor kept aside if corrupt)
+        let loaded = match confy::get_configuration_file_path(RUNTIME_NAME) {
+            Ok(path) if path.is_file() => confy::load_path::<Self>(path),
+            _ => Ok(Self::default()),
+        };
+
+        Self::load(loaded).0
     }
 
     pub fn save(&self) -> Result<(), ConfyError> {
+        // Refuse to overwrite a configuration file saved by a newer version, as it would be \
+        //   downgraded (values unknown to this version would be lost)
+        if self.is_from_newer_version() {
+            return Err(ConfyError::WriteConfigurationFileError(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("settings were saved by a newer version (v{})", self.version),
+            )));
+        }
+
         confy::store(RUNTIME_NAME, self)
     }
 
+    pub fn is_from_newer_version(&self) -> bool {
+        self.version > SETTINGS_VERSION
+    }
+
+    pub fn snooze_max(&self) -> Duration {
+        Duration::from_secs(self.snooze_max_seconds)
+    }
+
     pub fn set_locale(&mut self, locale: String) -> ConfigSettingsUpdateMay {
         if self.locale != locale {
             self.locale = locale;
@@ -50,14 +264,344 @@ impl ConfigSettings {
         }
     }
 
+    pub fn set_graph_seconds(&mut self, graph_seconds: u8) -> ConfigSettingsUpdateMay {
+        if self.graph_seconds != graph_seconds {
+            self.graph_seconds = graph_seconds;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_pressure_unit(
+        &mut self,
+        pressure_unit: ConfigSettingsPressureUnit,
+    ) -> ConfigSettingsUpdateMay {
+        if self.pressure_unit != pressure_unit {
+            self.pressure_unit = pressure_unit;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_flow_unit(&mut self, flow_unit: ConfigSettingsFlowUnit) -> ConfigSettingsUpdateMay {
+        if self.flow_unit != flow_unit {
+            self.flow_unit = flow_unit;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_volume_unit(
+        &mut self,
+        volume_unit: ConfigSettingsVolumeUnit,
+    ) -> ConfigSettingsUpdateMay {
+        if self.volume_unit != volume_unit {
+            self.volume_unit = volume_unit;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_snooze_max_seconds(&mut self, snooze_max_seconds: u64) -> ConfigSettingsUpdateMay {
+        if self.snooze_max_seconds != snooze_max_seconds {
+            self.snooze_max_seconds = snooze_max_seconds;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_brightness(&mut self, brightness: u8) -> ConfigSettingsUpdateMay {
+        if self.brightness != brightness {
+            self.brightness = brightness;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_preset_age(&mut self, preset_age: SettingsPresetAge) -> ConfigSettingsUpdateMay {
+        if self.preset_age != preset_age {
+            self.preset_age = preset_age;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_preset_gender(
+        &mut self,
+        preset_gender: SettingsPresetGender,
+    ) -> ConfigSettingsUpdateMay {
+        if self.preset_gender != preset_gender {
+            self.preset_gender = preset_gender;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    pub fn set_theme(&mut self, theme: ConfigSettingsTheme) -> ConfigSettingsUpdateMay {
+        if self.theme != theme {
+            self.theme = theme;
+
+            ConfigSettingsUpdateMay::ShouldSave
+        } else {
+            ConfigSettingsUpdateMay::NoChange
+        }
+    }
+
+    fn legacy_version() -> u32 {
+        1
+    }
+
+    fn load(loaded: Result<Self, ConfyError>) -> (Self, bool) {
+        match loaded {
+            Ok(configuration) => {
+                let upgraded = configuration.version < SETTINGS_VERSION;
+                let configuration = Self::check_configuration(Self::migrate(configuration));
+                let should_save = upgraded || configuration.warning.is_some();
+
+                (configuration, should_save)
+            }
+            Err(err) => {
+                error!(
+                    "settings could not be read, resetting to defaults: {:?}",
+                    err
+                );
+
+                let configuration = Self {
+                    warning: Some(ConfigSettingsWarning::Corrupt),
+                    ..Self::default()
+                };
+
+                (configuration, true)
+            }
+        }
+    }
+
+    fn migrate(mut configuration: Self) -> Self {
+        // Notice: a configuration file saved by a newer version is used as-is (values unknown to \
+        //   this version get ignored), though its version is kept so that it never gets saved \
+        //   back, which would downgrade it.
+        if configuration.is_from_newer_version() {
+            warn!(
+                "settings were saved by a newer version (v{}), they will not be saved",
+                configuration.version
+            );
+
+            return configuration;
+        }
+
+        // Version 1 to 2: only the locale was saved, and it could hold a full language tag (eg. \
+        //   'fr-FR' or 'pt_BR'), which is now stripped to its language code instead of being \
+        //   reset to the default locale (other values use their defaults)
+        if configuration.version < 2 {
+            info!("migrating settings from v{} to v2", configuration.version);
+
+            configuration.locale = configuration
+                .locale
+                .split(|character: char| character == '-' || character == '_')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_lowercase();
+        }
+
+        configuration.version = SETTINGS_VERSION;
+
+        configuration
+    }
+
     fn check_configuration(mut configuration: Self) -> Self {
         // Ensure configuration is still valid
         // Notice: as the UI may be resumed from an old saved state, some saved configuration \
-        //   values may not be valid anymore, hence this check to ensure their validity.
+        //   values may not be valid anymore, hence this check to ensure their validity. Any \
+        //   value that gets reset is reported with a warning, as it might have been hand-edited.
+        let defaults = Self::default();
+        let mut invalid = Vec::new();
+
         if LocaleCode::from_code(&configuration.locale.as_str()).is_none() {
-            configuration.locale = LocaleCode::default().to_code().to_string();
+            configuration.locale = defaults.locale;
+
+            invalid.push("locale");
+        }
+
+        if !SETTINGS_GRAPH_SECONDS_OPTIONS.contains(&configuration.graph_seconds) {
+            configuration.graph_seconds = defaults.graph_seconds;
+
+            invalid.push("graph_seconds");
+        }
+
+        if !(SETTINGS_SNOOZE_MAX_SECONDS_MIN..=SETTINGS_SNOOZE_MAX_SECONDS_MAX)
+            .contains(&configuration.snooze_max_seconds)
+        {
+            configuration.snooze_max_seconds = defaults.snooze_max_seconds;
+
+            invalid.push("snooze_max_seconds");
+        }
+
+        if !(SETTINGS_BRIGHTNESS_MIN..=SETTINGS_BRIGHTNESS_MAX).contains(&configuration.brightness)
+        {
+            configuration.brightness = defaults.brightness;
+
+            invalid.push("brightness");
+        }
+
+        // Notice: the record directory is not checked there, as it may only be missing for now \
+        //   (eg. an USB drive that is not plugged yet), thus it must not be reset. It gets ignored \
+        //   when resolving options instead, if it is still missing on start.
+
+        if !invalid.is_empty() {
+            warn!(
+                "invalid settings were reset to defaults: {}",
+                invalid.join(", ")
+            );
+
+            configuration.warning = Some(ConfigSettingsWarning::Invalid);
         }
 
         configuration
     }
+
+    fn backup_corrupt_file() {
+        if let Ok(path) = confy::get_configuration_file_path(RUNTIME_NAME) {
+            let backup_path = path.with_extension("toml.corrupt");
+
+            match std::fs::rename(&path, &backup_path) {
+                Ok(_) => warn!("kept corrupt settings file as: {:?}", backup_path),
+                Err(err) => error!("could not keep corrupt settings file: {:?}", err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_v1_locale() {
+        for (locale, expected) in
+            [("fr-FR", "fr"), ("pt_BR", "pt"), ("DE", "de"), ("en", "en")].iter()
+        {
+            let configuration = ConfigSettings::migrate(ConfigSettings {
+                version: 1,
+                locale: locale.to_string(),
+                ..ConfigSettings::default()
+            });
+
+            assert_eq!(configuration.version, SETTINGS_VERSION);
+            assert_eq!(configuration.locale, *expected);
+        }
+    }
+
+    #[test]
+    fn keeps_newer_version() {
+        let configuration = ConfigSettings::migrate(ConfigSettings {
+            version: SETTINGS_VERSION + 1,
+            locale: "fr-FR".to_string(),
+            ..ConfigSettings::default()
+        });
+
+        assert_eq!(configuration.version, SETTINGS_VERSION + 1);
+        assert_eq!(configuration.locale, "fr-FR");
+
+        // Saving must be refused, as it would downgrade the configuration file
+        assert!(configuration.save().is_err());
+    }
+
+    #[test]
+    fn resets_invalid_values() {
+        let configuration = ConfigSettings::check_configuration(ConfigSettings {
+            locale: "xx".to_string(),
+            graph_seconds: 7,
+            snooze_max_seconds: SETTINGS_SNOOZE_MAX_SECONDS_MAX + 1,
+            brightness: SETTINGS_BRIGHTNESS_MIN - 1,
+            record_directory: Some("/makair-control/missing".to_string()),
+            ..ConfigSettings::default()
+        });
+
+        let defaults = ConfigSettings::default();
+
+        assert_eq!(configuration.warning, Some(ConfigSettingsWarning::Invalid));
+        assert_eq!(configuration.locale, defaults.locale);
+        assert_eq!(configuration.graph_seconds, defaults.graph_seconds);
+        assert_eq!(
+            configuration.snooze_max_seconds,
+            defaults.snooze_max_seconds
+        );
+        assert_eq!(configuration.brightness, defaults.brightness);
+
+        // A missing record directory is kept, as it may only be missing for now
+        assert_eq!(
+            configuration.record_directory.as_deref(),
+            Some("/makair-control/missing")
+        );
+    }
+
+    #[test]
+    fn keeps_valid_values() {
+        let configuration = ConfigSettings::check_configuration(ConfigSettings {
+            graph_seconds: SETTINGS_GRAPH_SECONDS_OPTIONS[0],
+            snooze_max_seconds: SETTINGS_SNOOZE_MAX_SECONDS_MIN,
+            brightness: SETTINGS_BRIGHTNESS_MAX,
+            ..ConfigSettings::default()
+        });
+
+        assert_eq!(configuration.warning, None);
+        assert_eq!(
+            configuration.graph_seconds,
+            SETTINGS_GRAPH_SECONDS_OPTIONS[0]
+        );
+        assert_eq!(
+            configuration.snooze_max_seconds,
+            SETTINGS_SNOOZE_MAX_SECONDS_MIN
+        );
+        assert_eq!(configuration.brightness, SETTINGS_BRIGHTNESS_MAX);
+    }
+
+    #[test]
+    fn loads_upgraded_file() {
+        let (configuration, should_save) = ConfigSettings::load(Ok(ConfigSettings {
+            version: 1,
+            locale: "fr-FR".to_string(),
+            ..ConfigSettings::default()
+        }));
+
+        assert!(should_save);
+        assert_eq!(configuration.warning, None);
+        assert_eq!(configuration.locale, "fr");
+
+        let (_, should_save) = ConfigSettings::load(Ok(ConfigSettings::default()));
+
+        assert!(!should_save);
+    }
+
+    #[test]
+    fn loads_corrupt_file() {
+        let (configuration, should_save) = ConfigSettings::load(Err(ConfyError::GeneralLoadError(
+            io::Error::new(io::ErrorKind::InvalidData, "corrupt settings"),
+        )));
+
+        let defaults = ConfigSettings::default();
+
+        assert!(should_save);
+        assert_eq!(configuration.warning, Some(ConfigSettingsWarning::Corrupt));
+        assert_eq!(configuration.version, SETTINGS_VERSION);
+        assert_eq!(configuration.locale, defaults.locale);
+        assert_eq!(configuration.brightness, defaults.brightness);
+    }
 }
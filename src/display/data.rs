@@ -6,7 +6,13 @@
 use makair_telemetry::structures::MachineStateSnapshot;
 use plotters_conrod::ConrodBackendReusableGraph;
 
+use crate::chip::freeze::ChipFreeze;
+use crate::chip::mechanics::ChipMechanics;
+use crate::chip::session::ChipSession;
+use crate::chip::settings::mode::SettingsModeSettings;
+use crate::chip::weaning::{ChipWeaning, ChipWeaningCriteria};
 use crate::chip::{ChipData, ChipEndOfLine, ChipError, ChipState};
+use crate::serial::recorder::RecordEvent;
 
 use super::identifiers::ImageIds;
 
@@ -23,12 +29,19 @@ pub struct DisplayDataBranding<'a> {
 pub struct DisplayDataStatus<'a> {
     pub chip_state: &'a ChipState,
     pub battery_soc: Option<u8>,
+    pub snooze_remaining_seconds: Option<u64>,
+    pub snooze_escalated: bool,
 }
 
 pub struct DisplayDataHeartbeat<'a> {
     pub data_pressure: &'a ChipData,
 }
 
+pub struct DisplayDataTelemetry {
+    pub stale_seconds: Option<u64>,
+    pub triggered_percent: Option<f64>,
+}
+
 pub struct DisplayDataGraph<'a> {
     pub width: f64,
     pub height: f64,
@@ -36,6 +49,10 @@ pub struct DisplayDataGraph<'a> {
     pub data_flow: &'a ChipData,
     pub chip_state: &'a ChipState,
     pub machine_snapshot: &'a MachineStateSnapshot,
+    pub mode_settings: &'a SettingsModeSettings,
+    pub replay_events: &'a [RecordEvent],
+    pub freeze: Option<&'a ChipFreeze>,
+    pub freeze_save: bool,
     pub plot_graphs: &'a mut (ConrodBackendReusableGraph, ConrodBackendReusableGraph),
 }
 
@@ -64,4 +81,10 @@ pub struct DisplayDataEndOfLine<'a> {
 
 pub struct DisplayDataSettings<'a> {
     pub images: &'a ImageIds,
+    pub mechanics: &'a ChipMechanics,
+    pub weaning: &'a ChipWeaning,
+    pub weaning_criteria: &'a ChipWeaningCriteria,
+    pub weaning_available: bool,
+    pub alarms_auto_available: bool,
+    pub session: &'a ChipSession,
 }
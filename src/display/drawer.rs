@@ -16,6 +16,9 @@ use crate::chip::{Chip, ChipEventUpdate, ChipState};
 use crate::config::arguments::RunMode;
 use crate::config::environment::*;
 use crate::serial::poller::{PollEvent, SerialPoller, SerialPollerBuilder};
+use crate::serial::recorder::{RecordEvent, SerialRecorderBuilder};
+use crate::serial::source::{gather_telemetry_from_pipe, gather_telemetry_from_tcp};
+use crate::sound::player::{SoundPlayer, SoundPlayerBuilder};
 use crate::APP_ARGS;
 
 use super::events::{DisplayEventsBuilder, DisplayEventsHandleOutcome};
@@ -46,6 +49,7 @@ pub struct DisplayDrawer<'a> {
     events_loop: EventsLoop,
     image_map: conrod_core::image::Map<texture::SrgbTexture2d>,
     chip: Chip,
+    sound: SoundPlayer,
 }
 
 impl<'a> DisplayDrawerBuilder<'a> {
@@ -81,6 +85,7 @@ impl<'a> DisplayDrawerBuilder<'a> {
             events_loop,
             image_map,
             chip,
+            sound: SoundPlayerBuilder::new(&APP_ARGS.alarm_sound),
         }
     }
 }
@@ -114,6 +119,22 @@ impl<'a> DisplayDrawer<'a> {
             // Empty the events queue before doing anything else
             let has_poll_events = self.poll_telemetry(&mut serial_poller, &rx);
 
+            // Watch for stale telemetry data (ie. the motherboard stopped sending data while \
+            //   running, which would otherwise result in frozen values being shown)
+            self.chip.watch_stale_data();
+
+            // Watch for respiratory mechanics alarms (those are evaluated by the UI)
+            self.chip.watch_mechanics(&APP_ARGS.mechanics_thresholds);
+
+            // Watch for weaning trial completion (the trial ends after its selected duration)
+            self.chip.watch_weaning(&APP_ARGS.weaning_criteria);
+
+            // Watch for alarms snooze expiry (alarms get un-snoozed from the UI after a while)
+            self.chip.watch_snooze(APP_ARGS.snooze_max());
+
+            // Sound UI-originated alarms (the motherboard buzzer is unaware of those)
+            self.sound.update(self.chip.ui_alarms_sound_priority());
+
             // Handle incoming UI events (ie. from the window, eg. 'ESC' key is pressed)
             match events_handler.handle(&self.display, &mut self.interface, &mut self.events_loop) {
                 DisplayEventsHandleOutcome::Break => break 'main,
@@ -230,23 +251,32 @@ impl<'a> DisplayDrawer<'a> {
 
         match &APP_ARGS.mode {
             RunMode::Port { port, output_dir } => {
-                let optional_file_buffer = output_dir.as_ref().map(|dir| {
-                    let file_count: Vec<std::io::Result<std::fs::DirEntry>> =
-                        std::fs::read_dir(dir)
-                            .expect("should read directory")
-                            .collect();
-
-                    let path = format!(
-                        "{}/{}-{}.record",
-                        &dir,
-                        chrono::Local::now().format("%Y%m%d-%H%M%S"),
-                        file_count.len() + 1
-                    );
-
-                    let file = std::fs::File::create(&path)
-                        .unwrap_or_else(|_| panic!("could not create file '{}'", &path));
-
-                    std::io::BufWriter::new(file)
+                let records_receiver = output_dir
+                    .as_ref()
+                    .map(|_| self.chip.init_records_receiver());
+
+                // Start first patient session (generates its record file path, if recording)
+                self.chip
+                    .start_session(APP_ARGS.patient_id.clone(), output_dir.as_deref());
+
+                let optional_file_buffer = records_receiver.map(|records_receiver| {
+                    let record_path = self
+                        .chip
+                        .session
+                        .current
+                        .as_ref()
+                        .and_then(|session| session.record_path.clone())
+                        .expect("first session should have a record path");
+
+                    // Create recorder (interleaves UI events with telemetry in the record file)
+                    // Notice: this must happen before telemetry starts being gathered, as the \
+                    //   record file of the first session is created there, so that no telemetry \
+                    //   line gets lost.
+                    let recorder = SerialRecorderBuilder::new(&record_path);
+
+                    recorder.bind_events(records_receiver);
+
+                    recorder.bind_telemetry()
                 });
 
                 let settings_receiver = self.chip.init_settings_receiver();
@@ -262,12 +292,30 @@ impl<'a> DisplayDrawer<'a> {
             }
 
             RunMode::Input(path) => {
+                // Load UI events from the record file (they will be shown as markers)
+                self.chip
+                    .init_replay_events(RecordEvent::read_all_from_file(path));
+
                 std::thread::spawn(move || loop {
                     let file = std::fs::File::open(path).expect("input file not found");
 
                     makair_telemetry::gather_telemetry_from_file(file, tx.clone(), true);
                 });
             }
+            RunMode::Tcp(address) => {
+                self.chip.start_session(APP_ARGS.patient_id.clone(), None);
+
+                std::thread::spawn(move || {
+                    gather_telemetry_from_tcp(address, tx);
+                });
+            }
+            RunMode::Pipe(path) => {
+                self.chip.start_session(APP_ARGS.patient_id.clone(), None);
+
+                std::thread::spawn(move || {
+                    gather_telemetry_from_pipe(path, tx);
+                });
+            }
         }
 
         rx
@@ -5,7 +5,7 @@
 
 use std::time::Duration;
 
-use conrod_core::{widget::Id as WidgetId, Ui};
+use conrod_core::{input::Key, widget::Id as WidgetId, Ui};
 use glium::glutin::{Event, EventsLoop, KeyboardInput, WindowEvent};
 
 use crate::chip::{
@@ -20,6 +20,7 @@ use crate::chip::{
     },
     Chip,
 };
+use crate::{APP_ARGS, APP_SETTINGS};
 
 use super::identifiers::Ids;
 use super::renderer::{DisplayRendererSettingsStateVisibility, DisplayRendererStates};
@@ -103,7 +104,7 @@ impl DisplayUiEvents {
         }
 
         // Handle telemetry clicks
-        if Self::run_opener_clicks(interface, ids, states) {
+        if Self::run_opener_clicks(interface, ids, chip, states) {
             has_events = true;
         }
 
@@ -117,11 +118,31 @@ impl DisplayUiEvents {
             has_events = true;
         }
 
+        // Handle session patient identifier input
+        if Self::run_session_patient_input(interface, ids, chip, states) {
+            has_events = true;
+        }
+
         // Handle end-of-line settings clicks
         if Self::run_end_of_line_settings_clicks(interface, ids, &mut events) {
             has_events = true;
         }
 
+        // Handle resolved alarms acknowledgement clicks
+        if Self::run_alarm_acknowledge_clicks(interface, ids, chip, states) {
+            has_events = true;
+        }
+
+        // Handle settings notice dismiss clicks
+        if Self::run_settings_notice_clicks(interface, ids) {
+            has_events = true;
+        }
+
+        // Handle graph freeze clicks (and cursor drags)
+        if Self::run_graph_freeze_clicks(interface, ids, chip) {
+            has_events = true;
+        }
+
         (has_events, intents, events)
     }
 
@@ -132,7 +153,11 @@ impl DisplayUiEvents {
         // Notice: only if not open lastly (prevents visual jitters where the modal would \
         //   re-open right after submitting it as telemetry acknowledgement would not yet be \
         //   received on next frame)
-        let new_preset_visibility = if chip.last_machine_snapshot.patient_height == Some(0) {
+        // Notice: the preset modal is also forced when a new patient session has been started, \
+        //   until the preset for the new patient gets submitted.
+        let new_preset_visibility = if chip.last_machine_snapshot.patient_height == Some(0)
+            || chip.session.preset_pending
+        {
             DisplayRendererSettingsStateVisibility::Opened
         } else {
             DisplayRendererSettingsStateVisibility::Closed
@@ -144,6 +169,10 @@ impl DisplayUiEvents {
                 FORCED_CLICKS_PRESET_SETTINGS_OPEN_DEBOUNCE_DELAY,
             )
         {
+            if new_preset_visibility == DisplayRendererSettingsStateVisibility::Opened {
+                chip.record_modal_open("preset");
+            }
+
             states.preset_settings.update_to(new_preset_visibility);
 
             has_events = true;
@@ -155,6 +184,7 @@ impl DisplayUiEvents {
     fn run_opener_clicks(
         interface: &mut Ui,
         ids: &Ids,
+        chip: &mut Chip,
         states: &mut DisplayRendererStates,
     ) -> bool {
         let mut has_events = false;
@@ -164,7 +194,7 @@ impl DisplayUiEvents {
         //   parent. Maybe there is a way to listen on a parent for childs clicks but we could not \
         //   find one. So we chain each iterator of every childs to be sure to capture the click.
         gen_ui_events_opener_settings_clicks!(
-            interface, has_events,
+            interface, chip, has_events,
 
             {
                 "run", states.run_settings, [
@@ -648,6 +678,19 @@ impl DisplayUiEvents {
                     {
                         chip.settings.mode.group = SettingsModeGroupTab::Alarms;
                     }
+                },
+
+                {
+                    "alarms auto-set",
+
+                    [
+                        ids.mode_settings_alarms_auto_button,
+                        ids.mode_settings_alarms_auto_text,
+                    ],
+
+                    {
+                        chip.auto_set_alarms(APP_ARGS.alarms_auto_band);
+                    }
                 }
             },
 
@@ -667,6 +710,58 @@ impl DisplayUiEvents {
                     }
                 },
 
+                {
+                    "group mechanics",
+
+                    [
+                        ids.advanced_group_tab_mechanics_button,
+                        ids.advanced_group_tab_mechanics_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.group = SettingsAdvancedGroupTab::Mechanics;
+                    }
+                },
+
+                {
+                    "group weaning",
+
+                    [
+                        ids.advanced_group_tab_weaning_button,
+                        ids.advanced_group_tab_weaning_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.group = SettingsAdvancedGroupTab::Weaning;
+                    }
+                },
+
+                {
+                    "group session",
+
+                    [
+                        ids.advanced_group_tab_session_button,
+                        ids.advanced_group_tab_session_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.group = SettingsAdvancedGroupTab::Session;
+                    }
+                },
+
+                {
+                    "group preferences",
+
+                    [
+                        ids.advanced_group_tab_preferences_button,
+                        ids.advanced_group_tab_preferences_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.group = SettingsAdvancedGroupTab::Preferences;
+                    }
+                },
+
                 {
                     "group settings",
 
@@ -680,6 +775,214 @@ impl DisplayUiEvents {
                     }
                 },
 
+                {
+                    "preferences graph seconds previous",
+
+                    [
+                        ids.advanced_field_graph_seconds_less,
+                        ids.advanced_field_graph_seconds_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_graph_seconds(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences graph seconds next",
+
+                    [
+                        ids.advanced_field_graph_seconds_more,
+                        ids.advanced_field_graph_seconds_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_graph_seconds(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences pressure unit previous",
+
+                    [
+                        ids.advanced_field_pressure_unit_less,
+                        ids.advanced_field_pressure_unit_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_pressure_unit(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences pressure unit next",
+
+                    [
+                        ids.advanced_field_pressure_unit_more,
+                        ids.advanced_field_pressure_unit_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_pressure_unit(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences flow unit previous",
+
+                    [
+                        ids.advanced_field_flow_unit_less,
+                        ids.advanced_field_flow_unit_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_flow_unit(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences flow unit next",
+
+                    [
+                        ids.advanced_field_flow_unit_more,
+                        ids.advanced_field_flow_unit_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_flow_unit(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences volume unit previous",
+
+                    [
+                        ids.advanced_field_volume_unit_less,
+                        ids.advanced_field_volume_unit_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_volume_unit(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences volume unit next",
+
+                    [
+                        ids.advanced_field_volume_unit_more,
+                        ids.advanced_field_volume_unit_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_volume_unit(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences snooze max previous",
+
+                    [
+                        ids.advanced_field_snooze_max_less,
+                        ids.advanced_field_snooze_max_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.change_snooze_max(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences snooze max next",
+
+                    [
+                        ids.advanced_field_snooze_max_more,
+                        ids.advanced_field_snooze_max_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.change_snooze_max(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences brightness previous",
+
+                    [
+                        ids.advanced_field_brightness_less,
+                        ids.advanced_field_brightness_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.change_brightness(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences brightness next",
+
+                    [
+                        ids.advanced_field_brightness_more,
+                        ids.advanced_field_brightness_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.change_brightness(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences preset age previous",
+
+                    [
+                        ids.advanced_field_preset_age_less,
+                        ids.advanced_field_preset_age_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_preset_age(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences preset age next",
+
+                    [
+                        ids.advanced_field_preset_age_more,
+                        ids.advanced_field_preset_age_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_preset_age(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "preferences preset gender previous",
+
+                    [
+                        ids.advanced_field_preset_gender_less,
+                        ids.advanced_field_preset_gender_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_preset_gender(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "preferences preset gender next",
+
+                    [
+                        ids.advanced_field_preset_gender_more,
+                        ids.advanced_field_preset_gender_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_preset_gender(SettingActionRange::More);
+                    }
+                },
+
                 {
                     "settings locale previous",
 
@@ -704,6 +1007,104 @@ impl DisplayUiEvents {
                     {
                         chip.settings.advanced.switch_locale(SettingActionRange::More);
                     }
+                },
+
+                {
+                    "settings theme previous",
+
+                    [
+                        ids.advanced_field_theme_less,
+                        ids.advanced_field_theme_less_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_theme(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "settings theme next",
+
+                    [
+                        ids.advanced_field_theme_more,
+                        ids.advanced_field_theme_more_text,
+                    ],
+
+                    {
+                        chip.settings.advanced.switch_theme(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "weaning duration previous",
+
+                    [
+                        ids.advanced_field_weaning_duration_less,
+                        ids.advanced_field_weaning_duration_less_text,
+                    ],
+
+                    {
+                        chip.weaning.switch_duration(SettingActionRange::Less);
+                    }
+                },
+
+                {
+                    "weaning duration next",
+
+                    [
+                        ids.advanced_field_weaning_duration_more,
+                        ids.advanced_field_weaning_duration_more_text,
+                    ],
+
+                    {
+                        chip.weaning.switch_duration(SettingActionRange::More);
+                    }
+                },
+
+                {
+                    "weaning trial toggle",
+
+                    [
+                        ids.advanced_weaning_toggle_button,
+                        ids.advanced_weaning_toggle_button_text,
+                    ],
+
+                    {
+                        chip.toggle_weaning_trial(&APP_ARGS.weaning_criteria);
+                    }
+                },
+
+                {
+                    "session toggle",
+
+                    [
+                        ids.advanced_session_toggle_button,
+                        ids.advanced_session_toggle_button_text,
+                    ],
+
+                    {
+                        if chip.session.current.is_some() {
+                            chip.end_session();
+                        } else {
+                            chip.restart_session(APP_ARGS.record_directory());
+                        }
+                    }
+                },
+
+                {
+                    "session new patient",
+
+                    [
+                        ids.advanced_session_new_button,
+                        ids.advanced_session_new_button_text,
+                    ],
+
+                    {
+                        chip.start_new_patient_session(APP_ARGS.record_directory());
+
+                        // Close this modal, as the preset modal opens for the new patient
+                        states.advanced_settings.close();
+                    }
                 }
             },
         );
@@ -743,6 +1144,176 @@ impl DisplayUiEvents {
         has_events
     }
 
+    fn run_session_patient_input(
+        interface: &mut Ui,
+        ids: &Ids,
+        chip: &mut Chip,
+        states: &DisplayRendererStates,
+    ) -> bool {
+        let mut has_events = false;
+
+        if !states.advanced_settings.is_open()
+            || chip.settings.advanced.group != SettingsAdvancedGroupTab::Session
+        {
+            return false;
+        }
+
+        // Input the patient identifier from a keyboard (or from a barcode scanner, which types \
+        //   as a keyboard does)
+        // Notice: text only reaches the patient identifier button once it captures the \
+        //   keyboard, which happens when it gets tapped.
+        for widget in [
+            ids.advanced_session_patient_button,
+            ids.advanced_session_patient_button_text,
+        ]
+        .iter()
+        {
+            let input = interface.widget_input(*widget);
+
+            for text in input.text() {
+                chip.session.input_patient_id(&text.string);
+
+                has_events = true;
+            }
+
+            for _ in input
+                .presses()
+                .key()
+                .filter(|press| press.key == Key::Backspace)
+            {
+                chip.session.erase_patient_id();
+
+                has_events = true;
+            }
+        }
+
+        has_events
+    }
+
+    fn run_alarm_acknowledge_clicks(
+        interface: &mut Ui,
+        ids: &Ids,
+        chip: &mut Chip,
+        states: &DisplayRendererStates,
+    ) -> bool {
+        let mut has_events = false;
+
+        // Acknowledge resolved alarms that were tapped (on any part of their row)
+        // Notice: use the alarm positions from the last rendered frame, as alarms might have \
+        //   changed since, which would otherwise acknowledge the wrong alarm.
+        for (index, code) in states.latched_alarms.iter() {
+            if *index >= ids.alarm_alarms.len() {
+                continue;
+            }
+
+            let clicks = Self::count_clicks(
+                interface,
+                &[
+                    ids.alarm_alarms[*index],
+                    ids.alarm_codes_containers[*index],
+                    ids.alarm_codes[*index],
+                    ids.alarm_messages_containers[*index],
+                    ids.alarm_messages[*index],
+                ],
+            );
+
+            if clicks > 0 {
+                debug!("acknowledging resolved alarm: {:?}", code);
+
+                chip.acknowledge_alarm(*code);
+
+                has_events = true;
+            }
+        }
+
+        has_events
+    }
+
+    fn run_settings_notice_clicks(interface: &mut Ui, ids: &Ids) -> bool {
+        // Dismiss the settings notice (it is only informative, thus it does not need to stay \
+        //   until settings get saved again)
+        if Self::count_clicks(
+            interface,
+            &[ids.settings_notice_box, ids.settings_notice_text],
+        ) > 0
+        {
+            let mut settings = APP_SETTINGS.write().unwrap();
+
+            if settings.warning.take().is_some() {
+                debug!("dismissed the settings notice");
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn run_graph_freeze_clicks(interface: &mut Ui, ids: &Ids, chip: &mut Chip) -> bool {
+        let mut has_events = false;
+
+        // Freeze (or resume) graphs
+        if Self::count_clicks(interface, &[ids.graph_freeze_button, ids.graph_freeze_text]) > 0 {
+            chip.toggle_freeze();
+
+            has_events = true;
+        }
+
+        // Save frozen graphs to the session record
+        if Self::count_clicks(
+            interface,
+            &[ids.graph_freeze_save_button, ids.graph_freeze_save_text],
+        ) > 0
+        {
+            chip.save_freeze();
+
+            has_events = true;
+        }
+
+        // Move cursor over frozen graphs (follows drags, clicks and taps)
+        // Notice: widget input positions are relative to the center of the widget they were \
+        //   captured by, which may be the overlay itself or any of the cursor widgets drawn over \
+        //   it, thus positions are made absolute first.
+        if let (Some(freeze), Some(overlay_rect)) = (
+            chip.freeze.as_mut(),
+            interface.rect_of(ids.graph_cursor_overlay),
+        ) {
+            let mut cursor_x = None;
+
+            for widget in [
+                ids.graph_cursor_overlay,
+                ids.graph_cursor_line,
+                ids.graph_cursor_box,
+                ids.graph_cursor_text,
+            ]
+            .iter()
+            {
+                if let Some(widget_rect) = interface.rect_of(*widget) {
+                    let input = interface.widget_input(*widget);
+
+                    let positions = input
+                        .drags()
+                        .left()
+                        .map(|drag| drag.to[0])
+                        .chain(input.clicks().left().map(|click| click.xy[0]))
+                        .chain(input.taps().map(|tap| tap.xy[0]));
+
+                    if let Some(x) = positions.last() {
+                        cursor_x = Some(widget_rect.x() + x);
+                    }
+                }
+            }
+
+            if let Some(cursor_x) = cursor_x {
+                freeze.move_cursor((cursor_x - overlay_rect.left()) / overlay_rect.w());
+
+                has_events = true;
+            }
+        }
+
+        has_events
+    }
+
     fn count_clicks(interface: &Ui, widget_ids: &[WidgetId]) -> usize {
         widget_ids
             .iter()
@@ -5,13 +5,52 @@
 
 use conrod_core::text::font;
 
+use crate::locale::locales::LocaleCode;
+
+#[derive(Clone, Copy)]
+pub struct FontsSet {
+    pub regular: font::Id,
+    pub bold: font::Id,
+}
+
 pub struct Fonts {
     pub regular: font::Id,
     pub bold: font::Id,
+
+    default: FontsSet,
+    cjk: Option<FontsSet>,
+    rtl: Option<FontsSet>,
+}
+
+impl FontsSet {
+    pub fn new(regular: font::Id, bold: font::Id) -> FontsSet {
+        FontsSet { regular, bold }
+    }
 }
 
 impl Fonts {
-    pub fn new(regular: font::Id, bold: font::Id) -> Fonts {
-        Fonts { regular, bold }
+    pub fn new(default: FontsSet, cjk: Option<FontsSet>, rtl: Option<FontsSet>) -> Fonts {
+        Fonts {
+            regular: default.regular,
+            bold: default.bold,
+            default,
+            cjk,
+            rtl,
+        }
+    }
+
+    pub fn select(&mut self, locale: &LocaleCode) {
+        // Pick the font set able to render glyphs for this locale
+        // Notice: if CJK or RTL fonts were not embedded (ie. feature disabled), then use the \
+        //   default set; those locales are not listed as available in that case anyway.
+        let set = match (locale.is_cjk(), locale.direction().is_rtl()) {
+            (true, _) => self.cjk,
+            (_, true) => self.rtl,
+            _ => None,
+        }
+        .unwrap_or(self.default);
+
+        self.regular = set.regular;
+        self.bold = set.bold;
     }
 }
@@ -45,6 +45,16 @@ widget_ids!(pub struct Ids {
   graph_flow_saturate_low,
   graph_flow_saturate_high,
 
+  graph_freeze_button,
+  graph_freeze_text,
+  graph_freeze_save_button,
+  graph_freeze_save_text,
+
+  graph_cursor_overlay,
+  graph_cursor_line,
+  graph_cursor_box,
+  graph_cursor_text,
+
   branding_container,
   branding_text,
 
@@ -60,6 +70,8 @@ widget_ids!(pub struct Ids {
   status_power_text,
   status_recording_outer,
   status_recording_inner,
+  status_snooze_box,
+  status_snooze_text,
 
   heartbeat_ground,
   heartbeat_surround,
@@ -67,6 +79,14 @@ widget_ids!(pub struct Ids {
 
   telemetry_widgets_right,
 
+  telemetry_stale_overlay_right,
+  telemetry_stale_overlay_bottom,
+  telemetry_stale_message_box,
+  telemetry_stale_message_text,
+
+  settings_notice_box,
+  settings_notice_text,
+
   cycles_parent,
   cycles_title,
   cycles_value_measured,
@@ -107,6 +127,14 @@ widget_ids!(pub struct Ids {
   ratio_value_target,
   ratio_unit,
 
+  triggered_parent,
+  triggered_title,
+  triggered_value_measured,
+  triggered_value_arrow_main,
+  triggered_value_arrow_line,
+  triggered_value_target,
+  triggered_unit,
+
   cycles_container,
   cycles_more_button,
   cycles_more_button_text,
@@ -189,6 +217,8 @@ widget_ids!(pub struct Ids {
   mode_settings_group_tab_general_text,
   mode_settings_group_tab_alarms_button,
   mode_settings_group_tab_alarms_text,
+  mode_settings_alarms_auto_button,
+  mode_settings_alarms_auto_text,
 
   mode_settings_field_pressure_inspiratory_text,
   mode_settings_field_pressure_inspiratory_value,
@@ -349,6 +379,7 @@ widget_ids!(pub struct Ids {
   mode_settings_alarm_threshold_leak_more_text,
   mode_settings_alarm_threshold_leak_less,
   mode_settings_alarm_threshold_leak_less_text,
+  mode_settings_alarm_threshold_leak_measured,
 
   mode_settings_alarm_threshold_peak_pressure_text,
   mode_settings_alarm_threshold_peak_pressure_value,
@@ -374,8 +405,16 @@ widget_ids!(pub struct Ids {
   advanced_group_wrapper,
   advanced_form_wrapper,
   advanced_group_tab_statistics_button,
+  advanced_group_tab_mechanics_button,
+  advanced_group_tab_weaning_button,
+  advanced_group_tab_session_button,
+  advanced_group_tab_preferences_button,
   advanced_group_tab_settings_button,
   advanced_group_tab_statistics_text,
+  advanced_group_tab_mechanics_text,
+  advanced_group_tab_weaning_text,
+  advanced_group_tab_session_text,
+  advanced_group_tab_preferences_text,
   advanced_group_tab_settings_text,
 
   advanced_field_locale_text,
@@ -386,6 +425,14 @@ widget_ids!(pub struct Ids {
   advanced_field_locale_less,
   advanced_field_locale_less_text,
 
+  advanced_field_theme_text,
+  advanced_field_theme_value,
+  advanced_field_theme_value_wrapper,
+  advanced_field_theme_more,
+  advanced_field_theme_more_text,
+  advanced_field_theme_less,
+  advanced_field_theme_less_text,
+
   advanced_text_date_text,
   advanced_text_date_value,
 
@@ -395,6 +442,122 @@ widget_ids!(pub struct Ids {
   advanced_text_timezone_text,
   advanced_text_timezone_value,
 
+  advanced_field_weaning_duration_text,
+  advanced_field_weaning_duration_value,
+  advanced_field_weaning_duration_value_wrapper,
+  advanced_field_weaning_duration_more,
+  advanced_field_weaning_duration_more_text,
+  advanced_field_weaning_duration_less,
+  advanced_field_weaning_duration_less_text,
+
+  advanced_weaning_toggle_text,
+  advanced_weaning_toggle_button,
+  advanced_weaning_toggle_button_text,
+
+  advanced_text_weaning_elapsed_text,
+  advanced_text_weaning_elapsed_value,
+
+  advanced_text_weaning_rsbi_text,
+  advanced_text_weaning_rsbi_value,
+
+  advanced_text_weaning_rate_text,
+  advanced_text_weaning_rate_value,
+
+  advanced_text_weaning_minute_volume_text,
+  advanced_text_weaning_minute_volume_value,
+
+  advanced_text_weaning_result_text,
+  advanced_text_weaning_result_value,
+
+  advanced_text_session_started_text,
+  advanced_text_session_started_value,
+
+  advanced_text_session_duration_text,
+  advanced_text_session_duration_value,
+
+  advanced_text_session_events_text,
+  advanced_text_session_events_value,
+
+  advanced_text_session_record_text,
+  advanced_text_session_record_value,
+
+  advanced_session_patient_text,
+  advanced_session_patient_button,
+  advanced_session_patient_button_text,
+
+  advanced_session_toggle_text,
+  advanced_session_toggle_button,
+  advanced_session_toggle_button_text,
+
+  advanced_session_new_text,
+  advanced_session_new_button,
+  advanced_session_new_button_text,
+
+  advanced_field_graph_seconds_text,
+  advanced_field_graph_seconds_value,
+  advanced_field_graph_seconds_value_wrapper,
+  advanced_field_graph_seconds_more,
+  advanced_field_graph_seconds_more_text,
+  advanced_field_graph_seconds_less,
+  advanced_field_graph_seconds_less_text,
+
+  advanced_field_pressure_unit_text,
+  advanced_field_pressure_unit_value,
+  advanced_field_pressure_unit_value_wrapper,
+  advanced_field_pressure_unit_more,
+  advanced_field_pressure_unit_more_text,
+  advanced_field_pressure_unit_less,
+  advanced_field_pressure_unit_less_text,
+  advanced_field_flow_unit_text,
+  advanced_field_flow_unit_value,
+  advanced_field_flow_unit_value_wrapper,
+  advanced_field_flow_unit_more,
+  advanced_field_flow_unit_more_text,
+  advanced_field_flow_unit_less,
+  advanced_field_flow_unit_less_text,
+  advanced_field_volume_unit_text,
+  advanced_field_volume_unit_value,
+  advanced_field_volume_unit_value_wrapper,
+  advanced_field_volume_unit_more,
+  advanced_field_volume_unit_more_text,
+  advanced_field_volume_unit_less,
+  advanced_field_volume_unit_less_text,
+
+  advanced_field_snooze_max_text,
+  advanced_field_snooze_max_value,
+  advanced_field_snooze_max_value_wrapper,
+  advanced_field_snooze_max_more,
+  advanced_field_snooze_max_more_text,
+  advanced_field_snooze_max_less,
+  advanced_field_snooze_max_less_text,
+
+  advanced_field_brightness_text,
+  advanced_field_brightness_value,
+  advanced_field_brightness_value_wrapper,
+  advanced_field_brightness_more,
+  advanced_field_brightness_more_text,
+  advanced_field_brightness_less,
+  advanced_field_brightness_less_text,
+
+  advanced_field_preset_age_text,
+  advanced_field_preset_age_value,
+  advanced_field_preset_age_value_wrapper,
+  advanced_field_preset_age_more,
+  advanced_field_preset_age_more_text,
+  advanced_field_preset_age_less,
+  advanced_field_preset_age_less_text,
+
+  advanced_field_preset_gender_text,
+  advanced_field_preset_gender_value,
+  advanced_field_preset_gender_value_wrapper,
+  advanced_field_preset_gender_more,
+  advanced_field_preset_gender_more_text,
+  advanced_field_preset_gender_less,
+  advanced_field_preset_gender_less_text,
+
+  advanced_text_record_directory_text,
+  advanced_text_record_directory_value,
+
   modal_background,
   modal_container_borders,
   modal_container,
@@ -92,7 +92,7 @@ macro_rules! gen_widget_impls {
 }
 
 macro_rules! gen_ui_events_opener_settings_clicks {
-    ($interface:ident, $has:ident, $({$name:expr, $settings_state:expr, $widget_ids:expr}),+,) => {
+    ($interface:ident, $chip:ident, $has:ident, $({$name:expr, $settings_state:expr, $widget_ids:expr}),+,) => {
         $(
             let clicks = DisplayUiEvents::count_clicks(
                 $interface,
@@ -102,6 +102,10 @@ macro_rules! gen_ui_events_opener_settings_clicks {
             for _ in 0..clicks {
                 debug!("pressed the {} widget once", $name);
 
+                if !$settings_state.is_open() {
+                    $chip.record_modal_open($name);
+                }
+
                 $settings_state.open();
 
                 $has = true;
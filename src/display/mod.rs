@@ -16,5 +16,6 @@ mod renderer;
 mod screen;
 mod support;
 
+pub mod theme;
 pub mod widget;
 pub mod window;
@@ -6,6 +6,7 @@
 use std::time::{Duration, Instant};
 
 use conrod_core::Ui;
+use makair_telemetry::alarm::AlarmCode;
 use plotters_conrod::ConrodBackendReusableGraph;
 
 use crate::chip::settings::{ChipSettingsEvent, ChipSettingsIntent};
@@ -15,12 +16,14 @@ use crate::utilities::{
     index::{index_from_end_of_line_failure, index_from_end_of_line_step},
     parse::parse_version_number,
 };
+use crate::{APP_ARGS, APP_SETTINGS};
 
 use super::data::*;
 use super::events::DisplayUiEvents;
 use super::fonts::Fonts;
 use super::identifiers::{Ids, ImageIds};
 use super::screen::{Screen, ScreenModalsOpen};
+use super::theme::Theme;
 
 const WAITING_FOR_DATA_TIMEOUT_AFTER: Duration = Duration::from_secs(10);
 const DISPATCH_HEARTBEAT_EVERY: Duration = Duration::from_secs(1);
@@ -40,6 +43,7 @@ pub struct DisplayRendererBuilder;
 
 pub struct DisplayRenderer {
     fonts: Fonts,
+    theme: &'static Theme,
     ids: Ids,
     states: DisplayRendererStates,
     plot_graphs: (ConrodBackendReusableGraph, ConrodBackendReusableGraph),
@@ -53,6 +57,7 @@ pub struct DisplayRendererStates {
     pub snooze_settings: DisplayRendererSettingsState,
     pub advanced_settings: DisplayRendererSettingsState,
     pub mode_settings: DisplayRendererSettingsState,
+    pub latched_alarms: Vec<(usize, AlarmCode)>,
 }
 
 impl DisplayRendererSettingsState {
@@ -121,6 +126,7 @@ impl DisplayRendererBuilder {
     pub fn new(fonts: Fonts, ids: Ids, images: ImageIds) -> DisplayRenderer {
         DisplayRenderer {
             fonts,
+            theme: Theme::from_setting(APP_SETTINGS.read().unwrap().theme),
             ids,
             states: DisplayRendererStates::default(),
             images,
@@ -134,6 +140,16 @@ impl DisplayRendererBuilder {
 
 impl DisplayRenderer {
     pub fn render(&mut self, interface: &mut Ui, chip: &Chip) {
+        // Follow the current locale with fonts (as the locale can be changed at runtime)
+        self.fonts.select(&chip.settings.advanced.locale);
+
+        // Widgets that do not set a font explicitly use the UI theme default font, which must \
+        //   thus follow the selected fonts (otherwise, any loaded font could be picked)
+        interface.theme.font_id = Some(self.fonts.regular);
+
+        // Follow the current theme (as it can be changed at runtime from the advanced settings)
+        self.theme = Theme::from_setting(APP_SETTINGS.read().unwrap().theme);
+
         match &chip.state {
             // Waiting for data from the motherboard, treat it as a 'connecting...' state
             ChipState::WaitingData(started_time) => {
@@ -205,10 +221,12 @@ impl DisplayRenderer {
             interface.set_widgets(),
             &self.ids,
             &self.fonts,
+            self.theme,
             (None, None),
             None,
             None,
             None,
+            None,
         );
 
         screen.render_initializing(screen_bootloader);
@@ -227,10 +245,12 @@ impl DisplayRenderer {
             interface.set_widgets(),
             &self.ids,
             &self.fonts,
+            self.theme,
             (None, None),
             None,
             None,
             None,
+            None,
         );
 
         screen.render_error(screen_error);
@@ -275,10 +295,12 @@ impl DisplayRenderer {
             interface.set_widgets(),
             &self.ids,
             &self.fonts,
+            self.theme,
             (None, None),
             None,
             None,
             None,
+            None,
         );
 
         screen.render_end_of_line(screen_eol);
@@ -288,13 +310,24 @@ impl DisplayRenderer {
         // Create widgets
         let mut ui = interface.set_widgets();
 
-        let ongoing_alarms = chip.ongoing_alarms_sorted();
+        let (ongoing_alarms, latched_alarms) =
+            (chip.ongoing_alarms_sorted(), chip.latched_alarms_sorted());
 
-        let (ongoing_alarms_count, widgets_alarms_count) =
-            (ongoing_alarms.len(), self.ids.alarm_alarms.len());
+        // Store where latched alarms are shown, so that clicks can be mapped to their alarm code
+        // Notice: latched alarms are listed right after ongoing alarms.
+        self.states.latched_alarms = latched_alarms
+            .iter()
+            .enumerate()
+            .map(|(index, (code, _))| (ongoing_alarms.len() + index, *code))
+            .collect();
+
+        let (alarms_count, widgets_alarms_count) = (
+            ongoing_alarms.len() + latched_alarms.len(),
+            self.ids.alarm_alarms.len(),
+        );
 
-        if ongoing_alarms_count > widgets_alarms_count {
-            for i in widgets_alarms_count..ongoing_alarms_count {
+        if alarms_count > widgets_alarms_count {
+            for i in widgets_alarms_count..alarms_count {
                 let index = i + 1;
                 self.ids
                     .alarm_alarms
@@ -314,22 +347,20 @@ impl DisplayRenderer {
             }
         } else {
             let (alarms_difference, alarm_id) = (
-                widgets_alarms_count - ongoing_alarms_count,
+                widgets_alarms_count - alarms_count,
                 &mut ui.widget_id_generator(),
             );
 
             if alarms_difference > 0 {
-                self.ids.alarm_alarms.resize(ongoing_alarms_count, alarm_id);
+                self.ids.alarm_alarms.resize(alarms_count, alarm_id);
                 self.ids
                     .alarm_codes_containers
-                    .resize(ongoing_alarms_count, alarm_id);
-                self.ids.alarm_codes.resize(ongoing_alarms_count, alarm_id);
+                    .resize(alarms_count, alarm_id);
+                self.ids.alarm_codes.resize(alarms_count, alarm_id);
                 self.ids
                     .alarm_codes_containers
-                    .resize(ongoing_alarms_count, alarm_id);
-                self.ids
-                    .alarm_messages
-                    .resize(ongoing_alarms_count, alarm_id);
+                    .resize(alarms_count, alarm_id);
+                self.ids.alarm_messages.resize(alarms_count, alarm_id);
             }
         }
 
@@ -338,8 +369,10 @@ impl DisplayRenderer {
             ui,
             &self.ids,
             &self.fonts,
+            self.theme,
             (chip.boot_time, Some(chip.last_tick)),
             Some(&ongoing_alarms),
+            Some(&latched_alarms),
             Some(&chip.last_machine_snapshot),
             chip.last_data_snapshot.as_ref(),
         );
@@ -371,23 +404,52 @@ impl DisplayRenderer {
         let screen_data_status = DisplayDataStatus {
             chip_state: &chip.state,
             battery_soc: chip.estimated_soc,
+            snooze_remaining_seconds: chip
+                .settings
+                .snooze
+                .remaining(APP_ARGS.snooze_max())
+                .map(|remaining| remaining.as_secs()),
+            snooze_escalated: chip.settings.snooze.alarms_escalated,
         };
         let screen_data_heartbeat = DisplayDataHeartbeat {
             data_pressure: &chip.data_pressure,
         };
 
+        let screen_data_telemetry = DisplayDataTelemetry {
+            stale_seconds: chip.stale_data_elapsed().map(|elapsed| elapsed.as_secs()),
+            triggered_percent: chip.breaths.triggered_percent(),
+        };
+
         let screen_data_graph = DisplayDataGraph {
             width: GRAPH_WIDTH as _,
             height: GRAPH_HEIGHT as _,
-            data_pressure: &chip.data_pressure,
-            data_flow: &chip.data_flow,
+            data_pressure: chip
+                .freeze
+                .as_ref()
+                .map(|freeze| &freeze.data_pressure)
+                .unwrap_or(&chip.data_pressure),
+            data_flow: chip
+                .freeze
+                .as_ref()
+                .map(|freeze| &freeze.data_flow)
+                .unwrap_or(&chip.data_flow),
             chip_state: &chip.state,
             machine_snapshot: &chip.last_machine_snapshot,
+            mode_settings: &chip.settings.mode.live,
+            replay_events: &chip.replay_events,
+            freeze: chip.freeze.as_ref(),
+            freeze_save: APP_ARGS.is_recording(),
             plot_graphs: &mut self.plot_graphs,
         };
 
         let screen_data_settings = DisplayDataSettings {
             images: &self.images,
+            mechanics: &chip.mechanics,
+            weaning: &chip.weaning,
+            weaning_criteria: &APP_ARGS.weaning_criteria,
+            weaning_available: chip.is_weaning_available(),
+            alarms_auto_available: chip.average_cycle_measures().is_some(),
+            session: &chip.session,
         };
 
         // Render screen data (depending on state, running or stopped)
@@ -397,6 +459,7 @@ impl DisplayRenderer {
                 screen_data_branding,
                 screen_data_status,
                 screen_data_heartbeat,
+                screen_data_telemetry,
                 screen_data_graph,
                 screen_data_settings,
                 &chip.settings,
@@ -408,6 +471,7 @@ impl DisplayRenderer {
                 screen_data_branding,
                 screen_data_status,
                 screen_data_heartbeat,
+                screen_data_telemetry,
                 screen_data_graph,
                 screen_data_settings,
                 &chip.settings,
@@ -416,5 +480,10 @@ impl DisplayRenderer {
 
             _ => unreachable!(),
         };
+
+        // Render settings notice? (over the whole screen, until it gets dismissed)
+        if let Some(warning) = APP_SETTINGS.read().unwrap().warning {
+            screen.render_settings_notice(warning);
+        }
     }
 }
@@ -4,7 +4,6 @@
 // License: Public Domain License
 
 use chrono::{offset::Utc, DateTime};
-use conrod_core::color::{self, Color};
 
 use makair_telemetry::alarm::AlarmCode;
 use makair_telemetry::structures::{
@@ -16,16 +15,21 @@ use crate::chip::settings::{
     snooze::SettingsSnooze, ChipSettings,
 };
 use crate::config::environment::*;
+use crate::config::settings::ConfigSettingsWarning;
 use crate::locale::end_of_line::end_of_line_to_locales;
 use crate::locale::error::error_to_locales;
-use crate::utilities::units::{convert_ml_to_l, convert_mmh2o_to_cmh2o, ConvertMode};
+use crate::utilities::units::{
+    convert_cmh2o_to_pressure_unit, convert_lpm_to_flow_unit, convert_ml_to_l,
+    convert_ml_to_volume_unit, convert_mmh2o_to_cmh2o, convert_mmh2o_to_pressure_unit, ConvertMode,
+};
 use crate::widget::*;
-use crate::{APP_ARGS, APP_I18N};
+use crate::{APP_ARGS, APP_I18N, APP_SETTINGS};
 
 use super::data::*;
 use super::fonts::Fonts;
 use super::identifiers::Ids;
 use super::renderer::DisplayRendererStates;
+use super::theme::Theme;
 use super::widget::{ControlWidget, ControlWidgetType};
 
 pub struct ScreenModalsOpen {
@@ -40,6 +44,7 @@ pub struct Screen<'a> {
     ids: &'a Ids,
     timers: (Option<DateTime<Utc>>, Option<u64>),
     ongoing_alarms: Option<&'a [(AlarmCode, AlarmPriority)]>,
+    latched_alarms: Option<&'a [(AlarmCode, AlarmPriority)]>,
     machine_snapshot: Option<&'a MachineStateSnapshot>,
     data_snapshot: Option<&'a DataSnapshot>,
     widgets: ControlWidget<'a>,
@@ -58,12 +63,15 @@ impl ScreenModalsOpen {
 }
 
 impl<'a> Screen<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         ui: conrod_core::UiCell<'a>,
         ids: &'a Ids,
         fonts: &'a Fonts,
+        theme: &'static Theme,
         timers: (Option<DateTime<Utc>>, Option<u64>),
         ongoing_alarms: Option<&'a [(AlarmCode, AlarmPriority)]>,
+        latched_alarms: Option<&'a [(AlarmCode, AlarmPriority)]>,
         machine_snapshot: Option<&'a MachineStateSnapshot>,
         data_snapshot: Option<&'a DataSnapshot>,
     ) -> Screen<'a> {
@@ -71,9 +79,10 @@ impl<'a> Screen<'a> {
             ids,
             timers,
             ongoing_alarms,
+            latched_alarms,
             machine_snapshot,
             data_snapshot,
-            widgets: ControlWidget::new(ui, fonts),
+            widgets: ControlWidget::new(ui, fonts, theme),
         }
     }
 
@@ -84,6 +93,7 @@ impl<'a> Screen<'a> {
         branding_data: DisplayDataBranding<'a>,
         status_data: DisplayDataStatus<'a>,
         heartbeat_data: DisplayDataHeartbeat<'a>,
+        telemetry_data: DisplayDataTelemetry,
         graph_data: DisplayDataGraph<'a>,
         settings_data: DisplayDataSettings<'a>,
         settings: &'a ChipSettings,
@@ -111,7 +121,12 @@ impl<'a> Screen<'a> {
         self.render_graph(graph_data);
 
         // Render bottom elements
-        self.render_telemetry(&settings.mode);
+        self.render_telemetry(&settings.mode, &telemetry_data);
+
+        // Render stale telemetry overlay? (if data is not being updated anymore)
+        if let Some(stale_seconds) = telemetry_data.stale_seconds {
+            self.render_telemetry_stale(stale_seconds);
+        }
 
         // Render modals (as needed)
         self.render_settings(settings, modals, settings_data);
@@ -124,6 +139,7 @@ impl<'a> Screen<'a> {
         branding_data: DisplayDataBranding<'a>,
         status_data: DisplayDataStatus<'a>,
         heartbeat_data: DisplayDataHeartbeat<'a>,
+        telemetry_data: DisplayDataTelemetry,
         graph_data: DisplayDataGraph<'a>,
         settings_data: DisplayDataSettings<'a>,
         settings: &'a ChipSettings,
@@ -135,6 +151,7 @@ impl<'a> Screen<'a> {
             branding_data,
             status_data,
             heartbeat_data,
+            telemetry_data,
             graph_data,
             settings_data,
             settings,
@@ -153,8 +170,8 @@ impl<'a> Screen<'a> {
             height: DISPLAY_STOP_MESSAGE_CONTAINER_HEIGHT,
             padding: None,
             colors: Some((
-                Color::Rgba(0.0, 0.0, 0.0, 0.98),
-                Color::Rgba(1.0, 1.0, 1.0, 0.075),
+                self.widgets.theme.modal.stop_background,
+                self.widgets.theme.modal.stop_border,
             )),
             background_sizes: Some((GRAPH_WIDTH, GRAPH_HEIGHT)),
         }));
@@ -246,7 +263,7 @@ impl<'a> Screen<'a> {
     fn render_background(&mut self) {
         self.widgets
             .render(ControlWidgetType::Background(background::Config {
-                color: color::BLACK,
+                color: self.widgets.theme.base.background,
                 id: self.ids.background,
             }));
     }
@@ -320,6 +337,7 @@ impl<'a> Screen<'a> {
                 alarm_messages_containers: &self.ids.alarm_messages_containers,
                 alarm_messages: &self.ids.alarm_messages,
                 alarms: self.ongoing_alarms.unwrap(),
+                latched_alarms: self.latched_alarms.unwrap(),
             }));
     }
 
@@ -351,7 +369,11 @@ impl<'a> Screen<'a> {
                 unit_text: self.ids.status_unit_text,
                 power_box: self.ids.status_power_box,
                 power_text: self.ids.status_power_text,
+                snooze_box: self.ids.status_snooze_box,
+                snooze_text: self.ids.status_snooze_text,
                 battery_soc: status_data.battery_soc,
+                snooze_remaining_seconds: status_data.snooze_remaining_seconds,
+                snooze_escalated: status_data.snooze_escalated,
                 chip_state: status_data.chip_state,
                 data_snapshot: self.data_snapshot,
                 alarms: self.ongoing_alarms.unwrap(),
@@ -397,17 +419,40 @@ impl<'a> Screen<'a> {
                 self.ids.graph_flow_saturate_low,
                 self.ids.graph_flow_saturate_high,
             ),
-            boot_time: self.timers.0,
-            last_tick: self.timers.1,
+            freeze_ids: (
+                self.ids.graph_freeze_button,
+                self.ids.graph_freeze_text,
+                self.ids.graph_freeze_save_button,
+                self.ids.graph_freeze_save_text,
+            ),
+            cursor_ids: (
+                self.ids.graph_cursor_overlay,
+                self.ids.graph_cursor_line,
+                self.ids.graph_cursor_box,
+                self.ids.graph_cursor_text,
+            ),
+            // Notice: when frozen, the graph time range is the one at the time it was frozen
+            boot_time: graph_data
+                .freeze
+                .map(|freeze| freeze.boot_time)
+                .or(self.timers.0),
+            last_tick: graph_data
+                .freeze
+                .map(|freeze| freeze.last_tick)
+                .or(self.timers.1),
             data_pressure: graph_data.data_pressure,
             data_flow: graph_data.data_flow,
             chip_state: graph_data.chip_state,
             machine_snapshot: graph_data.machine_snapshot,
+            mode_settings: graph_data.mode_settings,
+            replay_events: graph_data.replay_events,
+            freeze: graph_data.freeze,
+            freeze_save: graph_data.freeze_save,
             plot_graphs: graph_data.plot_graphs,
         }));
     }
 
-    fn render_telemetry(&mut self, mode: &'a SettingsMode) {
+    fn render_telemetry(&mut self, mode: &'a SettingsMode, telemetry_data: &DisplayDataTelemetry) {
         let machine_snapshot = self.machine_snapshot.unwrap();
 
         // Check if at least a pressure value is known (otherwise, all pressure widgets should \
@@ -442,6 +487,9 @@ impl<'a> Screen<'a> {
 
         // Initialize the ratio widget
         self.render_telemetry_ratio(&machine_snapshot, mode);
+
+        // Initialize the triggered breaths widget
+        self.render_telemetry_triggered(telemetry_data.triggered_percent);
     }
 
     fn render_telemetry_graph(&mut self) {
@@ -463,7 +511,7 @@ impl<'a> Screen<'a> {
                 separator: self.ids.mode_overview_separator,
                 text_class: self.ids.mode_overview_text_class,
                 text_type: self.ids.mode_overview_text_type,
-                background_color: Color::Rgba(1.0, 1.0, 1.0, 1.0),
+                background_color: self.widgets.theme.mode.overview_background,
                 width: TELEMETRY_WIDGET_RIGHT_SIZE_WIDTH,
                 height: TELEMETRY_WIDGET_RIGHT_MODE_HEIGHT,
                 mode_settings: mode,
@@ -475,20 +523,23 @@ impl<'a> Screen<'a> {
         machine_snapshot: &MachineStateSnapshot,
         has_target_pressure: bool,
     ) {
+        let pressure_unit = APP_SETTINGS.read().unwrap().pressure_unit;
+
         self.widgets
             .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
                 title: APP_I18N.t("telemetry-label-peak"),
                 value_measured: Some(if !has_target_pressure {
                     TELEMETRY_WIDGET_VALUE_EMPTY.to_owned()
                 } else {
-                    convert_mmh2o_to_cmh2o(
+                    convert_mmh2o_to_pressure_unit(
                         ConvertMode::Rounded,
                         machine_snapshot.previous_peak_pressure as f64,
+                        pressure_unit,
                     )
                     .to_string()
                 }),
                 value_target: None,
-                unit: APP_I18N.t("telemetry-unit-cmh2o"),
+                unit: APP_I18N.t(pressure_unit.to_locale_key()),
                 ids: (
                     self.ids.telemetry_widgets_right,
                     self.ids.peak_parent,
@@ -504,7 +555,7 @@ impl<'a> Screen<'a> {
                 x_position: 0.0,
                 y_position: TELEMETRY_WIDGET_RIGHT_POSITION_Y_BASE
                     - TELEMETRY_WIDGET_RIGHT_SIZE_HEIGHT * 1.0,
-                background_color: Color::Rgba(39.0 / 255.0, 66.0 / 255.0, 100.0 / 255.0, 1.0),
+                background_color: self.widgets.theme.telemetry.peak,
                 width: TELEMETRY_WIDGET_RIGHT_SIZE_WIDTH,
                 height: TELEMETRY_WIDGET_RIGHT_SIZE_HEIGHT,
             }));
@@ -516,15 +567,18 @@ impl<'a> Screen<'a> {
         mode: &'a SettingsMode,
         has_target_pressure: bool,
     ) {
+        let pressure_unit = APP_SETTINGS.read().unwrap().pressure_unit;
+
         self.widgets
             .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
                 title: APP_I18N.t("telemetry-label-plateau"),
                 value_measured: Some(if !has_target_pressure {
                     TELEMETRY_WIDGET_VALUE_EMPTY.to_owned()
                 } else {
-                    convert_mmh2o_to_cmh2o(
+                    convert_mmh2o_to_pressure_unit(
                         ConvertMode::Rounded,
                         machine_snapshot.previous_plateau_pressure as f64,
+                        pressure_unit,
                     )
                     .to_string()
                 }),
@@ -533,9 +587,16 @@ impl<'a> Screen<'a> {
                 {
                     None
                 } else {
-                    Some(machine_snapshot.plateau_command.to_string())
+                    Some(
+                        convert_cmh2o_to_pressure_unit(
+                            ConvertMode::Rounded,
+                            machine_snapshot.plateau_command as f64,
+                            pressure_unit,
+                        )
+                        .to_string(),
+                    )
                 },
-                unit: APP_I18N.t("telemetry-unit-cmh2o"),
+                unit: APP_I18N.t(pressure_unit.to_locale_key()),
                 ids: (
                     self.ids.telemetry_widgets_right,
                     self.ids.plateau_parent,
@@ -552,7 +613,7 @@ impl<'a> Screen<'a> {
                 y_position: TELEMETRY_WIDGET_RIGHT_POSITION_Y_BASE
                     - TELEMETRY_WIDGET_RIGHT_SIZE_HEIGHT * 2.0
                     - TELEMETRY_WIDGET_SPACING_SIDES,
-                background_color: Color::Rgba(66.0 / 255.0, 44.0 / 255.0, 85.0 / 255.0, 1.0),
+                background_color: self.widgets.theme.telemetry.plateau,
                 width: TELEMETRY_WIDGET_RIGHT_SIZE_WIDTH,
                 height: TELEMETRY_WIDGET_RIGHT_SIZE_HEIGHT,
             }));
@@ -563,24 +624,34 @@ impl<'a> Screen<'a> {
         machine_snapshot: &MachineStateSnapshot,
         has_target_pressure: bool,
     ) {
+        let pressure_unit = APP_SETTINGS.read().unwrap().pressure_unit;
+
         self.widgets
             .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
                 title: APP_I18N.t("telemetry-label-expiratory"),
                 value_measured: Some(if !has_target_pressure {
                     TELEMETRY_WIDGET_VALUE_EMPTY.to_owned()
                 } else {
-                    convert_mmh2o_to_cmh2o(
+                    convert_mmh2o_to_pressure_unit(
                         ConvertMode::Rounded,
                         machine_snapshot.previous_peep_pressure as f64,
+                        pressure_unit,
                     )
                     .to_string()
                 }),
                 value_target: if !has_target_pressure {
                     None
                 } else {
-                    Some(machine_snapshot.peep_command.to_string())
+                    Some(
+                        convert_cmh2o_to_pressure_unit(
+                            ConvertMode::Rounded,
+                            machine_snapshot.peep_command as f64,
+                            pressure_unit,
+                        )
+                        .to_string(),
+                    )
                 },
-                unit: APP_I18N.t("telemetry-unit-cmh2o"),
+                unit: APP_I18N.t(pressure_unit.to_locale_key()),
                 ids: (
                     self.ids.telemetry_widgets_right,
                     self.ids.peep_parent,
@@ -597,7 +668,7 @@ impl<'a> Screen<'a> {
                 y_position: TELEMETRY_WIDGET_RIGHT_POSITION_Y_BASE
                     - TELEMETRY_WIDGET_RIGHT_SIZE_HEIGHT * 3.0
                     - TELEMETRY_WIDGET_SPACING_SIDES * 2.0,
-                background_color: Color::Rgba(76.0 / 255.0, 73.0 / 255.0, 25.0 / 255.0, 1.0),
+                background_color: self.widgets.theme.telemetry.peep,
                 width: TELEMETRY_WIDGET_RIGHT_SIZE_WIDTH,
                 height: TELEMETRY_WIDGET_RIGHT_SIZE_HEIGHT,
             }));
@@ -606,6 +677,7 @@ impl<'a> Screen<'a> {
     fn render_telemetry_cycles(&mut self, machine_snapshot: &MachineStateSnapshot) {
         // Acquire measured cycles per minute
         let measured_cpm = machine_snapshot.previous_cpm.unwrap_or(0);
+        let flow_unit = APP_SETTINGS.read().unwrap().flow_unit;
 
         self.widgets
             .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
@@ -635,7 +707,7 @@ impl<'a> Screen<'a> {
                 ),
                 x_position: 0.0,
                 y_position: 0.0,
-                background_color: Color::Rgba(47.0 / 255.0, 74.0 / 255.0, 16.0 / 255.0, 1.0),
+                background_color: self.widgets.theme.telemetry.cycles,
                 width: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH,
                 height: LAYOUT_FOOTER_SIZE_HEIGHT,
             }));
@@ -648,6 +720,7 @@ impl<'a> Screen<'a> {
     ) {
         // Acquire measured volume
         let measured_volume = machine_snapshot.previous_volume.unwrap_or(0);
+        let volume_unit = APP_SETTINGS.read().unwrap().volume_unit;
 
         // Check if should show target tidal volume
         let has_target_volume_tidal =
@@ -657,16 +730,30 @@ impl<'a> Screen<'a> {
             .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
                 title: APP_I18N.t("telemetry-label-tidal"),
                 value_measured: Some(if measured_volume > 0 || has_target_volume_tidal {
-                    measured_volume.to_string()
+                    APP_I18N.format_number(
+                        convert_ml_to_volume_unit(
+                            ConvertMode::WithDecimals,
+                            measured_volume as f64,
+                            volume_unit,
+                        ),
+                        volume_unit.decimals(),
+                    )
                 } else {
                     TELEMETRY_WIDGET_VALUE_EMPTY.to_owned()
                 }),
                 value_target: if has_target_volume_tidal {
-                    Some(mode.live.volume_tidal.to_string())
+                    Some(APP_I18N.format_number(
+                        convert_ml_to_volume_unit(
+                            ConvertMode::WithDecimals,
+                            mode.live.volume_tidal as f64,
+                            volume_unit,
+                        ),
+                        volume_unit.decimals(),
+                    ))
                 } else {
                     None
                 },
-                unit: APP_I18N.t("telemetry-unit-milliliters"),
+                unit: APP_I18N.t(volume_unit.to_locale_key()),
                 ids: (
                     self.ids.cycles_parent,
                     self.ids.tidal_parent,
@@ -681,7 +768,7 @@ impl<'a> Screen<'a> {
                 ),
                 x_position: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH + TELEMETRY_WIDGET_SPACING_SIDES,
                 y_position: 0.0,
-                background_color: Color::Rgba(52.0 / 255.0, 52.0 / 255.0, 52.0 / 255.0, 1.0),
+                background_color: self.widgets.theme.telemetry.volume,
                 width: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH,
                 height: LAYOUT_FOOTER_SIZE_HEIGHT,
             }));
@@ -696,18 +783,23 @@ impl<'a> Screen<'a> {
             .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
                 title: APP_I18N.t("telemetry-label-minute-volume"),
                 value_measured: Some(if measured_cpm > 0 && measured_volume > 0 {
-                    format!(
-                        "{:.1}",
-                        convert_ml_to_l(
+                    // Notice: the minute volume is a flow, thus it is shown in the flow unit.
+                    APP_I18N.format_number(
+                        convert_lpm_to_flow_unit(
                             ConvertMode::WithDecimals,
-                            (measured_cpm as u16 * measured_volume) as f64,
-                        )
+                            convert_ml_to_l(
+                                ConvertMode::WithDecimals,
+                                (measured_cpm as u16 * measured_volume) as f64,
+                            ),
+                            flow_unit,
+                        ),
+                        1,
                     )
                 } else {
                     TELEMETRY_WIDGET_VALUE_EMPTY.to_owned()
                 }),
                 value_target: None,
-                unit: APP_I18N.t("telemetry-unit-lpm"),
+                unit: APP_I18N.t(flow_unit.to_locale_key()),
                 ids: (
                     self.ids.tidal_parent,
                     self.ids.minute_volume_parent,
@@ -722,7 +814,7 @@ impl<'a> Screen<'a> {
                 ),
                 x_position: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH + TELEMETRY_WIDGET_SPACING_SIDES,
                 y_position: 0.0,
-                background_color: Color::Rgba(52.0 / 255.0, 52.0 / 255.0, 52.0 / 255.0, 1.0),
+                background_color: self.widgets.theme.telemetry.volume,
                 width: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH,
                 height: LAYOUT_FOOTER_SIZE_HEIGHT,
             }));
@@ -781,14 +873,20 @@ impl<'a> Screen<'a> {
                 unit: if computed_expiratory_term < 1.0 {
                     APP_I18N.t("telemetry-unit-milliseconds")
                 } else {
-                    format!(
-                        "{} ({} {})",
-                        &APP_I18N.t("telemetry-unit-milliseconds"),
-                        &APP_I18N.t("telemetry-label-ratio-details"),
-                        format!(
-                            "{}:{:.1}",
-                            TELEMETRY_WIDGET_CYCLES_RATIO_INSPIRATION, computed_expiratory_term,
-                        )
+                    APP_I18N.t_args(
+                        "telemetry-label-ratio-details",
+                        &[
+                            ("unit", APP_I18N.t("telemetry-unit-milliseconds").into()),
+                            (
+                                "ratio",
+                                format!(
+                                    "{}:{}",
+                                    TELEMETRY_WIDGET_CYCLES_RATIO_INSPIRATION,
+                                    APP_I18N.format_number(computed_expiratory_term, 1)
+                                )
+                                .into(),
+                            ),
+                        ],
                     )
                 },
                 ids: (
@@ -805,12 +903,66 @@ impl<'a> Screen<'a> {
                 ),
                 x_position: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH + TELEMETRY_WIDGET_SPACING_SIDES,
                 y_position: 0.0,
-                background_color: color::BLUE,
+                background_color: self.widgets.theme.telemetry.ratio,
+                width: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH,
+                height: LAYOUT_FOOTER_SIZE_HEIGHT,
+            }));
+    }
+
+    fn render_telemetry_triggered(&mut self, triggered_percent: Option<f64>) {
+        self.widgets
+            .render(ControlWidgetType::TelemetryView(telemetry_view::Config {
+                title: APP_I18N.t("telemetry-label-triggered"),
+                value_measured: Some(
+                    triggered_percent
+                        .map(|percent| format!("{:.0}", percent))
+                        .unwrap_or_else(|| TELEMETRY_WIDGET_VALUE_EMPTY.to_owned()),
+                ),
+                value_target: None,
+                unit: APP_I18N.t("telemetry-unit-percent"),
+                ids: (
+                    self.ids.ratio_parent,
+                    self.ids.triggered_parent,
+                    self.ids.triggered_title,
+                    self.ids.triggered_value_measured,
+                    (
+                        self.ids.triggered_value_arrow_main,
+                        self.ids.triggered_value_arrow_line,
+                    ),
+                    self.ids.triggered_value_target,
+                    Some(self.ids.triggered_unit),
+                ),
+                x_position: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH + TELEMETRY_WIDGET_SPACING_SIDES,
+                y_position: 0.0,
+                background_color: self.widgets.theme.telemetry.triggered,
                 width: TELEMETRY_WIDGET_BOTTOM_SIZE_WIDTH,
                 height: LAYOUT_FOOTER_SIZE_HEIGHT,
             }));
     }
 
+    pub fn render_settings_notice(&mut self, warning: ConfigSettingsWarning) {
+        self.widgets
+            .render(ControlWidgetType::SettingsNotice(settings_notice::Config {
+                parent: self.ids.layout_body,
+                message_box: self.ids.settings_notice_box,
+                message_text: self.ids.settings_notice_text,
+                warning,
+            }));
+    }
+
+    fn render_telemetry_stale(&mut self, elapsed_seconds: u64) {
+        self.widgets
+            .render(ControlWidgetType::TelemetryStale(telemetry_stale::Config {
+                parent_right: self.ids.telemetry_widgets_right,
+                parent_bottom: self.ids.layout_footer,
+                overlay_right: self.ids.telemetry_stale_overlay_right,
+                overlay_bottom: self.ids.telemetry_stale_overlay_bottom,
+                message_box: self.ids.telemetry_stale_message_box,
+                message_text: self.ids.telemetry_stale_message_text,
+                elapsed_seconds,
+            }));
+    }
+
     fn render_modal(&mut self, width: f64, height: f64, padding: Option<f64>, with_save: bool) {
         self.widgets.render(ControlWidgetType::Modal(modal::Config {
             parent: self.ids.background,
@@ -844,9 +996,13 @@ impl<'a> Screen<'a> {
         } else if modals.snooze {
             self.render_snooze_settings(&settings.snooze);
         } else if modals.advanced {
-            self.render_advanced_settings(&settings.advanced);
+            self.render_advanced_settings(
+                &settings.advanced,
+                settings.mode.live.alarm_threshold_leak,
+                settings_data,
+            );
         } else if modals.mode {
-            self.render_mode_settings(&settings.mode);
+            self.render_mode_settings(&settings.mode, settings_data);
         }
     }
 
@@ -939,7 +1095,12 @@ impl<'a> Screen<'a> {
             }));
     }
 
-    fn render_advanced_settings(&mut self, settings: &'a SettingsAdvanced) {
+    fn render_advanced_settings(
+        &mut self,
+        settings: &'a SettingsAdvanced,
+        leak_threshold: usize,
+        settings_data: DisplayDataSettings<'a>,
+    ) {
         self.render_modal(
             ADVANCED_SETTINGS_MODAL_WIDTH,
             ADVANCED_SETTINGS_MODAL_HEIGTH,
@@ -960,6 +1121,12 @@ impl<'a> Screen<'a> {
                 machine_snapshot: &self.machine_snapshot.unwrap(),
                 data_snapshot: self.data_snapshot,
                 alarms: self.ongoing_alarms.unwrap(),
+                mechanics: settings_data.mechanics,
+                leak_threshold,
+                weaning: settings_data.weaning,
+                weaning_criteria: settings_data.weaning_criteria,
+                weaning_available: settings_data.weaning_available,
+                session: settings_data.session,
 
                 advanced_container_parent: self.ids.modal_container,
                 advanced_container_widget: self.ids.advanced_container,
@@ -971,24 +1138,116 @@ impl<'a> Screen<'a> {
 
                 advanced_group_tab_buttons: [
                     self.ids.advanced_group_tab_statistics_button,
+                    self.ids.advanced_group_tab_mechanics_button,
+                    self.ids.advanced_group_tab_weaning_button,
+                    self.ids.advanced_group_tab_session_button,
+                    self.ids.advanced_group_tab_preferences_button,
                     self.ids.advanced_group_tab_settings_button,
                 ],
 
                 advanced_group_tab_texts: [
                     self.ids.advanced_group_tab_statistics_text,
+                    self.ids.advanced_group_tab_mechanics_text,
+                    self.ids.advanced_group_tab_weaning_text,
+                    self.ids.advanced_group_tab_session_text,
+                    self.ids.advanced_group_tab_preferences_text,
                     self.ids.advanced_group_tab_settings_text,
                 ],
 
                 field_locale_ids: gen_render_advanced_settings_field_ids!(self, locale),
+                field_theme_ids: gen_render_advanced_settings_field_ids!(self, theme),
 
                 text_date_ids: gen_render_advanced_settings_text_ids!(self, date),
                 text_time_ids: gen_render_advanced_settings_text_ids!(self, time),
                 text_timezone_ids: gen_render_advanced_settings_text_ids!(self, timezone),
+
+                field_weaning_duration_ids: gen_render_advanced_settings_field_ids!(
+                    self,
+                    weaning_duration
+                ),
+                button_weaning_toggle_ids: (
+                    self.ids.advanced_weaning_toggle_text,
+                    self.ids.advanced_weaning_toggle_button,
+                    self.ids.advanced_weaning_toggle_button_text,
+                ),
+
+                text_weaning_elapsed_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    weaning_elapsed
+                ),
+                text_weaning_rsbi_ids: gen_render_advanced_settings_text_ids!(self, weaning_rsbi),
+                text_weaning_rate_ids: gen_render_advanced_settings_text_ids!(self, weaning_rate),
+                text_weaning_minute_volume_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    weaning_minute_volume
+                ),
+                text_weaning_result_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    weaning_result
+                ),
+
+                button_session_patient_ids: (
+                    self.ids.advanced_session_patient_text,
+                    self.ids.advanced_session_patient_button,
+                    self.ids.advanced_session_patient_button_text,
+                ),
+                text_session_started_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    session_started
+                ),
+                text_session_duration_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    session_duration
+                ),
+                text_session_events_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    session_events
+                ),
+                text_session_record_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    session_record
+                ),
+                button_session_toggle_ids: (
+                    self.ids.advanced_session_toggle_text,
+                    self.ids.advanced_session_toggle_button,
+                    self.ids.advanced_session_toggle_button_text,
+                ),
+                button_session_new_ids: (
+                    self.ids.advanced_session_new_text,
+                    self.ids.advanced_session_new_button,
+                    self.ids.advanced_session_new_button_text,
+                ),
+
+                field_graph_seconds_ids: gen_render_advanced_settings_field_ids!(
+                    self,
+                    graph_seconds
+                ),
+                field_pressure_unit_ids: gen_render_advanced_settings_field_ids!(
+                    self,
+                    pressure_unit
+                ),
+                field_flow_unit_ids: gen_render_advanced_settings_field_ids!(self, flow_unit),
+                field_volume_unit_ids: gen_render_advanced_settings_field_ids!(self, volume_unit),
+                field_snooze_max_ids: gen_render_advanced_settings_field_ids!(self, snooze_max),
+                field_brightness_ids: gen_render_advanced_settings_field_ids!(self, brightness),
+                field_preset_age_ids: gen_render_advanced_settings_field_ids!(self, preset_age),
+                field_preset_gender_ids: gen_render_advanced_settings_field_ids!(
+                    self,
+                    preset_gender
+                ),
+                text_record_directory_ids: gen_render_advanced_settings_text_ids!(
+                    self,
+                    record_directory
+                ),
             },
         ));
     }
 
-    fn render_mode_settings(&mut self, settings: &'a SettingsMode) {
+    fn render_mode_settings(
+        &mut self,
+        settings: &'a SettingsMode,
+        settings_data: DisplayDataSettings<'a>,
+    ) {
         self.render_modal(
             MODE_SETTINGS_MODAL_WIDTH,
             MODE_SETTINGS_MODAL_HEIGTH,
@@ -1025,6 +1284,12 @@ impl<'a> Screen<'a> {
                     self.ids.mode_settings_selector_texts_vc_ac,
                 ],
 
+                leak_measured: self.ids.mode_settings_alarm_threshold_leak_measured,
+                leak: settings_data
+                    .mechanics
+                    .leak
+                    .zip(settings_data.mechanics.leak_percent),
+
                 group_wrapper: self.ids.mode_settings_group_wrapper,
                 content_wrapper: self.ids.mode_settings_content_wrapper,
                 form_wrapper: self.ids.mode_settings_form_wrapper,
@@ -1039,6 +1304,10 @@ impl<'a> Screen<'a> {
                     self.ids.mode_settings_group_tab_alarms_text,
                 ],
 
+                alarms_auto_button: self.ids.mode_settings_alarms_auto_button,
+                alarms_auto_text: self.ids.mode_settings_alarms_auto_text,
+                alarms_auto_available: settings_data.alarms_auto_available,
+
                 field_alarm_threshold_low_inspiratory_minute_volume_ids: gen_render_mode_settings_alarm_ids!(
                     self,
                     threshold_low_inspiratory_minute_volume
@@ -0,0 +1,484 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use conrod_core::color::{self, Color};
+use plotters::style::RGBColor;
+
+use crate::config::settings::ConfigSettingsTheme;
+
+pub struct Theme {
+    pub base: ThemeBase,
+    pub modal: ThemeModal,
+    pub status: ThemeStatus,
+    pub heartbeat: ThemeHeartbeat,
+    pub alarm: ThemeAlarm,
+    pub telemetry: ThemeTelemetry,
+    pub mode: ThemeMode,
+    pub graph: ThemeGraph,
+    pub end_of_line: ThemeEndOfLine,
+}
+
+pub struct ThemeBase {
+    pub background: Color,
+    pub text: Color,
+    pub text_changed: Color,
+    pub button: Color,
+    pub button_text: Color,
+    pub button_positive: Color,
+    pub button_negative: Color,
+    pub button_neutral: Color,
+    pub button_cancel: Color,
+    pub button_apply: Color,
+}
+
+pub struct ThemeModal {
+    pub overlay: Color,
+    pub border: Color,
+    pub background: Color,
+    pub tab_inactive: Color,
+    pub selector_default: Color,
+    pub selector_selected: Color,
+    pub stop_background: Color,
+    pub stop_border: Color,
+}
+
+pub struct ThemeStatus {
+    pub wrapper: Color,
+    pub unit_stopped: Color,
+    pub unit_active: Color,
+    pub battery_normal: Color,
+    pub battery_weak: Color,
+    pub snooze_normal: Color,
+    pub snooze_escalated: Color,
+    pub recording: Color,
+    pub notice: Color,
+}
+
+pub struct ThemeHeartbeat {
+    pub surround: Color,
+    pub inner_default: Color,
+    pub inner_overflow: Color,
+    pub ground_default: Color,
+    pub ground_overflow: Color,
+}
+
+pub struct ThemeAlarm {
+    pub container_active: Color,
+    pub container_empty: Color,
+    pub code_high: Color,
+    pub code_medium: Color,
+    pub code_low: Color,
+    pub message_high: Color,
+    pub message_medium: Color,
+    pub message_low: Color,
+    pub message_resolved: Color,
+}
+
+pub struct ThemeTelemetry {
+    pub peak: Color,
+    pub plateau: Color,
+    pub peep: Color,
+    pub cycles: Color,
+    pub volume: Color,
+    pub ratio: Color,
+    pub triggered: Color,
+    pub arrow: Color,
+    pub stale_overlay: Color,
+    pub stale_message: Color,
+}
+
+pub struct ThemeMode {
+    pub overview_background: Color,
+    pub overview_text: Color,
+    pub class_pressure: Color,
+    pub class_volume: Color,
+}
+
+pub struct ThemeGraph {
+    pub pressure_line: RGBColor,
+    pub flow_line: RGBColor,
+    pub mesh_axis: RGBColor,
+    pub mesh_bold: RGBColor,
+    pub mesh_light: RGBColor,
+    pub axis_font: RGBColor,
+    pub marker: RGBColor,
+    pub glyph: RGBColor,
+    pub limit_alarm: RGBColor,
+    pub limit_command: RGBColor,
+    pub cursor_overlay: Color,
+    pub cursor_line: Color,
+    pub cursor_box: Color,
+    pub saturate: Color,
+    pub label_background: Color,
+}
+
+pub struct ThemeEndOfLine {
+    pub title_background: Color,
+    pub title_separator: Color,
+    pub title_primary: Color,
+    pub title_secondary: Color,
+    pub step_progress: Color,
+    pub step_active: Color,
+    pub step_pending: Color,
+    pub step_done: Color,
+    pub index_active: Color,
+    pub index_pending: Color,
+    pub index_done: Color,
+    pub content_background: Color,
+    pub content_border_default: Color,
+    pub content_border_error: Color,
+    pub content_border_success: Color,
+    pub content_title: Color,
+    pub content_message: Color,
+    pub details_background: Color,
+    pub details_border: Color,
+    pub details_text: Color,
+}
+
+static THEME_DEFAULT: Theme = Theme {
+    base: ThemeBase {
+        background: color::BLACK,
+        text: color::WHITE,
+        text_changed: Color::Rgba(110.0 / 255.0, 191.0 / 255.0, 1.0, 1.0),
+        button: color::WHITE,
+        button_text: color::BLACK,
+        button_positive: color::DARK_GREEN,
+        button_negative: color::DARK_RED,
+        button_neutral: color::DARK_BLUE,
+        button_cancel: color::RED,
+        button_apply: color::BLUE,
+    },
+    modal: ThemeModal {
+        overlay: Color::Rgba(0.0, 0.0, 0.0, 0.8),
+        border: Color::Rgba(81.0 / 255.0, 81.0 / 255.0, 81.0 / 255.0, 1.0),
+        background: Color::Rgba(26.0 / 255.0, 26.0 / 255.0, 26.0 / 255.0, 1.0),
+        tab_inactive: Color::Rgba(48.0 / 255.0, 48.0 / 255.0, 48.0 / 255.0, 1.0),
+        selector_default: Color::Rgba(0.0, 0.0, 0.0, 0.975),
+        selector_selected: Color::Rgba(26.0 / 255.0, 26.0 / 255.0, 26.0 / 255.0, 1.0),
+        stop_background: Color::Rgba(0.0, 0.0, 0.0, 0.98),
+        stop_border: Color::Rgba(1.0, 1.0, 1.0, 0.075),
+    },
+    status: ThemeStatus {
+        wrapper: Color::Rgba(52.0 / 255.0, 52.0 / 255.0, 52.0 / 255.0, 1.0),
+        unit_stopped: Color::Rgba(180.0 / 255.0, 24.0 / 255.0, 28.0 / 255.0, 1.0),
+        unit_active: Color::Rgba(50.0 / 255.0, 186.0 / 255.0, 0.0, 1.0),
+        battery_normal: Color::Rgba(208.0 / 255.0, 92.0 / 255.0, 0.0, 1.0),
+        battery_weak: Color::Rgba(1.0, 0.0 / 255.0, 3.0 / 255.0, 1.0),
+        snooze_normal: Color::Rgba(52.0 / 255.0, 52.0 / 255.0, 52.0 / 255.0, 1.0),
+        snooze_escalated: Color::Rgba(1.0, 0.0 / 255.0, 3.0 / 255.0, 1.0),
+        recording: color::RED,
+        notice: Color::Rgba(208.0 / 255.0, 92.0 / 255.0, 0.0, 1.0),
+    },
+    heartbeat: ThemeHeartbeat {
+        surround: Color::Rgba(153.0 / 255.0, 153.0 / 255.0, 153.0 / 255.0, 1.0),
+        inner_default: color::WHITE,
+        inner_overflow: Color::Rgba(184.0 / 255.0, 1.0 / 255.0, 24.0 / 255.0, 1.0),
+        ground_default: Color::Rgba(116.0 / 255.0, 116.0 / 255.0, 116.0 / 255.0, 1.0),
+        ground_overflow: Color::Rgba(204.0 / 255.0, 204.0 / 255.0, 204.0 / 255.0, 1.0),
+    },
+    alarm: ThemeAlarm {
+        container_active: Color::Rgba(42.0 / 255.0, 42.0 / 255.0, 42.0 / 255.0, 0.96),
+        container_empty: Color::Rgba(17.0 / 255.0, 17.0 / 255.0, 17.0 / 255.0, 0.96),
+        code_high: Color::Rgba(1.0, 0.0 / 255.0, 3.0 / 255.0, 1.0),
+        code_medium: Color::Rgba(1.0, 135.0 / 255.0, 0.0, 1.0),
+        code_low: Color::Rgba(1.0, 195.0 / 255.0, 0.0, 1.0),
+        message_high: Color::Rgba(180.0 / 255.0, 24.0 / 255.0, 28.0 / 255.0, 1.0),
+        message_medium: Color::Rgba(189.0 / 255.0, 93.0 / 255.0, 0.0, 1.0),
+        message_low: Color::Rgba(174.0 / 255.0, 133.0 / 255.0, 0.0, 1.0),
+        message_resolved: Color::Rgba(80.0 / 255.0, 80.0 / 255.0, 80.0 / 255.0, 1.0),
+    },
+    telemetry: ThemeTelemetry {
+        peak: Color::Rgba(39.0 / 255.0, 66.0 / 255.0, 100.0 / 255.0, 1.0),
+        plateau: Color::Rgba(66.0 / 255.0, 44.0 / 255.0, 85.0 / 255.0, 1.0),
+        peep: Color::Rgba(76.0 / 255.0, 73.0 / 255.0, 25.0 / 255.0, 1.0),
+        cycles: Color::Rgba(47.0 / 255.0, 74.0 / 255.0, 16.0 / 255.0, 1.0),
+        volume: Color::Rgba(52.0 / 255.0, 52.0 / 255.0, 52.0 / 255.0, 1.0),
+        ratio: color::BLUE,
+        triggered: Color::Rgba(94.0 / 255.0, 54.0 / 255.0, 22.0 / 255.0, 1.0),
+        arrow: Color::Rgba(1.0, 1.0, 1.0, 0.25),
+        stale_overlay: Color::Rgba(20.0 / 255.0, 20.0 / 255.0, 20.0 / 255.0, 0.8),
+        stale_message: Color::Rgba(180.0 / 255.0, 24.0 / 255.0, 28.0 / 255.0, 1.0),
+    },
+    mode: ThemeMode {
+        overview_background: color::WHITE,
+        overview_text: color::BLACK,
+        class_pressure: Color::Rgba(14.0 / 255.0, 112.0 / 255.0, 182.0 / 255.0, 1.0),
+        class_volume: Color::Rgba(150.0 / 255.0, 20.0 / 255.0, 6.0 / 255.0, 1.0),
+    },
+    graph: ThemeGraph {
+        pressure_line: RGBColor(0, 196, 255),
+        flow_line: RGBColor(196, 37, 20),
+        mesh_axis: RGBColor(255, 255, 255),
+        mesh_bold: RGBColor(255, 255, 255),
+        mesh_light: RGBColor(0, 0, 0),
+        axis_font: RGBColor(255, 255, 255),
+        marker: RGBColor(255, 255, 255),
+        glyph: RGBColor(255, 213, 0),
+        limit_alarm: RGBColor(255, 64, 64),
+        limit_command: RGBColor(255, 255, 255),
+        cursor_overlay: Color::Rgba(0.0, 0.0, 0.0, 0.15),
+        cursor_line: Color::Rgba(1.0, 1.0, 1.0, 0.9),
+        cursor_box: Color::Rgba(0.0, 0.0, 0.0, 0.8),
+        saturate: Color::Rgba(184.0 / 255.0, 1.0 / 255.0, 24.0 / 255.0, 1.0),
+        label_background: color::BLACK,
+    },
+    end_of_line: ThemeEndOfLine {
+        title_background: color::RED,
+        title_separator: Color::Rgba(1.0, 1.0, 1.0, 0.15),
+        title_primary: color::WHITE,
+        title_secondary: Color::Rgba(1.0, 1.0, 1.0, 0.65),
+        step_progress: Color::Rgba(1.0, 1.0, 1.0, 0.075),
+        step_active: color::WHITE,
+        step_pending: Color::Rgba(1.0, 1.0, 1.0, 0.02),
+        step_done: Color::Rgba(1.0, 1.0, 1.0, 0.45),
+        index_active: color::BLACK,
+        index_pending: Color::Rgba(1.0, 1.0, 1.0, 0.1),
+        index_done: color::WHITE,
+        content_background: Color::Rgba(1.0, 1.0, 1.0, 0.01),
+        content_border_default: Color::Rgba(42.0 / 255.0, 42.0 / 255.0, 42.0 / 255.0, 1.0),
+        content_border_error: Color::Rgba(219.0 / 255.0, 16.0 / 255.0, 16.0 / 255.0, 1.0),
+        content_border_success: Color::Rgba(6.0 / 255.0, 174.0 / 255.0, 33.0 / 255.0, 1.0),
+        content_title: color::WHITE,
+        content_message: Color::Rgba(1.0, 1.0, 1.0, 0.5),
+        details_background: color::BLACK,
+        details_border: Color::Rgba(1.0, 1.0, 1.0, 0.3),
+        details_text: color::WHITE,
+    },
+};
+
+// Night theme, with dimmed colors so that the screen does not light up the room at night
+// Notice: alarm colors are only slightly dimmed, as alarms must stay as noticeable as possible.
+static THEME_NIGHT: Theme = Theme {
+    base: ThemeBase {
+        background: color::BLACK,
+        text: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+        text_changed: Color::Rgba(60.0 / 255.0, 105.0 / 255.0, 140.0 / 255.0, 1.0),
+        button: Color::Rgba(140.0 / 255.0, 140.0 / 255.0, 140.0 / 255.0, 1.0),
+        button_text: color::BLACK,
+        button_positive: Color::Rgba(20.0 / 255.0, 80.0 / 255.0, 0.0, 1.0),
+        button_negative: Color::Rgba(110.0 / 255.0, 10.0 / 255.0, 10.0 / 255.0, 1.0),
+        button_neutral: Color::Rgba(10.0 / 255.0, 40.0 / 255.0, 90.0 / 255.0, 1.0),
+        button_cancel: Color::Rgba(140.0 / 255.0, 20.0 / 255.0, 20.0 / 255.0, 1.0),
+        button_apply: Color::Rgba(20.0 / 255.0, 50.0 / 255.0, 110.0 / 255.0, 1.0),
+    },
+    modal: ThemeModal {
+        overlay: Color::Rgba(0.0, 0.0, 0.0, 0.85),
+        border: Color::Rgba(50.0 / 255.0, 50.0 / 255.0, 50.0 / 255.0, 1.0),
+        background: Color::Rgba(16.0 / 255.0, 16.0 / 255.0, 16.0 / 255.0, 1.0),
+        tab_inactive: Color::Rgba(32.0 / 255.0, 32.0 / 255.0, 32.0 / 255.0, 1.0),
+        selector_default: Color::Rgba(0.0, 0.0, 0.0, 0.975),
+        selector_selected: Color::Rgba(16.0 / 255.0, 16.0 / 255.0, 16.0 / 255.0, 1.0),
+        stop_background: Color::Rgba(0.0, 0.0, 0.0, 0.98),
+        stop_border: Color::Rgba(1.0, 1.0, 1.0, 0.05),
+    },
+    status: ThemeStatus {
+        wrapper: Color::Rgba(32.0 / 255.0, 32.0 / 255.0, 32.0 / 255.0, 1.0),
+        unit_stopped: Color::Rgba(110.0 / 255.0, 15.0 / 255.0, 17.0 / 255.0, 1.0),
+        unit_active: Color::Rgba(30.0 / 255.0, 110.0 / 255.0, 0.0, 1.0),
+        battery_normal: Color::Rgba(125.0 / 255.0, 55.0 / 255.0, 0.0, 1.0),
+        battery_weak: Color::Rgba(200.0 / 255.0, 0.0 / 255.0, 2.0 / 255.0, 1.0),
+        snooze_normal: Color::Rgba(32.0 / 255.0, 32.0 / 255.0, 32.0 / 255.0, 1.0),
+        snooze_escalated: Color::Rgba(200.0 / 255.0, 0.0 / 255.0, 2.0 / 255.0, 1.0),
+        recording: Color::Rgba(150.0 / 255.0, 20.0 / 255.0, 20.0 / 255.0, 1.0),
+        notice: Color::Rgba(125.0 / 255.0, 55.0 / 255.0, 0.0, 1.0),
+    },
+    heartbeat: ThemeHeartbeat {
+        surround: Color::Rgba(92.0 / 255.0, 92.0 / 255.0, 92.0 / 255.0, 1.0),
+        inner_default: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+        inner_overflow: Color::Rgba(150.0 / 255.0, 1.0 / 255.0, 20.0 / 255.0, 1.0),
+        ground_default: Color::Rgba(70.0 / 255.0, 70.0 / 255.0, 70.0 / 255.0, 1.0),
+        ground_overflow: Color::Rgba(122.0 / 255.0, 122.0 / 255.0, 122.0 / 255.0, 1.0),
+    },
+    alarm: ThemeAlarm {
+        container_active: Color::Rgba(25.0 / 255.0, 25.0 / 255.0, 25.0 / 255.0, 0.96),
+        container_empty: Color::Rgba(10.0 / 255.0, 10.0 / 255.0, 10.0 / 255.0, 0.96),
+        code_high: Color::Rgba(220.0 / 255.0, 0.0 / 255.0, 3.0 / 255.0, 1.0),
+        code_medium: Color::Rgba(200.0 / 255.0, 106.0 / 255.0, 0.0, 1.0),
+        code_low: Color::Rgba(200.0 / 255.0, 153.0 / 255.0, 0.0, 1.0),
+        message_high: Color::Rgba(135.0 / 255.0, 18.0 / 255.0, 21.0 / 255.0, 1.0),
+        message_medium: Color::Rgba(142.0 / 255.0, 70.0 / 255.0, 0.0, 1.0),
+        message_low: Color::Rgba(130.0 / 255.0, 100.0 / 255.0, 0.0, 1.0),
+        message_resolved: Color::Rgba(48.0 / 255.0, 48.0 / 255.0, 48.0 / 255.0, 1.0),
+    },
+    telemetry: ThemeTelemetry {
+        peak: Color::Rgba(20.0 / 255.0, 34.0 / 255.0, 52.0 / 255.0, 1.0),
+        plateau: Color::Rgba(34.0 / 255.0, 23.0 / 255.0, 44.0 / 255.0, 1.0),
+        peep: Color::Rgba(40.0 / 255.0, 38.0 / 255.0, 13.0 / 255.0, 1.0),
+        cycles: Color::Rgba(24.0 / 255.0, 38.0 / 255.0, 8.0 / 255.0, 1.0),
+        volume: Color::Rgba(27.0 / 255.0, 27.0 / 255.0, 27.0 / 255.0, 1.0),
+        ratio: Color::Rgba(26.0 / 255.0, 51.0 / 255.0, 84.0 / 255.0, 1.0),
+        triggered: Color::Rgba(49.0 / 255.0, 28.0 / 255.0, 11.0 / 255.0, 1.0),
+        arrow: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 0.25),
+        stale_overlay: Color::Rgba(10.0 / 255.0, 10.0 / 255.0, 10.0 / 255.0, 0.85),
+        stale_message: Color::Rgba(110.0 / 255.0, 15.0 / 255.0, 17.0 / 255.0, 1.0),
+    },
+    mode: ThemeMode {
+        overview_background: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+        overview_text: color::BLACK,
+        class_pressure: Color::Rgba(8.0 / 255.0, 67.0 / 255.0, 109.0 / 255.0, 1.0),
+        class_volume: Color::Rgba(100.0 / 255.0, 13.0 / 255.0, 4.0 / 255.0, 1.0),
+    },
+    graph: ThemeGraph {
+        pressure_line: RGBColor(0, 118, 153),
+        flow_line: RGBColor(130, 25, 13),
+        mesh_axis: RGBColor(150, 150, 150),
+        mesh_bold: RGBColor(150, 150, 150),
+        mesh_light: RGBColor(0, 0, 0),
+        axis_font: RGBColor(150, 150, 150),
+        marker: RGBColor(150, 150, 150),
+        glyph: RGBColor(153, 128, 0),
+        limit_alarm: RGBColor(170, 43, 43),
+        limit_command: RGBColor(150, 150, 150),
+        cursor_overlay: Color::Rgba(0.0, 0.0, 0.0, 0.15),
+        cursor_line: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 0.9),
+        cursor_box: Color::Rgba(0.0, 0.0, 0.0, 0.8),
+        saturate: Color::Rgba(150.0 / 255.0, 1.0 / 255.0, 20.0 / 255.0, 1.0),
+        label_background: color::BLACK,
+    },
+    end_of_line: ThemeEndOfLine {
+        title_background: Color::Rgba(140.0 / 255.0, 20.0 / 255.0, 20.0 / 255.0, 1.0),
+        title_separator: Color::Rgba(1.0, 1.0, 1.0, 0.1),
+        title_primary: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+        title_secondary: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 0.65),
+        step_progress: Color::Rgba(1.0, 1.0, 1.0, 0.05),
+        step_active: Color::Rgba(140.0 / 255.0, 140.0 / 255.0, 140.0 / 255.0, 1.0),
+        step_pending: Color::Rgba(1.0, 1.0, 1.0, 0.02),
+        step_done: Color::Rgba(1.0, 1.0, 1.0, 0.3),
+        index_active: color::BLACK,
+        index_pending: Color::Rgba(1.0, 1.0, 1.0, 0.08),
+        index_done: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+        content_background: Color::Rgba(1.0, 1.0, 1.0, 0.01),
+        content_border_default: Color::Rgba(32.0 / 255.0, 32.0 / 255.0, 32.0 / 255.0, 1.0),
+        content_border_error: Color::Rgba(150.0 / 255.0, 12.0 / 255.0, 12.0 / 255.0, 1.0),
+        content_border_success: Color::Rgba(4.0 / 255.0, 110.0 / 255.0, 20.0 / 255.0, 1.0),
+        content_title: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+        content_message: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 0.5),
+        details_background: color::BLACK,
+        details_border: Color::Rgba(1.0, 1.0, 1.0, 0.2),
+        details_text: Color::Rgba(150.0 / 255.0, 150.0 / 255.0, 150.0 / 255.0, 1.0),
+    },
+};
+
+// High-contrast theme, with pure white texts and borders, and saturated colors for accessibility
+static THEME_HIGH_CONTRAST: Theme = Theme {
+    base: ThemeBase {
+        background: color::BLACK,
+        text: color::WHITE,
+        text_changed: Color::Rgba(1.0, 221.0 / 255.0, 0.0, 1.0),
+        button: color::WHITE,
+        button_text: color::BLACK,
+        button_positive: Color::Rgba(0.0, 100.0 / 255.0, 0.0, 1.0),
+        button_negative: Color::Rgba(170.0 / 255.0, 0.0, 0.0, 1.0),
+        button_neutral: Color::Rgba(0.0, 50.0 / 255.0, 150.0 / 255.0, 1.0),
+        button_cancel: Color::Rgba(200.0 / 255.0, 0.0, 0.0, 1.0),
+        button_apply: Color::Rgba(0.0, 60.0 / 255.0, 200.0 / 255.0, 1.0),
+    },
+    modal: ThemeModal {
+        overlay: Color::Rgba(0.0, 0.0, 0.0, 0.9),
+        border: color::WHITE,
+        background: color::BLACK,
+        tab_inactive: Color::Rgba(70.0 / 255.0, 70.0 / 255.0, 70.0 / 255.0, 1.0),
+        selector_default: color::BLACK,
+        selector_selected: Color::Rgba(70.0 / 255.0, 70.0 / 255.0, 70.0 / 255.0, 1.0),
+        stop_background: color::BLACK,
+        stop_border: Color::Rgba(1.0, 1.0, 1.0, 0.6),
+    },
+    status: ThemeStatus {
+        wrapper: Color::Rgba(70.0 / 255.0, 70.0 / 255.0, 70.0 / 255.0, 1.0),
+        unit_stopped: Color::Rgba(200.0 / 255.0, 0.0, 0.0, 1.0),
+        unit_active: Color::Rgba(0.0, 130.0 / 255.0, 0.0, 1.0),
+        battery_normal: Color::Rgba(200.0 / 255.0, 80.0 / 255.0, 0.0, 1.0),
+        battery_weak: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        snooze_normal: Color::Rgba(70.0 / 255.0, 70.0 / 255.0, 70.0 / 255.0, 1.0),
+        snooze_escalated: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        recording: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        notice: Color::Rgba(200.0 / 255.0, 80.0 / 255.0, 0.0, 1.0),
+    },
+    heartbeat: ThemeHeartbeat {
+        surround: color::WHITE,
+        inner_default: color::WHITE,
+        inner_overflow: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        ground_default: Color::Rgba(128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0, 1.0),
+        ground_overflow: color::WHITE,
+    },
+    alarm: ThemeAlarm {
+        container_active: Color::Rgba(60.0 / 255.0, 60.0 / 255.0, 60.0 / 255.0, 0.98),
+        container_empty: Color::Rgba(30.0 / 255.0, 30.0 / 255.0, 30.0 / 255.0, 0.98),
+        code_high: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        code_medium: Color::Rgba(1.0, 140.0 / 255.0, 0.0, 1.0),
+        code_low: Color::Rgba(1.0, 210.0 / 255.0, 0.0, 1.0),
+        message_high: Color::Rgba(190.0 / 255.0, 0.0, 0.0, 1.0),
+        message_medium: Color::Rgba(200.0 / 255.0, 90.0 / 255.0, 0.0, 1.0),
+        message_low: Color::Rgba(140.0 / 255.0, 100.0 / 255.0, 0.0, 1.0),
+        message_resolved: Color::Rgba(90.0 / 255.0, 90.0 / 255.0, 90.0 / 255.0, 1.0),
+    },
+    telemetry: ThemeTelemetry {
+        peak: Color::Rgba(0.0, 50.0 / 255.0, 120.0 / 255.0, 1.0),
+        plateau: Color::Rgba(80.0 / 255.0, 0.0, 110.0 / 255.0, 1.0),
+        peep: Color::Rgba(90.0 / 255.0, 80.0 / 255.0, 0.0, 1.0),
+        cycles: Color::Rgba(30.0 / 255.0, 90.0 / 255.0, 0.0, 1.0),
+        volume: Color::Rgba(60.0 / 255.0, 60.0 / 255.0, 60.0 / 255.0, 1.0),
+        ratio: Color::Rgba(0.0, 70.0 / 255.0, 170.0 / 255.0, 1.0),
+        triggered: Color::Rgba(130.0 / 255.0, 60.0 / 255.0, 0.0, 1.0),
+        arrow: Color::Rgba(1.0, 1.0, 1.0, 0.6),
+        stale_overlay: Color::Rgba(0.0, 0.0, 0.0, 0.9),
+        stale_message: Color::Rgba(200.0 / 255.0, 0.0, 0.0, 1.0),
+    },
+    mode: ThemeMode {
+        overview_background: color::WHITE,
+        overview_text: color::BLACK,
+        class_pressure: Color::Rgba(0.0, 80.0 / 255.0, 170.0 / 255.0, 1.0),
+        class_volume: Color::Rgba(170.0 / 255.0, 0.0, 0.0, 1.0),
+    },
+    graph: ThemeGraph {
+        pressure_line: RGBColor(0, 220, 255),
+        flow_line: RGBColor(255, 70, 40),
+        mesh_axis: RGBColor(255, 255, 255),
+        mesh_bold: RGBColor(255, 255, 255),
+        mesh_light: RGBColor(0, 0, 0),
+        axis_font: RGBColor(255, 255, 255),
+        marker: RGBColor(255, 255, 255),
+        glyph: RGBColor(255, 230, 0),
+        limit_alarm: RGBColor(255, 40, 40),
+        limit_command: RGBColor(255, 255, 255),
+        cursor_overlay: Color::Rgba(0.0, 0.0, 0.0, 0.3),
+        cursor_line: color::WHITE,
+        cursor_box: Color::Rgba(0.0, 0.0, 0.0, 0.95),
+        saturate: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        label_background: color::BLACK,
+    },
+    end_of_line: ThemeEndOfLine {
+        title_background: Color::Rgba(200.0 / 255.0, 0.0, 0.0, 1.0),
+        title_separator: Color::Rgba(1.0, 1.0, 1.0, 0.5),
+        title_primary: color::WHITE,
+        title_secondary: color::WHITE,
+        step_progress: Color::Rgba(1.0, 1.0, 1.0, 0.4),
+        step_active: color::WHITE,
+        step_pending: Color::Rgba(1.0, 1.0, 1.0, 0.3),
+        step_done: Color::Rgba(1.0, 1.0, 1.0, 0.8),
+        index_active: color::BLACK,
+        index_pending: Color::Rgba(1.0, 1.0, 1.0, 0.5),
+        index_done: color::WHITE,
+        content_background: color::BLACK,
+        content_border_default: Color::Rgba(1.0, 1.0, 1.0, 0.6),
+        content_border_error: Color::Rgba(1.0, 0.0, 0.0, 1.0),
+        content_border_success: Color::Rgba(0.0, 200.0 / 255.0, 0.0, 1.0),
+        content_title: color::WHITE,
+        content_message: color::WHITE,
+        details_background: color::BLACK,
+        details_border: color::WHITE,
+        details_text: color::WHITE,
+    },
+};
+
+impl Theme {
+    pub fn from_setting(theme: ConfigSettingsTheme) -> &'static Self {
+        match theme {
+            ConfigSettingsTheme::Default => &THEME_DEFAULT,
+            ConfigSettingsTheme::Night => &THEME_NIGHT,
+            ConfigSettingsTheme::HighContrast => &THEME_HIGH_CONTRAST,
+        }
+    }
+}
@@ -3,18 +3,36 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use conrod_core::position::Place;
+use conrod_core::widget::Id as WidgetId;
+use conrod_core::{Positionable, Scalar};
+
+use crate::locale::direction::LocaleDirection;
 use crate::widget::*;
+use crate::APP_I18N;
 
 use super::fonts::Fonts;
+use super::theme::Theme;
 
 pub struct ControlWidget<'a> {
     pub ui: conrod_core::UiCell<'a>,
     pub fonts: &'a Fonts,
+    pub theme: &'static Theme,
+    pub direction: LocaleDirection,
 }
 
 impl<'a> ControlWidget<'a> {
-    pub fn new(ui: conrod_core::UiCell<'a>, fonts: &'a Fonts) -> ControlWidget<'a> {
-        ControlWidget { ui, fonts }
+    pub fn new(
+        ui: conrod_core::UiCell<'a>,
+        fonts: &'a Fonts,
+        theme: &'static Theme,
+    ) -> ControlWidget<'a> {
+        ControlWidget {
+            ui,
+            fonts,
+            theme,
+            direction: APP_I18N.direction(),
+        }
     }
 }
 
@@ -32,6 +50,8 @@ gen_widget_impls!(
     Modal -> modal,
     Stop -> stop,
     TelemetryContainer -> telemetry_container,
+    TelemetryStale -> telemetry_stale,
+    SettingsNotice -> settings_notice,
     TelemetryView -> telemetry_view,
     Layout -> layout,
     PresetSettings -> preset_settings['a],
@@ -41,3 +61,122 @@ gen_widget_impls!(
     ModeSettings -> mode_settings['a],
     ModeOverview -> mode_overview['a],
 );
+
+// Positioning helpers that mirror layouts for right-to-left locales, where 'start' is the left \
+//   side in left-to-right locales, and the right side in right-to-left locales.
+pub trait DirectionalPositionable: Positionable + Sized {
+    fn top_start_of(self, direction: LocaleDirection, other: WidgetId) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.top_left_of(other),
+            LocaleDirection::RightToLeft => self.top_right_of(other),
+        }
+    }
+
+    fn top_end_of(self, direction: LocaleDirection, other: WidgetId) -> Self {
+        self.top_start_of(direction.reverse(), other)
+    }
+
+    fn top_start_with_margins_on(
+        self,
+        direction: LocaleDirection,
+        other: WidgetId,
+        top: Scalar,
+        start: Scalar,
+    ) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.top_left_with_margins_on(other, top, start),
+            LocaleDirection::RightToLeft => self.top_right_with_margins_on(other, top, start),
+        }
+    }
+
+    fn mid_start_of(self, direction: LocaleDirection, other: WidgetId) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.mid_left_of(other),
+            LocaleDirection::RightToLeft => self.mid_right_of(other),
+        }
+    }
+
+    fn mid_end_of(self, direction: LocaleDirection, other: WidgetId) -> Self {
+        self.mid_start_of(direction.reverse(), other)
+    }
+
+    fn mid_start_with_margin_on(
+        self,
+        direction: LocaleDirection,
+        other: WidgetId,
+        start: Scalar,
+    ) -> Self {
+        self.mid_end_with_margin_on(direction.reverse(), other, start)
+    }
+
+    fn mid_end_with_margin_on(
+        self,
+        direction: LocaleDirection,
+        other: WidgetId,
+        end: Scalar,
+    ) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.mid_right_with_margin_on(other, end),
+            LocaleDirection::RightToLeft => self.mid_left_with_margin_on(other, end),
+        }
+    }
+
+    fn bottom_start_of(self, direction: LocaleDirection, other: WidgetId) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.bottom_left_of(other),
+            LocaleDirection::RightToLeft => self.bottom_right_of(other),
+        }
+    }
+
+    fn bottom_end_of(self, direction: LocaleDirection, other: WidgetId) -> Self {
+        self.bottom_start_of(direction.reverse(), other)
+    }
+
+    fn bottom_start_with_margins_on(
+        self,
+        direction: LocaleDirection,
+        other: WidgetId,
+        bottom: Scalar,
+        start: Scalar,
+    ) -> Self {
+        self.bottom_end_with_margins_on(direction.reverse(), other, bottom, start)
+    }
+
+    fn bottom_end_with_margins_on(
+        self,
+        direction: LocaleDirection,
+        other: WidgetId,
+        bottom: Scalar,
+        end: Scalar,
+    ) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.bottom_right_with_margins_on(other, bottom, end),
+            LocaleDirection::RightToLeft => self.bottom_left_with_margins_on(other, bottom, end),
+        }
+    }
+
+    fn start_from(self, direction: LocaleDirection, other: WidgetId, margin: Scalar) -> Self {
+        self.end_from(direction.reverse(), other, margin)
+    }
+
+    fn end_from(self, direction: LocaleDirection, other: WidgetId, margin: Scalar) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.right_from(other, margin),
+            LocaleDirection::RightToLeft => self.left_from(other, margin),
+        }
+    }
+
+    fn x_place_start_on(
+        self,
+        direction: LocaleDirection,
+        other: WidgetId,
+        margin: Option<Scalar>,
+    ) -> Self {
+        match direction {
+            LocaleDirection::LeftToRight => self.x_place_on(other, Place::Start(margin)),
+            LocaleDirection::RightToLeft => self.x_place_on(other, Place::End(margin)),
+        }
+    }
+}
+
+impl<T: Positionable> DirectionalPositionable for T {}
@@ -14,11 +14,13 @@ use crate::config::environment::*;
 #[cfg(feature = "fonts-cjk")]
 use crate::EmbeddedFontsCjk;
 use crate::EmbeddedFontsDefault;
+#[cfg(feature = "fonts-rtl")]
+use crate::EmbeddedFontsRtl;
 use crate::EmbeddedImages;
 use crate::APP_ARGS;
 
 use super::drawer::DisplayDrawerBuilder;
-use super::fonts::Fonts;
+use super::fonts::{Fonts, FontsSet};
 
 pub struct DisplayWindowBuilder;
 pub struct DisplayWindow;
@@ -48,6 +50,14 @@ lazy_static! {
         gen_load_font!(EmbeddedFontsCjk, "notosans_cjk_regular");
 }
 
+#[cfg(feature = "fonts-rtl")]
+lazy_static! {
+    static ref FONT_RTL_DEJAVUSANS_REGULAR: conrod_core::text::Font =
+        gen_load_font!(EmbeddedFontsRtl, "dejavusans_regular");
+    static ref FONT_RTL_DEJAVUSANS_BOLD: conrod_core::text::Font =
+        gen_load_font!(EmbeddedFontsRtl, "dejavusans_bold");
+}
+
 impl DisplayWindow {
     pub fn spawn(&self, chip: Chip) {
         debug!("spawning window...");
@@ -96,22 +106,38 @@ impl DisplayWindow {
         .build();
 
         // Load all required fonts to interface
-        // Notice: this depends on the in-use translation, as eg. CJK glyphs are not included in \
-        //   the default font; used only for Chinese, Japanese and Korean (ie. CJK).
-        let fonts = match APP_ARGS.translation.as_str() {
-            #[cfg(feature = "fonts-cjk")]
-            "zh" | "ko" | "ja" => Fonts::new(
-                interface.fonts.insert(FONT_CJK_NOTOSANS_ALL.clone()),
-                interface.fonts.insert(FONT_CJK_NOTOSANS_ALL.clone()),
-            ),
-            _ => Fonts::new(
-                interface
-                    .fonts
-                    .insert(FONT_DEFAULT_NOTOSANS_REGULAR.clone()),
-                interface.fonts.insert(FONT_DEFAULT_NOTOSANS_BOLD.clone()),
-            ),
+        // Notice: CJK and RTL (eg. Hebrew) glyphs are not included in the default font, which is \
+        //   why CJK and RTL font sets are also loaded if available. The in-use set gets selected \
+        //   at render time from the current locale, so that switching locale at runtime also \
+        //   switches fonts.
+        let fonts_default = FontsSet::new(
+            interface
+                .fonts
+                .insert(FONT_DEFAULT_NOTOSANS_REGULAR.clone()),
+            interface.fonts.insert(FONT_DEFAULT_NOTOSANS_BOLD.clone()),
+        );
+
+        #[cfg(feature = "fonts-cjk")]
+        let fonts_cjk = {
+            let font_cjk = interface.fonts.insert(FONT_CJK_NOTOSANS_ALL.clone());
+
+            Some(FontsSet::new(font_cjk, font_cjk))
         };
 
+        #[cfg(not(feature = "fonts-cjk"))]
+        let fonts_cjk = None;
+
+        #[cfg(feature = "fonts-rtl")]
+        let fonts_rtl = Some(FontsSet::new(
+            interface.fonts.insert(FONT_RTL_DEJAVUSANS_REGULAR.clone()),
+            interface.fonts.insert(FONT_RTL_DEJAVUSANS_BOLD.clone()),
+        ));
+
+        #[cfg(not(feature = "fonts-rtl"))]
+        let fonts_rtl = None;
+
+        let fonts = Fonts::new(fonts_default, fonts_cjk, fonts_rtl);
+
         // Create window contents drawer
         let mut drawer =
             DisplayDrawerBuilder::new(window, context, events_loop, &mut interface, fonts, chip);
@@ -3,37 +3,134 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
-use std::sync::RwLock;
+use std::collections::HashSet;
+use std::sync::{Mutex, RwLock};
 
 use fluent::bundle::FluentBundle;
-use fluent::{FluentArgs, FluentResource};
+use fluent::{FluentArgs, FluentError, FluentResource, FluentValue};
 use intl_memoizer::concurrent::IntlLangMemoizer;
 
+use super::direction::LocaleDirection;
+use super::locales::LocaleCode;
+
 pub struct LocaleAccessor {
     bundle: RwLock<FluentBundle<FluentResource, IntlLangMemoizer>>,
+    fallback: FluentBundle<FluentResource, IntlLangMemoizer>,
+    warned_keys: Mutex<HashSet<String>>,
+}
+
+#[derive(Debug)]
+enum LocaleAccessorError {
+    KeyNotFound,
+    FormatFailed(Vec<FluentError>),
 }
 
 impl LocaleAccessor {
-    pub fn new(bundle: FluentBundle<FluentResource, IntlLangMemoizer>) -> Self {
+    pub fn new(
+        bundle: FluentBundle<FluentResource, IntlLangMemoizer>,
+        fallback: FluentBundle<FluentResource, IntlLangMemoizer>,
+    ) -> Self {
         LocaleAccessor {
             bundle: RwLock::new(bundle),
+            fallback,
+            warned_keys: Mutex::new(HashSet::new()),
         }
     }
 
     pub fn replace(&self, bundle: FluentBundle<FluentResource, IntlLangMemoizer>) {
         *self.bundle.write().unwrap() = bundle;
+
+        // Keys that were missing from the previous locale may also be missing from the new one, \
+        //   thus warn about them again
+        self.warned_keys.lock().unwrap().clear();
     }
 
     pub fn t(&self, key: &str) -> String {
         self.process(key, None)
     }
 
+    pub fn t_args(&self, key: &str, arguments: &[(&str, FluentValue)]) -> String {
+        let mut fluent_arguments = FluentArgs::new();
+
+        for (name, value) in arguments {
+            fluent_arguments.set(*name, value.clone());
+        }
+
+        self.process(key, Some(&fluent_arguments))
+    }
+
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        // Notice: numbers with decimals are formatted there rather than by Fluent, as Fluent \
+        //   does not format numbers according to the locale.
+        let formatted = format!("{:.*}", decimals, value);
+
+        match self.locale().decimal_separator() {
+            '.' => formatted,
+            separator => formatted.replace('.', &separator.to_string()),
+        }
+    }
+
+    pub fn direction(&self) -> LocaleDirection {
+        self.locale().direction()
+    }
+
     fn process(&self, key: &str, arguments: Option<&FluentArgs>) -> String {
-        let bundle = self.bundle.read().unwrap();
+        // Notice: text is drawn glyph after glyph from left to right, thus right-to-left \
+        //   messages must be reordered to their visual order before being rendered.
+        self.direction().to_visual(&self.lookup(key, arguments))
+    }
+
+    fn lookup(&self, key: &str, arguments: Option<&FluentArgs>) -> String {
+        // Notice: a missing or broken translation must never crash the UI, thus the message is \
+        //   looked up in the current locale, then in the fallback locale (English), and the key \
+        //   itself is used as a last resort.
+        let error = match Self::format(&self.bundle.read().unwrap(), key, arguments) {
+            Ok(formatted) => return formatted,
+            Err(error) => error,
+        };
+
+        match Self::format(&self.fallback, key, arguments) {
+            Ok(formatted) => {
+                self.warn_once(key, || {
+                    format!(
+                        "locale message unusable: {} ({:?}), using fallback locale",
+                        key, error
+                    )
+                });
+
+                formatted
+            }
+            Err(fallback_error) => {
+                self.warn_once(key, || {
+                    format!(
+                        "locale message unusable: {} ({:?}, fallback: {:?}), using key",
+                        key, error, fallback_error
+                    )
+                });
 
+                key.to_string()
+            }
+        }
+    }
+
+    fn locale(&self) -> LocaleCode {
+        self.bundle
+            .read()
+            .unwrap()
+            .locales
+            .first()
+            .and_then(|locale| LocaleCode::from_code(&locale.to_string()))
+            .unwrap_or_default()
+    }
+
+    fn format(
+        bundle: &FluentBundle<FluentResource, IntlLangMemoizer>,
+        key: &str,
+        arguments: Option<&FluentArgs>,
+    ) -> Result<String, LocaleAccessorError> {
         let message = bundle
             .get_message(key)
-            .unwrap_or_else(|| panic!("locale key not found: {}", key));
+            .ok_or(LocaleAccessorError::KeyNotFound)?;
 
         // Notice: return the key if the message has no value (eg. not yet translated)
         if let Some(pattern) = message.value() {
@@ -41,14 +138,170 @@ impl LocaleAccessor {
 
             let formatted = bundle.format_pattern(&pattern, arguments, &mut errors);
 
-            // Any error? Panic
             if !errors.is_empty() {
-                panic!("could not format pattern: {:?}", errors);
+                return Err(LocaleAccessorError::FormatFailed(errors));
             }
 
-            formatted.to_string()
+            Ok(formatted.to_string())
         } else {
-            key.to_string()
+            Ok(key.to_string())
+        }
+    }
+
+    fn warn_once<F>(&self, key: &str, message: F)
+    where
+        F: FnOnce() -> String,
+    {
+        // Only warn once per key, as messages are formatted on every frame
+        if self.warned_keys.lock().unwrap().insert(key.to_string()) {
+            warn!("{}", message());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static WARNINGS_LOGGER: WarningsLogger = WarningsLogger {
+        warnings: Mutex::new(Vec::new()),
+    };
+
+    // Captures warnings, so that tests can count them (as tests run in parallel, warnings must \
+    //   be filtered on keys that are unique to a test)
+    struct WarningsLogger {
+        warnings: Mutex<Vec<String>>,
+    }
+
+    impl WarningsLogger {
+        fn install(&'static self) {
+            if log::set_logger(self).is_ok() {
+                log::set_max_level(log::LevelFilter::Warn);
+            }
+        }
+
+        fn count(&self, key: &str) -> usize {
+            self.warnings
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|warning| warning.contains(&format!(" {} (", key)))
+                .count()
+        }
+    }
+
+    impl log::Log for WarningsLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Warn
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.warnings
+                    .lock()
+                    .unwrap()
+                    .push(record.args().to_string());
+            }
         }
+
+        fn flush(&self) {}
+    }
+
+    fn bundle(locale: &str, source: &str) -> FluentBundle<FluentResource, IntlLangMemoizer> {
+        let mut bundle = FluentBundle::new_concurrent(vec![locale.parse().unwrap()]);
+
+        bundle.set_use_isolating(false);
+        bundle
+            .add_resource(FluentResource::try_new(source.to_string()).unwrap())
+            .unwrap();
+
+        bundle
+    }
+
+    fn accessor() -> LocaleAccessor {
+        LocaleAccessor::new(
+            bundle(
+                "fr",
+                "modal-close = Fermer\n\
+                    modal-apply = Appliquer { -missing-term }\n\
+                    broken-everywhere = Cassé { -missing-term }\n",
+            ),
+            bundle(
+                "en",
+                "modal-close = Close\n\
+                    modal-apply = Apply\n\
+                    modal-cancel = Cancel\n\
+                    broken-everywhere = Broken { -missing-term }\n",
+            ),
+        )
+    }
+
+    // Notice: keys used in tests do not exist in locale files, thus they must not be passed to \
+    //   the accessor as literals, as the locale checker would then expect them to exist.
+    fn translate(accessor: &LocaleAccessor, key: &str) -> String {
+        accessor.t(key)
+    }
+
+    fn warned(accessor: &LocaleAccessor, key: &str) -> bool {
+        accessor.warned_keys.lock().unwrap().contains(key)
+    }
+
+    #[test]
+    fn uses_current_locale() {
+        let accessor = accessor();
+
+        assert_eq!(translate(&accessor, "modal-close"), "Fermer");
+        assert!(!warned(&accessor, "modal-close"));
+    }
+
+    #[test]
+    fn falls_back_to_english() {
+        let accessor = accessor();
+
+        // Missing from the current locale
+        assert_eq!(translate(&accessor, "modal-cancel"), "Cancel");
+        assert!(warned(&accessor, "modal-cancel"));
+
+        // Cannot be formatted in the current locale
+        assert_eq!(translate(&accessor, "modal-apply"), "Apply");
+        assert!(warned(&accessor, "modal-apply"));
+    }
+
+    #[test]
+    fn falls_back_to_key() {
+        let accessor = accessor();
+
+        // Missing from all locales
+        assert_eq!(translate(&accessor, "modal-unknown"), "modal-unknown");
+        assert!(warned(&accessor, "modal-unknown"));
+
+        // Cannot be formatted in any locale
+        assert_eq!(
+            translate(&accessor, "broken-everywhere"),
+            "broken-everywhere"
+        );
+        assert!(warned(&accessor, "broken-everywhere"));
+    }
+
+    #[test]
+    fn warns_once_per_key() {
+        WARNINGS_LOGGER.install();
+
+        let accessor = accessor();
+
+        for _ in 0..3 {
+            assert_eq!(translate(&accessor, "modal-snooze"), "modal-snooze");
+        }
+
+        assert_eq!(WARNINGS_LOGGER.count("modal-snooze"), 1);
+
+        // Keys are warned about again once the locale is replaced
+        accessor.replace(bundle("de", "modal-close = Schließen\n"));
+
+        for _ in 0..3 {
+            assert_eq!(translate(&accessor, "modal-snooze"), "modal-snooze");
+        }
+
+        assert_eq!(WARNINGS_LOGGER.count("modal-snooze"), 2);
     }
 }
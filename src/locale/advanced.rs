@@ -10,6 +10,10 @@ use crate::APP_I18N;
 pub fn group_tab_to_locale(group_tab: SettingsAdvancedGroupTab) -> String {
     let locale_key = match group_tab {
         SettingsAdvancedGroupTab::Statistics => "statistics",
+        SettingsAdvancedGroupTab::Mechanics => "mechanics",
+        SettingsAdvancedGroupTab::Weaning => "weaning",
+        SettingsAdvancedGroupTab::Session => "session",
+        SettingsAdvancedGroupTab::Preferences => "preferences",
         SettingsAdvancedGroupTab::Settings => "settings",
     };
 
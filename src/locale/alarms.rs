@@ -3,10 +3,28 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
-use makair_telemetry::alarm::AlarmCodeDescription;
+use makair_telemetry::alarm::{AlarmCode, AlarmCodeDescription};
 
+use crate::chip::alarms::{
+    ALARM_CODE_UI_COMPLIANCE_LOW, ALARM_CODE_UI_DRIVING_PRESSURE_HIGH,
+    ALARM_CODE_UI_RESISTANCE_HIGH, ALARM_CODE_UI_TELEMETRY_LOST,
+};
 use crate::APP_I18N;
 
+pub fn code_to_locale(code: AlarmCode) -> String {
+    // UI-originated alarms are not known from the telemetry library, thus their description \
+    //   cannot be acquired from their code, so they need to be mapped there.
+    match code.code() {
+        ALARM_CODE_UI_TELEMETRY_LOST => APP_I18N.t("alarms-message-telemetry-lost"),
+        ALARM_CODE_UI_DRIVING_PRESSURE_HIGH => {
+            APP_I18N.t("alarms-message-driving-pressure-too-high")
+        }
+        ALARM_CODE_UI_COMPLIANCE_LOW => APP_I18N.t("alarms-message-compliance-too-low"),
+        ALARM_CODE_UI_RESISTANCE_HIGH => APP_I18N.t("alarms-message-resistance-too-high"),
+        _ => description_to_locale(code.description()),
+    }
+}
+
 pub fn description_to_locale(description: AlarmCodeDescription) -> String {
     let locale_key = match description {
         AlarmCodeDescription::PlateauPressureNotReached => "plateau-pressure-not-reached",
@@ -0,0 +1,500 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use fluent::FluentResource;
+use fluent_syntax::ast;
+
+use super::locales::LocaleCode;
+
+const LOCALES_DIRECTORY: &str = "res/locales";
+const SOURCES_DIRECTORY: &str = "src";
+const LOCALE_EXTENSION: &str = "ftl";
+const SOURCE_EXTENSION: &str = "rs";
+const ACCESSOR_CALLS: [&str; 2] = [".t(", ".t_args("];
+
+pub struct LocaleChecker {
+    used_keys: HashSet<String>,
+    direct_keys: HashSet<String>,
+    dynamic_prefixes: Vec<String>,
+}
+
+pub struct LocaleCheckReport {
+    pub locales: Vec<LocaleCheckResult>,
+}
+
+#[derive(Default)]
+pub struct LocaleCheckResult {
+    pub code: String,
+    pub syntax_errors: Vec<String>,
+    pub missing: Vec<String>,
+    pub unused: Vec<String>,
+    pub extra: Vec<String>,
+    pub placeholders: Vec<String>,
+}
+
+type LocaleMessages = BTreeMap<String, BTreeSet<String>>;
+
+impl LocaleChecker {
+    pub fn check(root: &Path) -> LocaleCheckReport {
+        let checker = Self::from_sources(&root.join(SOURCES_DIRECTORY));
+
+        // Parse all locales (the default locale is the reference one, that all other locales \
+        //   get compared to)
+        let codes: Vec<String> = LocaleCode::list_all()
+            .iter()
+            .map(|code| code.to_code().to_string())
+            .collect();
+
+        let parsed: Vec<(String, LocaleMessages, Vec<String>)> = codes
+            .into_iter()
+            .map(|code| {
+                let (messages, syntax_errors) = Self::parse_locale(
+                    &root
+                        .join(LOCALES_DIRECTORY)
+                        .join(format!("{}.{}", code, LOCALE_EXTENSION)),
+                );
+
+                (code, messages, syntax_errors)
+            })
+            .collect();
+
+        let reference_code = LocaleCode::default().to_code().to_string();
+        let reference = parsed
+            .iter()
+            .find(|(code, _, _)| code == &reference_code)
+            .map(|(_, messages, _)| messages.clone())
+            .unwrap_or_default();
+
+        LocaleCheckReport {
+            locales: parsed
+                .into_iter()
+                .map(|(code, messages, syntax_errors)| {
+                    checker.check_locale(code, &messages, syntax_errors, &reference)
+                })
+                .collect(),
+        }
+    }
+
+    fn from_sources(directory: &Path) -> Self {
+        let mut checker = LocaleChecker {
+            used_keys: HashSet::new(),
+            direct_keys: HashSet::new(),
+            dynamic_prefixes: Vec::new(),
+        };
+
+        let mut literals = Vec::new();
+
+        Self::scan_sources(directory, &mut literals);
+
+        for (literal, direct) in literals {
+            if Self::is_key(&literal) {
+                // Keys that are directly passed to the translation accessor must exist, while \
+                //   other key-like literals may be keys returned by mapping functions
+                if direct {
+                    checker.direct_keys.insert(literal.clone());
+                }
+
+                checker.used_keys.insert(literal);
+            } else if let Some(prefix) = literal.find('{').map(|index| &literal[..index]) {
+                // Keys that are formatted at runtime (eg. 'alarms-message-{}') mark all keys \
+                //   starting with their prefix as used
+                if prefix.ends_with('-') && Self::is_key(prefix.trim_end_matches('-')) {
+                    checker.dynamic_prefixes.push(prefix.to_string());
+                }
+            }
+        }
+
+        checker
+    }
+
+    fn check_locale(
+        &self,
+        code: String,
+        messages: &LocaleMessages,
+        syntax_errors: Vec<String>,
+        reference: &LocaleMessages,
+    ) -> LocaleCheckResult {
+        let mut result = LocaleCheckResult {
+            code,
+            syntax_errors,
+            ..Default::default()
+        };
+
+        // Missing keys are either defined in the reference locale, or directly used in the code
+        let mut expected: BTreeSet<&String> = reference.keys().collect();
+
+        expected.extend(self.direct_keys.iter());
+
+        for key in expected {
+            if !messages.contains_key(key) {
+                result.missing.push(key.to_owned());
+            }
+        }
+
+        Self::compare_messages(messages, reference, &mut result);
+
+        for key in messages.keys() {
+            if reference.contains_key(key) && !self.is_used(key) {
+                result.unused.push(key.to_owned());
+            }
+        }
+
+        result
+    }
+
+    pub fn check_override(code: &str, source: &str, reference: &str) -> LocaleCheckResult {
+        // Check a locale override against the reference locale
+        // Notice: overrides are merged over a complete locale, thus they may only hold some \
+        //   messages. Also, sources are not available at runtime, thus unused keys are not \
+        //   checked there (those keys are the ones of the reference locale anyway).
+        let (messages, syntax_errors) = Self::parse_source(source);
+        let (reference, _) = Self::parse_source(reference);
+
+        let mut result = LocaleCheckResult {
+            code: code.to_string(),
+            syntax_errors,
+            ..Default::default()
+        };
+
+        Self::compare_messages(&messages, &reference, &mut result);
+
+        result
+    }
+
+    fn compare_messages(
+        messages: &LocaleMessages,
+        reference: &LocaleMessages,
+        result: &mut LocaleCheckResult,
+    ) {
+        for (key, variables) in messages {
+            match reference.get(key) {
+                Some(reference_variables) => {
+                    if variables != reference_variables {
+                        result.placeholders.push(format!(
+                            "{} (has: {:?}, expected: {:?})",
+                            key, variables, reference_variables
+                        ));
+                    }
+                }
+                None => result.extra.push(key.to_owned()),
+            }
+        }
+    }
+
+    fn is_used(&self, key: &str) -> bool {
+        self.used_keys.contains(key)
+            || self
+                .dynamic_prefixes
+                .iter()
+                .any(|prefix| key.starts_with(prefix.as_str()))
+    }
+
+    fn is_key(value: &str) -> bool {
+        // Keys are lowercase words separated with dashes (eg. 'telemetry-unit-cmh2o')
+        value.contains('-')
+            && value.starts_with(|character: char| character.is_ascii_lowercase())
+            && value.split('-').all(|word| {
+                !word.is_empty()
+                    && word.chars().all(|character| {
+                        character.is_ascii_lowercase() || character.is_ascii_digit()
+                    })
+            })
+    }
+
+    fn scan_sources(directory: &Path, literals: &mut Vec<(String, bool)>) {
+        let entries = match fs::read_dir(directory) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                Self::scan_sources(&path, literals);
+            } else if path.extension().and_then(|extension| extension.to_str())
+                == Some(SOURCE_EXTENSION)
+            {
+                if let Ok(source) = fs::read_to_string(&path) {
+                    Self::extract_literals(&source, literals);
+                }
+            }
+        }
+    }
+
+    fn extract_literals(source: &str, literals: &mut Vec<(String, bool)>) {
+        // Extract string literals, while skipping comments and character literals (which could \
+        //   hold quotes); each literal is marked as direct if passed to the translation accessor
+        let characters: Vec<char> = source.chars().collect();
+        let mut index = 0;
+
+        while index < characters.len() {
+            match (characters[index], characters.get(index + 1)) {
+                ('/', Some('/')) => {
+                    while index < characters.len() && characters[index] != '\n' {
+                        index += 1;
+                    }
+                }
+                ('/', Some('*')) => {
+                    index += 2;
+
+                    while index + 1 < characters.len()
+                        && !(characters[index] == '*' && characters[index + 1] == '/')
+                    {
+                        index += 1;
+                    }
+
+                    index += 2;
+                }
+                ('\'', Some('\\')) => {
+                    index += 2;
+
+                    while index < characters.len() && characters[index] != '\'' {
+                        index += 1;
+                    }
+
+                    index += 1;
+                }
+                ('\'', Some(_)) if characters.get(index + 2) == Some(&'\'') => {
+                    index += 3;
+                }
+                ('"', _) => {
+                    let direct = ACCESSOR_CALLS.iter().any(|call| {
+                        let call: Vec<char> = call.chars().collect();
+
+                        characters[..index].ends_with(&call)
+                    });
+
+                    let mut literal = String::new();
+
+                    index += 1;
+
+                    while index < characters.len() && characters[index] != '"' {
+                        if characters[index] == '\\' {
+                            literal.push(characters[index]);
+
+                            index += 1;
+                        }
+
+                        if let Some(character) = characters.get(index) {
+                            literal.push(*character);
+                        }
+
+                        index += 1;
+                    }
+
+                    literals.push((literal, direct));
+
+                    index += 1;
+                }
+                _ => {
+                    index += 1;
+                }
+            }
+        }
+    }
+
+    fn parse_locale(path: &Path) -> (LocaleMessages, Vec<String>) {
+        match fs::read_to_string(path) {
+            Ok(source) => Self::parse_source(&source),
+            Err(err) => (
+                LocaleMessages::new(),
+                vec![format!("could not read {}: {}", path.display(), err)],
+            ),
+        }
+    }
+
+    fn parse_source(source: &str) -> (LocaleMessages, Vec<String>) {
+        // Notice: a resource with syntax errors still holds all entries that could be parsed, \
+        //   thus those entries are checked anyway.
+        let (resource, syntax_errors) = match FluentResource::try_new(source.to_string()) {
+            Ok(resource) => (resource, Vec::new()),
+            Err((resource, errors)) => {
+                let syntax_errors = errors
+                    .iter()
+                    .map(|error| {
+                        let line = source[..error.pos.start.min(source.len())]
+                            .matches('\n')
+                            .count()
+                            + 1;
+
+                        format!("line {}: {:?}", line, error.kind)
+                    })
+                    .collect();
+
+                (resource, syntax_errors)
+            }
+        };
+
+        let mut messages = LocaleMessages::new();
+
+        for entry in resource.entries() {
+            if let ast::Entry::Message(message) = entry {
+                let mut variables = BTreeSet::new();
+
+                if let Some(pattern) = &message.value {
+                    Self::collect_pattern_variables(pattern, &mut variables);
+                }
+
+                for attribute in &message.attributes {
+                    Self::collect_pattern_variables(&attribute.value, &mut variables);
+                }
+
+                messages.insert(message.id.name.to_string(), variables);
+            }
+        }
+
+        (messages, syntax_errors)
+    }
+
+    fn collect_pattern_variables(pattern: &ast::Pattern<&str>, variables: &mut BTreeSet<String>) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                Self::collect_expression_variables(expression, variables);
+            }
+        }
+    }
+
+    fn collect_expression_variables(
+        expression: &ast::Expression<&str>,
+        variables: &mut BTreeSet<String>,
+    ) {
+        match expression {
+            ast::Expression::Select { selector, variants } => {
+                Self::collect_inline_variables(selector, variables);
+
+                for variant in variants {
+                    Self::collect_pattern_variables(&variant.value, variables);
+                }
+            }
+            ast::Expression::Inline(inline) => Self::collect_inline_variables(inline, variables),
+        }
+    }
+
+    fn collect_inline_variables(
+        inline: &ast::InlineExpression<&str>,
+        variables: &mut BTreeSet<String>,
+    ) {
+        match inline {
+            ast::InlineExpression::VariableReference { id } => {
+                variables.insert(id.name.to_string());
+            }
+            ast::InlineExpression::FunctionReference { arguments, .. } => {
+                for argument in &arguments.positional {
+                    Self::collect_inline_variables(argument, variables);
+                }
+
+                for argument in &arguments.named {
+                    Self::collect_inline_variables(&argument.value, variables);
+                }
+            }
+            ast::InlineExpression::Placeable { expression } => {
+                Self::collect_expression_variables(expression, variables);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl LocaleCheckReport {
+    pub fn is_ok(&self) -> bool {
+        self.locales.iter().all(|locale| locale.is_ok())
+    }
+}
+
+impl LocaleCheckResult {
+    pub fn is_ok(&self) -> bool {
+        self.syntax_errors.is_empty()
+            && self.missing.is_empty()
+            && self.unused.is_empty()
+            && self.extra.is_empty()
+            && self.placeholders.is_empty()
+    }
+}
+
+impl fmt::Display for LocaleCheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for locale in &self.locales {
+            if locale.is_ok() {
+                writeln!(f, "[{}] ok", locale.code)?;
+
+                continue;
+            }
+
+            writeln!(f, "[{}] has issues:", locale.code)?;
+
+            for (title, items) in &[
+                ("syntax error", &locale.syntax_errors),
+                ("missing key", &locale.missing),
+                ("unused key", &locale.unused),
+                ("extra key", &locale.extra),
+                ("placeholders mismatch", &locale.placeholders),
+            ] {
+                for item in items.iter() {
+                    writeln!(f, "  - {}: {}", title, item)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locales_are_complete() {
+        let report = LocaleChecker::check(Path::new(env!("CARGO_MANIFEST_DIR")));
+
+        assert!(report.is_ok(), "locales have issues:\n{}", report);
+    }
+
+    #[test]
+    fn checks_overrides() {
+        let reference = "modal-close = Close\n\
+            telemetry-stale-since = Data not updated since { $seconds }s\n";
+
+        assert!(LocaleChecker::check_override("fr", "modal-close = Fermer\n", reference).is_ok());
+
+        let result = LocaleChecker::check_override(
+            "fr",
+            "unknown-key = Inconnu\n\
+            telemetry-stale-since = Pas de données depuis { $secondes }s\n",
+            reference,
+        );
+
+        assert_eq!(result.extra, vec!["unknown-key".to_string()]);
+        assert_eq!(result.placeholders.len(), 1);
+    }
+
+    #[test]
+    fn extracts_literals() {
+        let mut literals = Vec::new();
+
+        // Notice: the source is not given as a raw string, as this file also gets scanned.
+        LocaleChecker::extract_literals(
+            "// APP_I18N.t(\"commented-key\")\n\
+            let quote = '\"';\n\
+            APP_I18N.t(\"direct-key\");\n\
+            let key = \"mapped-key\";\n\
+            APP_I18N.t(&format!(\"dynamic-key-{}\", value));",
+            &mut literals,
+        );
+
+        assert_eq!(
+            literals,
+            vec![
+                ("direct-key".to_string(), true),
+                ("mapped-key".to_string(), false),
+                ("dynamic-key-{}".to_string(), false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum LocaleDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+enum LocaleDirectionClass {
+    LeftToRight,
+    RightToLeft,
+    Number,
+    Neutral,
+}
+
+impl LocaleDirection {
+    pub fn is_rtl(&self) -> bool {
+        *self == Self::RightToLeft
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Self::LeftToRight => Self::RightToLeft,
+            Self::RightToLeft => Self::LeftToRight,
+        }
+    }
+
+    pub fn to_visual(&self, text: &str) -> String {
+        match self {
+            Self::LeftToRight => text.to_string(),
+            Self::RightToLeft => Self::reorder_rtl(text),
+        }
+    }
+
+    fn reorder_rtl(text: &str) -> String {
+        // Reorder a right-to-left paragraph from its logical order (ie. the order it is written \
+        //   in) to its visual order (ie. the order glyphs are drawn in, from left to right).
+        // Notice: this is a simplified implementation of the Unicode bidirectional algorithm, \
+        //   without explicit embeddings, which is enough to render single-line UI messages.
+        let characters: Vec<char> = text.chars().collect();
+        let mut classes: Vec<LocaleDirectionClass> = characters
+            .iter()
+            .map(|character| Self::classify(*character))
+            .collect();
+
+        // Resolve separators within numbers (eg. '12.5') and percent signs after numbers
+        for index in 0..characters.len() {
+            if classes[index] == LocaleDirectionClass::Neutral && index > 0 {
+                let is_after_number = classes[index - 1] == LocaleDirectionClass::Number;
+                let is_before_number =
+                    classes.get(index + 1) == Some(&LocaleDirectionClass::Number);
+
+                match characters[index] {
+                    '.' | ',' | ':' if is_after_number && is_before_number => {
+                        classes[index] = LocaleDirectionClass::Number
+                    }
+                    '%' if is_after_number => classes[index] = LocaleDirectionClass::Number,
+                    _ => {}
+                }
+            }
+        }
+
+        // Resolve neutral characters from their surrounding strong characters (numbers count \
+        //   as right-to-left there), falling back to the paragraph direction (right-to-left)
+        let strong_at = |index: Option<usize>| match index.map(|index| classes[index]) {
+            Some(LocaleDirectionClass::LeftToRight) => LocaleDirectionClass::LeftToRight,
+            _ => LocaleDirectionClass::RightToLeft,
+        };
+
+        let levels: Vec<u8> = (0..characters.len())
+            .map(|index| match classes[index] {
+                LocaleDirectionClass::RightToLeft => 1,
+                LocaleDirectionClass::LeftToRight | LocaleDirectionClass::Number => 2,
+                LocaleDirectionClass::Neutral => {
+                    let before = strong_at(
+                        (0..index)
+                            .rev()
+                            .find(|at| classes[*at] != LocaleDirectionClass::Neutral),
+                    );
+                    let after = strong_at(
+                        (index + 1..characters.len())
+                            .find(|at| classes[*at] != LocaleDirectionClass::Neutral),
+                    );
+
+                    if before == LocaleDirectionClass::LeftToRight && before == after {
+                        2
+                    } else {
+                        1
+                    }
+                }
+            })
+            .collect();
+
+        // Reverse left-to-right runs, then the whole paragraph, so that left-to-right runs end \
+        //   up in their original order (mirroring right-to-left characters such as brackets)
+        let mut visual: Vec<(char, u8)> = characters.into_iter().zip(levels).collect();
+
+        let mut index = 0;
+
+        while index < visual.len() {
+            if visual[index].1 == 2 {
+                let end = (index..visual.len())
+                    .find(|at| visual[*at].1 != 2)
+                    .unwrap_or(visual.len());
+
+                visual[index..end].reverse();
+
+                index = end;
+            } else {
+                index += 1;
+            }
+        }
+
+        visual
+            .into_iter()
+            .rev()
+            .map(|(character, level)| {
+                if level == 1 {
+                    Self::mirror(character)
+                } else {
+                    character
+                }
+            })
+            .collect()
+    }
+
+    fn classify(character: char) -> LocaleDirectionClass {
+        match character {
+            '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}' => {
+                LocaleDirectionClass::RightToLeft
+            }
+            '0'..='9' => LocaleDirectionClass::Number,
+            _ if character.is_alphabetic() => LocaleDirectionClass::LeftToRight,
+            _ => LocaleDirectionClass::Neutral,
+        }
+    }
+
+    fn mirror(character: char) -> char {
+        match character {
+            '(' => ')',
+            ')' => '(',
+            '[' => ']',
+            ']' => '[',
+            '{' => '}',
+            '}' => '{',
+            '<' => '>',
+            '>' => '<',
+            _ => character,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_left_to_right_text() {
+        assert_eq!(
+            LocaleDirection::LeftToRight.to_visual("Tidal volume (max)"),
+            "Tidal volume (max)"
+        );
+        assert_eq!(
+            LocaleDirection::RightToLeft.to_visual("12.5 cmH2O"),
+            "cmH2O 12.5"
+        );
+    }
+
+    #[test]
+    fn reverses_right_to_left_text() {
+        assert_eq!(LocaleDirection::RightToLeft.to_visual("אבג"), "גבא");
+        assert_eq!(
+            LocaleDirection::RightToLeft.to_visual("נפח (מקס)"),
+            "(סקמ) חפנ"
+        );
+    }
+
+    #[test]
+    fn keeps_embedded_runs_in_order() {
+        assert_eq!(
+            LocaleDirection::RightToLeft.to_visual("נמדד: 45 mL/min (12%)"),
+            "(12%) mL/min 45 :דדמנ"
+        );
+    }
+}
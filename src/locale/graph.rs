@@ -0,0 +1,24 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use crate::chip::ChipDataEventKind;
+use crate::locale::modes::{class_to_locale, kind_to_locale};
+
+use crate::APP_I18N;
+
+pub fn data_event_kind_to_locale(event_kind: ChipDataEventKind) -> String {
+    match event_kind {
+        ChipDataEventKind::TriggeredBreath => APP_I18N.t("graph-event-triggered"),
+        ChipDataEventKind::SettingsAck => APP_I18N.t("graph-event-settings"),
+        ChipDataEventKind::AlarmTriggered(code) => {
+            APP_I18N.t_args("graph-event-alarm", &[("code", code.code().into())])
+        }
+        ChipDataEventKind::ModeChange(mode) => format!(
+            "{} {}",
+            class_to_locale(mode.class()),
+            kind_to_locale(mode.kind())
+        ),
+    }
+}
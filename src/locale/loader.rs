@@ -9,6 +9,7 @@ use intl_memoizer::concurrent::IntlLangMemoizer;
 use unic_langid::LanguageIdentifier;
 
 use crate::EmbeddedLocales;
+use crate::APP_LOCALE_OVERRIDES;
 
 use super::accessor::LocaleAccessor;
 use super::locales::LocaleCode;
@@ -17,7 +18,7 @@ const LOCALE_EXTENSION: &str = ".ftl";
 
 pub struct LocaleLoader {
     locale_id: LanguageIdentifier,
-    resource: FluentResource,
+    resources: Vec<FluentResource>,
 }
 
 impl LocaleLoader {
@@ -32,30 +33,64 @@ impl LocaleLoader {
         }
 
         let locale_id: LanguageIdentifier = locale.parse().expect("locale code parsing failed");
-        let locale_buffer = EmbeddedLocales::get(&format!("{}{}", locale, LOCALE_EXTENSION))
-            .expect("locale not found");
-        let locale_string =
-            String::from_utf8(locale_buffer.into_owned()).expect("locale file is not a string");
+        let mut resources = Vec::new();
 
-        info!("loaded locale: [{}]", locale);
+        // Load embedded locale (if any, as locales registered from overrides are not embedded)
+        if let Some(locale_buffer) =
+            EmbeddedLocales::get(&format!("{}{}", locale, LOCALE_EXTENSION))
+        {
+            let locale_string =
+                String::from_utf8(locale_buffer.into_owned()).expect("locale file is not a string");
+
+            resources
+                .push(FluentResource::try_new(locale_string).expect("failed to parse locale file"));
+        }
+
+        // Load locale overrides, that get merged over the embedded locale
+        // Notice: overrides have already been validated, thus cannot fail parsing there.
+        for source in APP_LOCALE_OVERRIDES.sources(locale) {
+            resources.push(
+                FluentResource::try_new(source.to_owned())
+                    .expect("failed to parse locale override"),
+            );
+        }
+
+        if resources.is_empty() {
+            panic!("locale not found: {}", locale);
+        }
+
+        info!(
+            "loaded locale: [{}] (with {} override(s))",
+            locale,
+            APP_LOCALE_OVERRIDES.sources(locale).len()
+        );
 
         LocaleLoader {
             locale_id,
-            resource: FluentResource::try_new(locale_string).expect("failed to parse locale file"),
+            resources,
         }
     }
 
     pub fn into_bundle(self) -> FluentBundle<FluentResource, IntlLangMemoizer> {
         let mut bundle = FluentBundle::new_concurrent(vec![self.locale_id]);
 
-        bundle
-            .add_resource(self.resource)
-            .expect("failed to add locale to its bundle");
+        // Notice: Unicode isolation marks are not inserted around arguments, as they would \
+        //   render as unknown glyphs (right-to-left messages get reordered once formatted).
+        bundle.set_use_isolating(false);
+
+        for resource in self.resources {
+            // Notice: messages defined in later resources (ie. overrides) replace messages from \
+            //   earlier resources, rather than being rejected as duplicates.
+            bundle.add_resource_overriding(resource);
+        }
 
         bundle
     }
 
     pub fn into_accessor(self) -> LocaleAccessor {
-        LocaleAccessor::new(self.into_bundle())
+        // The default locale is used as a fallback for messages missing from other locales
+        let fallback = LocaleLoader::new(LocaleCode::default().to_code()).into_bundle();
+
+        LocaleAccessor::new(self.into_bundle(), fallback)
     }
 }
@@ -3,6 +3,9 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use super::direction::LocaleDirection;
+use crate::APP_LOCALE_OVERRIDES;
+
 gen_locale_impls!(
     default: En,
 
@@ -16,6 +19,34 @@ gen_locale_impls!(
         Pt -> ["pt", "Português", true],
         Ru -> ["ru", "Русский", true],
         Uk -> ["uk", "Українська", true],
-        Zh -> ["zh", "汉语", cfg!(feature = "fonts-cjk")]
+        Zh -> ["zh", "汉语", cfg!(feature = "fonts-cjk")],
+        He -> ["he", "עברית", cfg!(feature = "fonts-rtl")]
     }
 );
+
+impl LocaleCode {
+    pub fn is_cjk(&self) -> bool {
+        matches!(self, Self::Zh)
+    }
+
+    pub fn direction(&self) -> LocaleDirection {
+        match self {
+            Self::He => LocaleDirection::RightToLeft,
+            _ => LocaleDirection::LeftToRight,
+        }
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Self::En | Self::Zh | Self::He | Self::Other(_) => '.',
+            Self::Fr
+            | Self::De
+            | Self::Es
+            | Self::It
+            | Self::Lv
+            | Self::Pt
+            | Self::Ru
+            | Self::Uk => ',',
+        }
+    }
+}
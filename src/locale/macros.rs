@@ -10,9 +10,20 @@ macro_rules! gen_locale_impls {
             $(
                 $identifier,
             )+
+            // Notice: this holds locales that are not built-in, and that were registered at \
+            //   runtime from locale overrides.
+            Other(String),
         }
 
         impl LocaleCode {
+            pub fn list_all() -> Vec<Self> {
+                vec![
+                    $(
+                        Self::$identifier,
+                    )+
+                ]
+            }
+
             pub fn list_available() -> Vec<Self> {
                 let mut locales = vec![];
 
@@ -22,6 +33,10 @@ macro_rules! gen_locale_impls {
                     }
                 )+
 
+                for code in APP_LOCALE_OVERRIDES.codes() {
+                    locales.push(Self::Other(code.to_string()));
+                }
+
                 locales
             }
 
@@ -30,6 +45,9 @@ macro_rules! gen_locale_impls {
                     $(
                         $code => Some(Self::$identifier),
                     )+
+                    _ if APP_LOCALE_OVERRIDES.codes().contains(&code) => {
+                        Some(Self::Other(code.to_string()))
+                    }
                     _ => None,
                 }
             }
@@ -39,6 +57,7 @@ macro_rules! gen_locale_impls {
                     $(
                         Self::$identifier => $code,
                     )+
+                    Self::Other(code) => code.as_str(),
                 }
             }
 
@@ -47,6 +66,7 @@ macro_rules! gen_locale_impls {
                     $(
                         Self::$identifier => $name,
                     )+
+                    Self::Other(code) => code.as_str(),
                 }
             }
         }
@@ -9,9 +9,14 @@ mod macros;
 pub mod accessor;
 pub mod advanced;
 pub mod alarms;
+pub mod checker;
+pub mod direction;
 pub mod end_of_line;
 pub mod error;
+pub mod graph;
 pub mod loader;
 pub mod locales;
 pub mod modes;
+pub mod overrides;
 pub mod preset;
+pub mod recorder;
@@ -0,0 +1,155 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::environment::*;
+use crate::EmbeddedLocales;
+
+use super::checker::{LocaleCheckReport, LocaleCheckResult, LocaleChecker};
+use super::locales::LocaleCode;
+
+const LOCALE_EXTENSION: &str = "ftl";
+
+pub struct LocaleOverrides {
+    sources: BTreeMap<String, Vec<String>>,
+}
+
+impl LocaleOverrides {
+    pub fn load() -> Self {
+        let mut overrides = LocaleOverrides {
+            sources: BTreeMap::new(),
+        };
+
+        // Load overrides from the system directory, then from the user directory (user overrides \
+        //   are merged last, thus they take precedence over system ones)
+        let mut directories = vec![PathBuf::from(CONFIG_SYSTEM_LOCALES_PATH)];
+
+        directories.extend(Self::user_directory_path());
+
+        for directory in directories {
+            for (code, source) in Self::read_directory(&directory) {
+                let result = Self::check(&code, &source);
+
+                // Notice: the logger may not be initialized yet (locales are listed while \
+                //   settings get validated), thus errors are printed directly.
+                if result.is_ok() {
+                    overrides.sources.entry(code).or_default().push(source);
+                } else {
+                    eprint!(
+                        "Ignoring invalid locale override in {}:\n{}",
+                        directory.display(),
+                        LocaleCheckReport {
+                            locales: vec![result]
+                        }
+                    );
+                }
+            }
+        }
+
+        overrides
+    }
+
+    pub fn check_directory(directory: &Path) -> LocaleCheckReport {
+        LocaleCheckReport {
+            locales: Self::read_directory(directory)
+                .iter()
+                .map(|(code, source)| Self::check(code, source))
+                .collect(),
+        }
+    }
+
+    pub fn sources(&self, code: &str) -> &[String] {
+        self.sources
+            .get(code)
+            .map(|sources| &sources[..])
+            .unwrap_or(&[])
+    }
+
+    pub fn codes(&self) -> Vec<&str> {
+        // List codes that are not built-in (ie. new locales only provided as overrides)
+        self.sources
+            .keys()
+            .map(|code| code.as_str())
+            .filter(|code| {
+                !LocaleCode::list_all()
+                    .iter()
+                    .any(|locale| &locale.to_code() == code)
+            })
+            .collect()
+    }
+
+    fn check(code: &str, source: &str) -> LocaleCheckResult {
+        // Overrides get checked against the embedded default locale, which is the reference one
+        let reference = EmbeddedLocales::get(&format!(
+            "{}.{}",
+            LocaleCode::default().to_code(),
+            LOCALE_EXTENSION
+        ))
+        .map(|buffer| String::from_utf8_lossy(&buffer).into_owned())
+        .unwrap_or_default();
+
+        LocaleChecker::check_override(code, source, &reference)
+    }
+
+    fn read_directory(directory: &Path) -> Vec<(String, String)> {
+        let entries = match fs::read_dir(directory) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut files: Vec<(String, String)> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().and_then(|extension| extension.to_str()) == Some(LOCALE_EXTENSION)
+            })
+            .filter_map(|path| {
+                let code = path.file_stem()?.to_str()?.to_string();
+
+                // Locale codes are ISO 639 language codes (eg. 'fr')
+                if !(2..=3).contains(&code.len())
+                    || !code.chars().all(|character| character.is_ascii_lowercase())
+                {
+                    eprintln!(
+                        "Ignoring locale override with invalid code: {}",
+                        path.display()
+                    );
+
+                    return None;
+                }
+
+                match fs::read_to_string(&path) {
+                    Ok(source) => Some((code, source)),
+                    Err(err) => {
+                        eprintln!(
+                            "Ignoring unreadable locale override {}: {}",
+                            path.display(),
+                            err
+                        );
+
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        files.sort();
+
+        files
+    }
+
+    fn user_directory_path() -> Option<PathBuf> {
+        // The user overrides directory lives next to the user settings file
+        confy::get_configuration_file_path(RUNTIME_NAME)
+            .ok()
+            .and_then(|path| {
+                path.parent()
+                    .map(|parent| parent.join(CONFIG_LOCALES_DIRECTORY_NAME))
+            })
+    }
+}
@@ -0,0 +1,27 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use crate::serial::recorder::RecordEventKind;
+
+use crate::APP_I18N;
+
+pub fn event_kind_to_locale(event_kind: RecordEventKind) -> String {
+    let locale_key = match event_kind {
+        RecordEventKind::SettingsCommit => "settings",
+        RecordEventKind::SnoozeToggle => "snooze",
+        RecordEventKind::SnoozeExpire => "snooze-expired",
+        RecordEventKind::PresetSubmit => "preset",
+        RecordEventKind::EndOfLineConfirm => "eol",
+        RecordEventKind::WeaningStart => "weaning-start",
+        RecordEventKind::WeaningEnd => "weaning-end",
+        RecordEventKind::GraphFreeze => "freeze",
+        RecordEventKind::SessionStart => "session-start",
+        RecordEventKind::SessionEnd => "session-end",
+        RecordEventKind::AlarmAcknowledge => "alarm-ack",
+        RecordEventKind::ModalOpen => "modal",
+    };
+
+    APP_I18N.t(&format!("record-event-{}", locale_key))
+}
@@ -30,6 +30,7 @@ mod locale;
 #[cfg(feature = "lora")]
 mod lora;
 mod serial;
+mod sound;
 mod utilities;
 mod widget;
 
@@ -50,6 +51,8 @@ use config::settings::ConfigSettings;
 use display::window::DisplayWindowBuilder;
 use locale::accessor::LocaleAccessor;
 use locale::loader::LocaleLoader;
+use locale::overrides::LocaleOverrides;
+use utilities::backlight::apply_brightness;
 
 #[derive(RustEmbed)]
 #[folder = "res/images/"]
@@ -64,6 +67,11 @@ pub struct EmbeddedFontsDefault;
 #[folder = "res/fonts/cjk/"]
 pub struct EmbeddedFontsCjk;
 
+#[cfg(feature = "fonts-rtl")]
+#[derive(RustEmbed)]
+#[folder = "res/fonts/rtl/"]
+pub struct EmbeddedFontsRtl;
+
 #[derive(RustEmbed)]
 #[folder = "res/locales/"]
 pub struct EmbeddedLocales;
@@ -73,6 +81,7 @@ lazy_static! {
     static ref APP_CONTEXT: ConfigContext = make_app_context();
     static ref APP_SETTINGS: RwLock<ConfigSettings> = RwLock::new(make_app_settings());
     static ref APP_I18N: LocaleAccessor = make_app_i18n();
+    static ref APP_LOCALE_OVERRIDES: LocaleOverrides = make_app_locale_overrides();
 }
 
 fn make_app_args() -> ConfigArguments {
@@ -91,12 +100,17 @@ fn make_app_i18n() -> LocaleAccessor {
     LocaleLoader::new(&APP_ARGS.translation).into_accessor()
 }
 
+fn make_app_locale_overrides() -> LocaleOverrides {
+    LocaleOverrides::load()
+}
+
 fn ensure_states() {
     // Ensure all statics are valid (a `deref` is enough to lazily initialize them)
-    let (_, _, _, _) = (
+    let (_, _, _, _, _) = (
         APP_CONTEXT.deref(),
         APP_SETTINGS.deref(),
         APP_ARGS.deref(),
+        APP_LOCALE_OVERRIDES.deref(),
         APP_I18N.deref(),
     );
 }
@@ -110,6 +124,9 @@ fn main() {
     // Ensure all states are bound
     ensure_states();
 
+    // Restore display brightness from settings
+    apply_brightness(APP_SETTINGS.read().unwrap().brightness);
+
     // Launch LORA init and get Sender for chip
     #[cfg(feature = "lora")]
     let lora_sender = if APP_ARGS.lora {
@@ -122,7 +139,7 @@ fn main() {
     let lora_sender = None;
 
     // Create our "Chip" that will store all the data
-    let chip = Chip::new(lora_sender);
+    let chip = Chip::new(lora_sender, APP_ARGS.alarms_latch);
 
     // Spawn window manager
     DisplayWindowBuilder::new().spawn(chip);
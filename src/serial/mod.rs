@@ -4,3 +4,8 @@
 // License: Public Domain License
 
 pub mod poller;
+pub mod recorder;
+pub mod source;
+
+#[cfg(test)]
+mod test_frames;
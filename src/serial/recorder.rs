@@ -0,0 +1,460 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::os::unix::io::OwnedFd;
+use std::os::unix::net::UnixStream;
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex};
+
+use makair_telemetry::control::ControlMessage;
+
+// Notice: this prefix uses a character that is not part of the base64 alphabet, which means \
+//   that UI event lines will never be mistaken for telemetry lines by the telemetry library \
+//   when replaying a record file (those lines will be ignored as they cannot be decoded).
+const RECORD_EVENT_LINE_PREFIX: &str = "#ui";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordEventKind {
+    SettingsCommit,
+    SnoozeToggle,
+    SnoozeExpire,
+    PresetSubmit,
+    EndOfLineConfirm,
+    WeaningStart,
+    WeaningEnd,
+    GraphFreeze,
+    SessionStart,
+    SessionEnd,
+    AlarmAcknowledge,
+    ModalOpen,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordEvent {
+    pub systick: u64,
+    pub kind: RecordEventKind,
+    pub details: String,
+}
+
+#[derive(Debug)]
+pub enum RecordCommand {
+    Event(RecordEvent),
+    Rotate(String),
+    Stop,
+}
+
+pub struct SerialRecorderBuilder;
+
+pub struct SerialRecorder {
+    path: String,
+    file: Arc<Mutex<Option<BufWriter<File>>>>,
+}
+
+impl RecordEventKind {
+    pub fn to_code(&self) -> &'static str {
+        match self {
+            Self::SettingsCommit => "settings",
+            Self::SnoozeToggle => "snooze",
+            Self::SnoozeExpire => "snooze-expired",
+            Self::PresetSubmit => "preset",
+            Self::EndOfLineConfirm => "eol",
+            Self::WeaningStart => "weaning-start",
+            Self::WeaningEnd => "weaning-end",
+            Self::GraphFreeze => "freeze",
+            Self::SessionStart => "session-start",
+            Self::SessionEnd => "session-end",
+            Self::AlarmAcknowledge => "alarm-ack",
+            Self::ModalOpen => "modal",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "settings" => Some(Self::SettingsCommit),
+            "snooze" => Some(Self::SnoozeToggle),
+            "snooze-expired" => Some(Self::SnoozeExpire),
+            "preset" => Some(Self::PresetSubmit),
+            "eol" => Some(Self::EndOfLineConfirm),
+            "weaning-start" => Some(Self::WeaningStart),
+            "weaning-end" => Some(Self::WeaningEnd),
+            "freeze" => Some(Self::GraphFreeze),
+            "session-start" => Some(Self::SessionStart),
+            "session-end" => Some(Self::SessionEnd),
+            "alarm-ack" => Some(Self::AlarmAcknowledge),
+            "modal" => Some(Self::ModalOpen),
+            _ => None,
+        }
+    }
+}
+
+impl RecordEvent {
+    pub fn new(systick: u64, kind: RecordEventKind, messages: &[ControlMessage]) -> Self {
+        RecordEvent {
+            systick,
+            kind,
+            details: messages
+                .iter()
+                .map(|message| format!("{:?}={}", message.setting, message.value))
+                .collect::<Vec<String>>()
+                .join(","),
+        }
+    }
+
+    pub fn with_details(systick: u64, kind: RecordEventKind, details: String) -> Self {
+        RecordEvent {
+            systick,
+            kind,
+            details,
+        }
+    }
+
+    pub fn from_line(line: &str) -> Option<Self> {
+        // Parse event line, which is formatted as such: '#ui {systick} {kind} {details}'
+        let mut parts = line
+            .trim_end()
+            .strip_prefix(RECORD_EVENT_LINE_PREFIX)?
+            .trim_start()
+            .splitn(3, ' ');
+
+        let systick = parts.next()?.parse::<u64>().ok()?;
+        let kind = RecordEventKind::from_code(parts.next()?)?;
+
+        Some(RecordEvent {
+            systick,
+            kind,
+            details: parts.next().unwrap_or("").to_string(),
+        })
+    }
+
+    pub fn to_line(&self) -> String {
+        format!(
+            "{} {} {} {}",
+            RECORD_EVENT_LINE_PREFIX,
+            self.systick,
+            self.kind.to_code(),
+            self.details
+        )
+    }
+
+    pub fn read_all_from_file(path: &str) -> Vec<Self> {
+        let mut events = Vec::new();
+
+        match File::open(path) {
+            Ok(file) => {
+                // Notice: lines are read as bytes, as telemetry lines are not guaranteed to be \
+                //   valid UTF-8 strings.
+                for line in BufReader::new(file).split(b'\n').flatten() {
+                    if line.starts_with(RECORD_EVENT_LINE_PREFIX.as_bytes()) {
+                        if let Some(event) = RecordEvent::from_line(&String::from_utf8_lossy(&line))
+                        {
+                            events.push(event);
+                        }
+                    }
+                }
+            }
+            Err(err) => error!("could not read events from record file: {:?}", err),
+        }
+
+        debug!("read {} recorded events from record file", events.len());
+
+        events
+    }
+}
+
+#[allow(clippy::new_ret_no_self)]
+impl SerialRecorderBuilder {
+    pub fn new(path: &str) -> SerialRecorder {
+        // Notice: the first record file is created synchronously, so that telemetry gets recorded \
+        //   from the very first line. Next files are created upon rotate commands, which are sent \
+        //   when a new patient session starts (each session has its own record file).
+        let file =
+            File::create(path).unwrap_or_else(|_| panic!("could not create file '{}'", path));
+
+        SerialRecorder {
+            path: path.to_owned(),
+            file: Arc::new(Mutex::new(Some(BufWriter::new(file)))),
+        }
+    }
+}
+
+impl SerialRecorder {
+    pub fn bind_telemetry(&self) -> BufWriter<File> {
+        // The telemetry library writes all telemetry lines to a file buffer that it owns, which \
+        //   makes it impossible to append UI events to the same file from the outside without \
+        //   interleaving bytes in the middle of telemetry lines. Thus, a socket-backed file is \
+        //   handed out to the telemetry library, and each line written there gets forwarded to \
+        //   the actual record file, which UI events can be safely written to in-between lines.
+        let (telemetry_socket, forward_socket) =
+            UnixStream::pair().expect("could not create record socket pair");
+
+        let file = self.file.clone();
+
+        std::thread::spawn(move || {
+            let mut reader = BufReader::new(forward_socket);
+            let mut line = Vec::new();
+
+            loop {
+                line.clear();
+
+                match reader.read_until(b'\n', &mut line) {
+                    // The telemetry library closed its file buffer
+                    Ok(0) => break,
+                    Ok(_) => {
+                        if let Some(file) = file.lock().unwrap().as_mut() {
+                            if let Err(err) = file.write_all(&line) {
+                                error!("could not write telemetry to record file: {:?}", err);
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        error!("could not read telemetry to record: {:?}", err);
+
+                        break;
+                    }
+                }
+            }
+        });
+
+        // Notice: the ownership of the socket file descriptor is moved to the file.
+        BufWriter::new(File::from(OwnedFd::from(telemetry_socket)))
+    }
+
+    pub fn bind_events(&self, rx: Receiver<RecordCommand>) {
+        let file = self.file.clone();
+
+        let mut current_path = Some(self.path.clone());
+
+        std::thread::spawn(move || {
+            for command in rx.iter() {
+                match command {
+                    RecordCommand::Event(event) => {
+                        debug!("recording event: {:?}", event);
+
+                        // Notice: UI events are written with the last known systick, as the \
+                        //   telemetry lines might still be pending in the telemetry library \
+                        //   file buffer at this point, so lines order is not guaranteed to be \
+                        //   chronological.
+                        if let Some(file) = file.lock().unwrap().as_mut() {
+                            if let Err(err) =
+                                writeln!(file, "{}", event.to_line()).and_then(|_| file.flush())
+                            {
+                                error!("could not write event to record file: {:?}", err);
+                            }
+                        }
+                    }
+                    RecordCommand::Rotate(path) => {
+                        // The record file for this path is already open (this happens for the \
+                        //   first session, whose file gets created along with the recorder)
+                        if current_path.as_deref() == Some(path.as_str()) {
+                            continue;
+                        }
+
+                        info!("recording to new file: {}", path);
+
+                        // Swap the record file (the previous file gets flushed and closed)
+                        // Notice: rotating happens in the same thread as writing events, so \
+                        //   that events sent before a rotation end up in the previous file.
+                        let mut file = file.lock().unwrap();
+
+                        Self::close_file(&mut file);
+
+                        *file = match File::create(&path) {
+                            Ok(new_file) => Some(BufWriter::new(new_file)),
+                            Err(err) => {
+                                error!("could not create record file '{}': {:?}", path, err);
+
+                                None
+                            }
+                        };
+
+                        current_path = file.as_ref().map(|_| path);
+                    }
+                    RecordCommand::Stop => {
+                        info!("stopped recording to file");
+
+                        // Close the record file, meaning that telemetry will not be recorded until \
+                        //   the next rotation (ie. until a new session starts)
+                        Self::close_file(&mut file.lock().unwrap());
+
+                        current_path = None;
+                    }
+                }
+            }
+        });
+    }
+
+    fn close_file(file: &mut Option<BufWriter<File>>) {
+        if let Some(mut previous_file) = file.take() {
+            if let Err(err) = previous_file.flush() {
+                error!("could not flush previous record file: {:?}", err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::mpsc;
+
+    use makair_telemetry::structures::TelemetryMessage;
+
+    use crate::serial::test_frames::control_ack_frame;
+
+    const BASE64_ALPHABET: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    struct TestRecordFile(std::path::PathBuf);
+
+    impl TestRecordFile {
+        fn new(test_name: &str) -> Self {
+            // Notice: each test gets its own record file, as tests may run concurrently (possibly \
+            //   from multiple processes).
+            TestRecordFile(std::env::temp_dir().join(format!(
+                "makair-control-{}-{}.record",
+                test_name,
+                std::process::id()
+            )))
+        }
+    }
+
+    impl Drop for TestRecordFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn encode_base64(bytes: &[u8]) -> String {
+        // Encode as the telemetry library does for each telemetry line in record files
+        let mut encoded = String::new();
+
+        for chunk in bytes.chunks(3) {
+            let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+                group | ((*byte as u32) << (16 - index * 8))
+            });
+
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    encoded.push(
+                        BASE64_ALPHABET[((group >> (18 - index * 6)) & 0x3F) as usize] as char,
+                    );
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+
+        encoded
+    }
+
+    #[test]
+    fn it_round_trips_event_lines() {
+        let events = vec![
+            RecordEvent::with_details(
+                42,
+                RecordEventKind::SettingsCommit,
+                "PeakPressure=250,PEEP=50".to_string(),
+            ),
+            RecordEvent::with_details(0, RecordEventKind::ModalOpen, "advanced".to_string()),
+            RecordEvent::with_details(7, RecordEventKind::GraphFreeze, "".to_string()),
+            RecordEvent::with_details(
+                u64::MAX,
+                RecordEventKind::SessionEnd,
+                "id=20210101-120000,patient=,duration=60".to_string(),
+            ),
+        ];
+
+        for event in events {
+            let parsed = RecordEvent::from_line(&event.to_line()).expect("should parse line");
+
+            assert_eq!(parsed.systick, event.systick);
+            assert_eq!(parsed.kind, event.kind);
+            assert_eq!(parsed.details, event.details);
+        }
+    }
+
+    #[test]
+    fn it_round_trips_event_kinds() {
+        let kinds = [
+            RecordEventKind::SettingsCommit,
+            RecordEventKind::SnoozeToggle,
+            RecordEventKind::SnoozeExpire,
+            RecordEventKind::PresetSubmit,
+            RecordEventKind::EndOfLineConfirm,
+            RecordEventKind::WeaningStart,
+            RecordEventKind::WeaningEnd,
+            RecordEventKind::GraphFreeze,
+            RecordEventKind::SessionStart,
+            RecordEventKind::SessionEnd,
+            RecordEventKind::AlarmAcknowledge,
+            RecordEventKind::ModalOpen,
+        ];
+
+        for kind in kinds.iter() {
+            assert_eq!(RecordEventKind::from_code(kind.to_code()), Some(*kind));
+        }
+    }
+
+    #[test]
+    fn it_ignores_invalid_event_lines() {
+        assert!(RecordEvent::from_line("A/telemetry+line==").is_none());
+        assert!(RecordEvent::from_line("#ui notatick settings").is_none());
+        assert!(RecordEvent::from_line("#ui 42 unknown-kind details").is_none());
+    }
+
+    #[test]
+    fn it_replays_record_with_event_lines() {
+        let record_file = TestRecordFile::new("it_replays_record_with_event_lines");
+        let path = &record_file.0;
+
+        // Interleave UI events with telemetry lines, as written by the recorder
+        let lines = vec![
+            RecordEvent::with_details(0, RecordEventKind::SessionStart, "id=test".to_string())
+                .to_line(),
+            encode_base64(&control_ack_frame(1_000, 250)),
+            RecordEvent::with_details(1_000, RecordEventKind::ModalOpen, "mode".to_string())
+                .to_line(),
+            encode_base64(&control_ack_frame(2_000, 300)),
+            RecordEvent::with_details(2_000, RecordEventKind::SessionEnd, "id=test".to_string())
+                .to_line(),
+        ];
+
+        std::fs::write(path, lines.join("\n") + "\n").expect("should write record file");
+
+        // Telemetry lines must still be replayed (event lines must be skipped)
+        let (tx, rx) = mpsc::channel();
+
+        makair_telemetry::gather_telemetry_from_file(
+            File::open(path).expect("should open record file"),
+            tx,
+            false,
+        );
+
+        let values: Vec<u16> = rx
+            .try_iter()
+            .filter_map(|message| match message {
+                Ok(TelemetryMessage::ControlAck(ack)) => Some(ack.value),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(values, vec![250, 300]);
+
+        // Event lines must be read back from the same file
+        let events = RecordEvent::read_all_from_file(path.to_str().unwrap());
+
+        assert_eq!(
+            events.iter().map(|event| event.kind).collect::<Vec<_>>(),
+            vec![
+                RecordEventKind::SessionStart,
+                RecordEventKind::ModalOpen,
+                RecordEventKind::SessionEnd
+            ]
+        );
+    }
+}
@@ -0,0 +1,211 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::net::TcpStream;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use makair_telemetry::parsers::parse_telemetry_message;
+use makair_telemetry::TelemetryChannelType;
+
+const SOURCE_READ_BUFFER_SIZE: usize = 1024;
+const SOURCE_TCP_RECONNECT_AFTER: Duration = Duration::from_secs(1);
+
+pub const SOURCE_PIPE_STDIN: &str = "-";
+
+#[derive(Debug, PartialEq)]
+pub enum StreamEnd {
+    Closed,
+    ReceiverGone,
+}
+
+pub fn gather_telemetry_from_tcp(address: &str, tx: Sender<TelemetryChannelType>) {
+    // Connect to the TCP source, and re-connect whenever the connection gets lost (the remote \
+    //   end may be restarted at any time, eg. if the serial port forwarder gets restarted)
+    loop {
+        match TcpStream::connect(address) {
+            Ok(stream) => {
+                info!("connected to telemetry tcp source: {}", address);
+
+                match gather_telemetry_from_stream(stream, &tx) {
+                    Ok(StreamEnd::Closed) => {
+                        warn!("telemetry tcp source closed connection: {}", address)
+                    }
+                    Ok(StreamEnd::ReceiverGone) => break,
+                    Err(err) => error!("telemetry tcp source failed: {}: {:?}", address, err),
+                }
+            }
+            Err(err) => warn!(
+                "could not connect to telemetry tcp source: {}: {:?}",
+                address, err
+            ),
+        }
+
+        std::thread::sleep(SOURCE_TCP_RECONNECT_AFTER);
+    }
+}
+
+pub fn gather_telemetry_from_pipe(path: &str, tx: Sender<TelemetryChannelType>) {
+    // Read from the standard input, or from a named pipe
+    // Notice: once the pipe gets closed, the sender channel is dropped, which will be handled by \
+    //   the serial poller as a disconnected device.
+    let result = if path == SOURCE_PIPE_STDIN {
+        gather_telemetry_from_stream(io::stdin(), &tx)
+    } else {
+        File::open(path).and_then(|file| gather_telemetry_from_stream(file, &tx))
+    };
+
+    match result {
+        Ok(StreamEnd::Closed) => info!("telemetry pipe source closed: {}", path),
+        Ok(StreamEnd::ReceiverGone) => debug!("telemetry pipe source not needed anymore: {}", path),
+        Err(err) => error!("telemetry pipe source failed: {}: {:?}", path, err),
+    }
+}
+
+pub fn gather_telemetry_from_stream<R: Read>(
+    mut reader: R,
+    tx: &Sender<TelemetryChannelType>,
+) -> io::Result<StreamEnd> {
+    let (mut buffer, mut chunk) = (Vec::new(), [0; SOURCE_READ_BUFFER_SIZE]);
+
+    loop {
+        // Read next chunk of bytes (this blocks until bytes are available)
+        let chunk_size = match reader.read(&mut chunk) {
+            Ok(0) => return Ok(StreamEnd::Closed),
+            Ok(chunk_size) => chunk_size,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+
+        buffer.extend_from_slice(&chunk[..chunk_size]);
+
+        // Parse as many telemetry messages as possible from buffered bytes
+        // Notice: the framing is the same as the one used over the serial port, so any byte \
+        //   that cannot be parsed as the start of a message gets skipped, until the parser \
+        //   re-synchronizes on the next message. Consumed bytes are only dropped from the \
+        //   buffer once all messages have been parsed.
+        let mut offset = 0;
+
+        while offset < buffer.len() {
+            match parse_telemetry_message(&buffer[offset..]) {
+                Ok((rest, message)) => {
+                    offset = buffer.len() - rest.len();
+
+                    if tx.send(Ok(message)).is_err() {
+                        return Ok(StreamEnd::ReceiverGone);
+                    }
+                }
+                Err(err) if err.is_incomplete() => break,
+                Err(_) => offset += 1,
+            }
+        }
+
+        buffer.drain(..offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{Cursor, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, TryRecvError};
+
+    use makair_telemetry::structures::TelemetryMessage;
+
+    use crate::serial::poller::{PollEvent, SerialPollerBuilder};
+    use crate::serial::test_frames::control_ack_frame;
+
+    const GARBAGE_BYTES: &[u8] = b"not a telemetry message";
+
+    #[test]
+    fn it_skips_garbage_bytes_from_stream() {
+        let (tx, rx) = mpsc::channel();
+
+        assert_eq!(
+            gather_telemetry_from_stream(Cursor::new(GARBAGE_BYTES), &tx).ok(),
+            Some(StreamEnd::Closed)
+        );
+        assert_eq!(rx.try_recv().err(), Some(TryRecvError::Empty));
+    }
+
+    #[test]
+    fn it_stops_once_receiver_is_gone() {
+        let (tx, rx) = mpsc::channel();
+
+        drop(rx);
+
+        let mut bytes = control_ack_frame(1_000, 250);
+
+        bytes.extend_from_slice(&control_ack_frame(2_000, 300));
+
+        assert_eq!(
+            gather_telemetry_from_stream(Cursor::new(bytes), &tx).ok(),
+            Some(StreamEnd::ReceiverGone)
+        );
+    }
+
+    #[test]
+    fn it_reads_stream_from_local_socket() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("should bind local socket");
+        let address = listener.local_addr().expect("should get local address");
+
+        let writer = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("should accept connection");
+
+            // Write garbage first, then a valid message split in two chunks (the reader must \
+            //   re-synchronize on the message, and wait for its remaining bytes)
+            let frame = control_ack_frame(123_456, 250);
+            let (frame_start, frame_end) = frame.split_at(frame.len() / 2);
+
+            stream.write_all(GARBAGE_BYTES).expect("should write bytes");
+            stream.write_all(frame_start).expect("should write bytes");
+            stream.flush().expect("should flush bytes");
+
+            std::thread::sleep(Duration::from_millis(50));
+
+            stream.write_all(frame_end).expect("should write bytes");
+        });
+
+        let (tx, rx) = mpsc::channel();
+        let stream = TcpStream::connect(address).expect("should connect to local socket");
+
+        assert_eq!(
+            gather_telemetry_from_stream(stream, &tx).ok(),
+            Some(StreamEnd::Closed)
+        );
+
+        writer.join().expect("should join writer thread");
+
+        // The message must reach the poller as a valid message, and be the only one received
+        let mut poller = SerialPollerBuilder::new();
+
+        match poller.poll(&rx) {
+            Ok(PollEvent::Ready(TelemetryMessage::ControlAck(ack))) => {
+                assert_eq!(ack.systick, 123_456);
+                assert_eq!(ack.value, 250);
+            }
+            other => panic!("expected a control acknowledgement, got: {:?}", other),
+        }
+
+        assert!(matches!(poller.poll(&rx), Ok(PollEvent::Pending)));
+    }
+
+    #[test]
+    fn it_polls_disconnected_pipe_as_error() {
+        let (tx, rx) = mpsc::channel();
+
+        // Use a missing path as a pipe that closes immediately
+        gather_telemetry_from_pipe("/nonexistent/makair-control.pipe", tx);
+
+        let mut poller = SerialPollerBuilder::new();
+
+        assert!(!matches!(poller.poll(&rx), Ok(PollEvent::Pending)));
+        assert!(poller.poll(&rx).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+const FRAME_HEADER: &[u8] = &[0x03, 0x0C];
+const FRAME_FOOTER: &[u8] = &[0x30, 0xC0];
+
+const ACK_SETTING: u8 = 1;
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in bytes {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+pub fn control_ack_frame(systick: u64, value: u16) -> Vec<u8> {
+    // Build a control acknowledgement message, framed as sent by the firmware (header, \
+    //   message, CRC of the message, then footer)
+    let version = b"test";
+    let mut message = Vec::new();
+
+    message.extend_from_slice(b"A:");
+    message.push(2);
+    message.push(version.len() as u8);
+    message.extend_from_slice(version);
+
+    for device_id in [1u32, 2, 3].iter() {
+        message.extend_from_slice(&device_id.to_be_bytes());
+    }
+
+    message.push(b'\t');
+    message.extend_from_slice(&systick.to_be_bytes());
+    message.push(b'\t');
+    message.push(ACK_SETTING);
+    message.push(b'\t');
+    message.extend_from_slice(&value.to_be_bytes());
+    message.push(b'\n');
+
+    let mut frame = FRAME_HEADER.to_vec();
+
+    frame.extend_from_slice(&message);
+    frame.extend_from_slice(&crc32(&message).to_be_bytes());
+    frame.extend_from_slice(FRAME_FOOTER);
+
+    frame
+}
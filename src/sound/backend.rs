@@ -0,0 +1,151 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+
+use crate::config::environment::*;
+
+const WAV_HEADER_SIZE: u32 = 44;
+const WAV_BITS_PER_SAMPLE: u16 = 16;
+const WAV_CHANNELS: u16 = 1;
+
+pub trait SoundBackend: Send {
+    fn write(&mut self, samples: &[i16]) -> io::Result<()>;
+}
+
+pub struct SoundBackendNull;
+
+pub struct SoundBackendWav {
+    writer: BufWriter<File>,
+    data_size: u32,
+}
+
+pub struct SoundBackendAplay {
+    // Notice: the child process handle is retained so that the process does not get orphaned
+    _process: Child,
+    stdin: ChildStdin,
+}
+
+impl SoundBackend for SoundBackendNull {
+    fn write(&mut self, _samples: &[i16]) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SoundBackendWav {
+    pub fn new(path: &str) -> io::Result<Self> {
+        let mut backend = SoundBackendWav {
+            writer: BufWriter::new(File::create(path)?),
+            data_size: 0,
+        };
+
+        backend.write_header()?;
+
+        Ok(backend)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        let block_align = WAV_CHANNELS * WAV_BITS_PER_SAMPLE / 8;
+
+        self.writer.seek(SeekFrom::Start(0))?;
+
+        self.writer.write_all(b"RIFF")?;
+        self.writer
+            .write_all(&(WAV_HEADER_SIZE - 8 + self.data_size).to_le_bytes())?;
+        self.writer.write_all(b"WAVEfmt ")?;
+        self.writer.write_all(&16_u32.to_le_bytes())?;
+        self.writer.write_all(&1_u16.to_le_bytes())?;
+        self.writer.write_all(&WAV_CHANNELS.to_le_bytes())?;
+        self.writer.write_all(&SOUND_SAMPLE_RATE.to_le_bytes())?;
+        self.writer
+            .write_all(&(SOUND_SAMPLE_RATE * block_align as u32).to_le_bytes())?;
+        self.writer.write_all(&block_align.to_le_bytes())?;
+        self.writer.write_all(&WAV_BITS_PER_SAMPLE.to_le_bytes())?;
+        self.writer.write_all(b"data")?;
+        self.writer.write_all(&self.data_size.to_le_bytes())?;
+
+        self.writer.seek(SeekFrom::End(0))?;
+
+        Ok(())
+    }
+}
+
+impl SoundBackend for SoundBackendWav {
+    fn write(&mut self, samples: &[i16]) -> io::Result<()> {
+        for sample in samples {
+            self.writer.write_all(&sample.to_le_bytes())?;
+        }
+
+        self.data_size += (samples.len() * 2) as u32;
+
+        // Update sizes in header, so that the file is always valid (even if never closed)
+        self.write_header()?;
+        self.writer.flush()
+    }
+}
+
+impl SoundBackendAplay {
+    pub fn new() -> io::Result<Self> {
+        // Play sound through ALSA, using the 'aplay' utility (which is available on all \
+        //   Raspberry Pi OS installations); raw samples are piped to its standard input
+        let mut process = Command::new("aplay")
+            .args(&["-q", "-t", "raw", "-f", "S16_LE", "-c", "1", "-r"])
+            .arg(SOUND_SAMPLE_RATE.to_string())
+            .stdin(Stdio::piped())
+            .spawn()?;
+
+        let stdin = process
+            .stdin
+            .take()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::BrokenPipe, "no aplay stdin"))?;
+
+        Ok(SoundBackendAplay {
+            _process: process,
+            stdin,
+        })
+    }
+}
+
+impl SoundBackend for SoundBackendAplay {
+    fn write(&mut self, samples: &[i16]) -> io::Result<()> {
+        let bytes: Vec<u8> = samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes().to_vec())
+            .collect();
+
+        self.stdin.write_all(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_writes_wav_file() {
+        let path = std::env::temp_dir().join("makair-control-sound-test.wav");
+        let path = path.to_str().expect("should get temporary path");
+
+        let mut backend = SoundBackendWav::new(path).expect("should create wav file");
+
+        backend
+            .write(&[0, 1, -1, i16::MAX])
+            .expect("should write samples");
+        backend.write(&[i16::MIN]).expect("should write samples");
+
+        let bytes = std::fs::read(path).expect("should read wav file");
+
+        std::fs::remove_file(path).ok();
+
+        assert_eq!(bytes.len(), WAV_HEADER_SIZE as usize + 10);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(&bytes[4..8], &(WAV_HEADER_SIZE - 8 + 10).to_le_bytes());
+        assert_eq!(&bytes[36..40], b"data");
+        assert_eq!(&bytes[40..44], &10_u32.to_le_bytes());
+        assert_eq!(&bytes[50..52], &i16::MAX.to_le_bytes());
+    }
+}
@@ -0,0 +1,171 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::f64::consts::PI;
+
+use makair_telemetry::structures::AlarmPriority;
+
+use crate::config::environment::*;
+
+use super::pattern::SoundPattern;
+
+pub struct SoundEngineBuilder;
+
+pub struct SoundEngine {
+    pattern: Option<&'static SoundPattern>,
+    position: u64,
+}
+
+#[allow(clippy::new_ret_no_self)]
+impl SoundEngineBuilder {
+    pub fn new() -> SoundEngine {
+        SoundEngine {
+            pattern: None,
+            position: 0,
+        }
+    }
+}
+
+impl SoundEngine {
+    pub fn set_priority(&mut self, priority: Option<AlarmPriority>) {
+        let pattern = priority.map(SoundPattern::from_priority);
+
+        // Restart the burst pattern from its beginning upon any change, so that a newly raised \
+        //   alarm (or an escalated alarm) can be heard immediately
+        if pattern != self.pattern {
+            self.pattern = pattern;
+            self.position = 0;
+        }
+    }
+
+    pub fn render(&mut self, buffer: &mut [i16]) {
+        for sample in buffer.iter_mut() {
+            *sample = self.next_sample();
+        }
+    }
+
+    fn next_sample(&mut self) -> i16 {
+        let pattern = if let Some(pattern) = self.pattern {
+            pattern
+        } else {
+            return 0;
+        };
+
+        let position = self.position;
+
+        self.position += 1;
+
+        let time = (position * 1000 / SOUND_SAMPLE_RATE as u64) as u32;
+
+        if let Some(pulse_time) = pattern.pulse_at(time) {
+            let amplitude = Self::envelope(pulse_time, pattern.pulse_duration)
+                * Self::tone(position as f64 / SOUND_SAMPLE_RATE as f64);
+
+            (amplitude * SOUND_TONE_VOLUME * i16::MAX as f64) as i16
+        } else {
+            0
+        }
+    }
+
+    fn envelope(pulse_time: u32, pulse_duration: u32) -> f64 {
+        // Ramp up at the start of the pulse and down at its end (this prevents clicks)
+        let ramp_time = pulse_time.min(pulse_duration - pulse_time);
+
+        (ramp_time as f64 / SOUND_PULSE_RAMP_MILLISECONDS as f64).min(1.0)
+    }
+
+    fn tone(time: f64) -> f64 {
+        // Synthesize a tone made of a fundamental frequency and its harmonics (the standard \
+        //   requires at least 4 harmonics, which make the alarm easier to localize and to hear)
+        let (sum, scale) = (1..=SOUND_TONE_HARMONICS).fold((0.0, 0.0), |(sum, scale), harmonic| {
+            let (frequency, weight) = (
+                SOUND_TONE_FUNDAMENTAL_FREQUENCY * harmonic as f64,
+                1.0 / harmonic as f64,
+            );
+
+            (
+                sum + weight * (2.0 * PI * frequency * time).sin(),
+                scale + weight,
+            )
+        });
+
+        sum / scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_seconds(engine: &mut SoundEngine, seconds: u32) -> Vec<i16> {
+        let mut buffer = vec![0; (SOUND_SAMPLE_RATE * seconds) as usize];
+
+        engine.render(&mut buffer);
+
+        buffer
+    }
+
+    fn count_pulses(samples: &[i16]) -> usize {
+        // Count pulses as runs of audible samples, separated by at least 10ms of silence
+        let silence_samples = (SOUND_SAMPLE_RATE / 100) as usize;
+
+        let (mut pulses, mut silent_for) = (0, silence_samples);
+
+        for sample in samples {
+            if *sample == 0 {
+                silent_for += 1;
+            } else {
+                if silent_for >= silence_samples {
+                    pulses += 1;
+                }
+
+                silent_for = 0;
+            }
+        }
+
+        pulses
+    }
+
+    #[test]
+    fn it_renders_silence_without_priority() {
+        let mut engine = SoundEngineBuilder::new();
+
+        assert_eq!(count_pulses(&render_seconds(&mut engine, 2)), 0);
+    }
+
+    #[test]
+    fn it_renders_priority_bursts() {
+        for (priority, pulses) in &[
+            (AlarmPriority::High, 10),
+            (AlarmPriority::Medium, 3),
+            (AlarmPriority::Low, 2),
+        ] {
+            let mut engine = SoundEngineBuilder::new();
+
+            engine.set_priority(Some(*priority));
+
+            assert_eq!(count_pulses(&render_seconds(&mut engine, 5)), *pulses);
+        }
+    }
+
+    #[test]
+    fn it_repeats_bursts() {
+        let mut engine = SoundEngineBuilder::new();
+
+        engine.set_priority(Some(AlarmPriority::Medium));
+
+        assert_eq!(count_pulses(&render_seconds(&mut engine, 16)), 6);
+    }
+
+    #[test]
+    fn it_stops_when_priority_is_cleared() {
+        let mut engine = SoundEngineBuilder::new();
+
+        engine.set_priority(Some(AlarmPriority::High));
+        engine.set_priority(None);
+
+        assert_eq!(count_pulses(&render_seconds(&mut engine, 2)), 0);
+    }
+}
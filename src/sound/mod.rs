@@ -0,0 +1,9 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+pub mod backend;
+pub mod engine;
+pub mod pattern;
+pub mod player;
@@ -0,0 +1,60 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use makair_telemetry::structures::AlarmPriority;
+
+// Notice: those burst patterns follow the IEC 60601-1-8 standard for auditory alarm signals, \
+//   ie. high priority alarms are made of a burst of 10 pulses (3+2 pulses, repeated twice), \
+//   medium priority alarms are made of a burst of 3 pulses, and low priority alarms are made \
+//   of a burst of 2 pulses. Each burst gets repeated after an interval that depends on the \
+//   priority, which is longer than the minimum interval required by the standard.
+const PATTERN_HIGH: SoundPattern = SoundPattern {
+    pulses: &[0, 250, 500, 1000, 1250, 2250, 2500, 2750, 3250, 3500],
+    pulse_duration: 150,
+    burst_period: 7000,
+};
+
+const PATTERN_MEDIUM: SoundPattern = SoundPattern {
+    pulses: &[0, 400, 800],
+    pulse_duration: 200,
+    burst_period: 8000,
+};
+
+const PATTERN_LOW: SoundPattern = SoundPattern {
+    pulses: &[0, 400],
+    pulse_duration: 200,
+    burst_period: 20000,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SoundPattern {
+    // All durations are expressed in milliseconds (pulses are offsets from the burst start)
+    pub pulses: &'static [u32],
+    pub pulse_duration: u32,
+    pub burst_period: u32,
+}
+
+impl SoundPattern {
+    pub fn from_priority(priority: AlarmPriority) -> &'static Self {
+        match priority {
+            AlarmPriority::High => &PATTERN_HIGH,
+            AlarmPriority::Medium => &PATTERN_MEDIUM,
+            AlarmPriority::Low => &PATTERN_LOW,
+        }
+    }
+
+    pub fn pulse_at(&self, time: u32) -> Option<u32> {
+        // Return the time elapsed since the start of the pulse being played at given time (if any)
+        let burst_time = time % self.burst_period;
+
+        self.pulses.iter().find_map(|offset| {
+            if burst_time >= *offset && burst_time < offset + self.pulse_duration {
+                Some(burst_time - offset)
+            } else {
+                None
+            }
+        })
+    }
+}
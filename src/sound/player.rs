@@ -0,0 +1,105 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::sync::mpsc::{self, Sender, TryRecvError};
+use std::time::{Duration, Instant};
+
+use makair_telemetry::structures::AlarmPriority;
+
+use crate::config::arguments::SoundOutput;
+use crate::config::environment::*;
+
+use super::backend::{SoundBackend, SoundBackendAplay, SoundBackendNull, SoundBackendWav};
+use super::engine::SoundEngineBuilder;
+
+const CHUNK_DURATION: Duration = Duration::from_millis(SOUND_CHUNK_MILLISECONDS as u64);
+const CHUNK_SAMPLES: usize = (SOUND_SAMPLE_RATE * SOUND_CHUNK_MILLISECONDS / 1000) as usize;
+
+pub struct SoundPlayerBuilder;
+
+pub struct SoundPlayer {
+    tx: Sender<Option<AlarmPriority>>,
+    priority: Option<AlarmPriority>,
+}
+
+#[allow(clippy::new_ret_no_self)]
+impl SoundPlayerBuilder {
+    pub fn new(output: &SoundOutput) -> SoundPlayer {
+        let backend: Box<dyn SoundBackend> = match output {
+            SoundOutput::None => Box::new(SoundBackendNull),
+            SoundOutput::Aplay => match SoundBackendAplay::new() {
+                Ok(backend) => Box::new(backend),
+                Err(err) => {
+                    error!("could not start aplay sound output, muting: {:?}", err);
+
+                    Box::new(SoundBackendNull)
+                }
+            },
+            SoundOutput::Wav(path) => match SoundBackendWav::new(path) {
+                Ok(backend) => Box::new(backend),
+                Err(err) => {
+                    error!("could not create wav sound output, muting: {:?}", err);
+
+                    Box::new(SoundBackendNull)
+                }
+            },
+        };
+
+        let (tx, rx) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            let mut backend = backend;
+            let mut engine = SoundEngineBuilder::new();
+            let mut buffer = vec![0; CHUNK_SAMPLES];
+
+            let (start_time, mut chunks) = (Instant::now(), 0);
+
+            loop {
+                // Acquire latest priority (if it changed)
+                loop {
+                    match rx.try_recv() {
+                        Ok(priority) => engine.set_priority(priority),
+                        Err(TryRecvError::Empty) => break,
+                        Err(TryRecvError::Disconnected) => return,
+                    }
+                }
+
+                // Render next sound chunk to backend
+                engine.render(&mut buffer);
+
+                if let Err(err) = backend.write(&buffer) {
+                    error!("could not write to sound output, stopping: {:?}", err);
+
+                    return;
+                }
+
+                // Pace rendering to real-time (backends are kept a few chunks ahead, so that \
+                //   they never run out of samples)
+                chunks += 1;
+
+                let next_time =
+                    start_time + CHUNK_DURATION * chunks.saturating_sub(SOUND_PREROLL_CHUNKS);
+
+                if let Some(sleep_duration) = next_time.checked_duration_since(Instant::now()) {
+                    std::thread::sleep(sleep_duration);
+                }
+            }
+        });
+
+        SoundPlayer { tx, priority: None }
+    }
+}
+
+impl SoundPlayer {
+    pub fn update(&mut self, priority: Option<AlarmPriority>) {
+        if priority != self.priority {
+            debug!("alarm sound priority changed to: {:?}", priority);
+
+            self.priority = priority;
+
+            self.tx.send(priority).ok();
+        }
+    }
+}
@@ -0,0 +1,44 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::environment::*;
+
+pub fn apply_brightness(percent: u8) {
+    // Acquire the first backlight device (the Raspberry Pi display has a single backlight)
+    let device = match fs::read_dir(BACKLIGHT_DEVICES_PATH)
+        .ok()
+        .and_then(|mut entries| entries.next())
+        .and_then(|entry| entry.ok())
+    {
+        Some(entry) => entry.path(),
+        None => {
+            debug!("no backlight device found, not applying brightness");
+
+            return;
+        }
+    };
+
+    match write_brightness(device, percent) {
+        Ok(value) => info!("applied backlight brightness: {}% ({})", percent, value),
+        Err(err) => warn!("could not apply backlight brightness: {:?}", err),
+    }
+}
+
+fn write_brightness(device: PathBuf, percent: u8) -> std::io::Result<u32> {
+    // Scale brightness percent to the device range (which is device-specific)
+    let max_brightness: u32 = fs::read_to_string(device.join("max_brightness"))?
+        .trim()
+        .parse()
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+
+    let value = (max_brightness * percent.min(100) as u32 / 100).max(1);
+
+    fs::write(device.join("brightness"), value.to_string())?;
+
+    Ok(value)
+}
@@ -3,6 +3,7 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+pub mod backlight;
 pub mod battery;
 pub mod image;
 pub mod index;
@@ -3,10 +3,16 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use crate::config::settings::{
+    ConfigSettingsFlowUnit, ConfigSettingsPressureUnit, ConfigSettingsVolumeUnit,
+};
+
 const CONVERT_RATIO_MMH2O_TO_CMH2O: f64 = 10.0;
 const CONVERT_RATIO_CL_TO_ML: f64 = 0.1;
 const CONVERT_RATIO_ML_TO_L: f64 = 1000.0;
 const CONVERT_RATIO_CV_TO_V: f64 = 100.0;
+const CONVERT_RATIO_CMH2O_TO_HPA: f64 = 0.980665;
+const CONVERT_RATIO_LPM_TO_MLPS: f64 = 1000.0 / 60.0;
 
 pub enum ConvertMode {
     Rounded,
@@ -48,3 +54,229 @@ pub fn convert_cv_to_v(mode: ConvertMode, value: f64) -> f64 {
         ConvertMode::Rounded => (value / CONVERT_RATIO_CV_TO_V).round(),
     }
 }
+
+pub fn convert_mmh2o_to_pressure_unit(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsPressureUnit,
+) -> f64 {
+    convert_cmh2o_to_pressure_unit(mode, value / CONVERT_RATIO_MMH2O_TO_CMH2O, unit)
+}
+
+pub fn convert_cmh2o_to_pressure_unit(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsPressureUnit,
+) -> f64 {
+    convert_with_ratio(mode, value, pressure_unit_ratio(unit))
+}
+
+pub fn convert_pressure_unit_to_cmh2o(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsPressureUnit,
+) -> f64 {
+    convert_with_ratio(mode, value, 1.0 / pressure_unit_ratio(unit))
+}
+
+pub fn convert_lpm_to_flow_unit(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsFlowUnit,
+) -> f64 {
+    convert_with_ratio(mode, value, flow_unit_ratio(unit))
+}
+
+pub fn convert_flow_unit_to_lpm(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsFlowUnit,
+) -> f64 {
+    convert_with_ratio(mode, value, 1.0 / flow_unit_ratio(unit))
+}
+
+pub fn convert_ml_to_volume_unit(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsVolumeUnit,
+) -> f64 {
+    convert_with_ratio(mode, value, volume_unit_ratio(unit))
+}
+
+pub fn convert_volume_unit_to_ml(
+    mode: ConvertMode,
+    value: f64,
+    unit: ConfigSettingsVolumeUnit,
+) -> f64 {
+    convert_with_ratio(mode, value, 1.0 / volume_unit_ratio(unit))
+}
+
+pub fn pressure_unit_ratio(unit: ConfigSettingsPressureUnit) -> f64 {
+    // Notice: millibars and hectopascals are the same unit, under different names.
+    match unit {
+        ConfigSettingsPressureUnit::CmH2O => 1.0,
+        ConfigSettingsPressureUnit::Mbar | ConfigSettingsPressureUnit::HPa => {
+            CONVERT_RATIO_CMH2O_TO_HPA
+        }
+    }
+}
+
+pub fn flow_unit_ratio(unit: ConfigSettingsFlowUnit) -> f64 {
+    match unit {
+        ConfigSettingsFlowUnit::LitersPerMinute => 1.0,
+        ConfigSettingsFlowUnit::MillilitersPerSecond => CONVERT_RATIO_LPM_TO_MLPS,
+    }
+}
+
+pub fn volume_unit_ratio(unit: ConfigSettingsVolumeUnit) -> f64 {
+    match unit {
+        ConfigSettingsVolumeUnit::Milliliters => 1.0,
+        ConfigSettingsVolumeUnit::Liters => 1.0 / CONVERT_RATIO_ML_TO_L,
+    }
+}
+
+fn convert_with_ratio(mode: ConvertMode, value: f64, ratio: f64) -> f64 {
+    match mode {
+        ConvertMode::WithDecimals => value * ratio,
+        ConvertMode::Rounded => (value * ratio).round(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn pressure_round_trip() {
+        for unit in ConfigSettingsPressureUnit::list_all().iter() {
+            for value in [0.0, 5.0, 12.5, 30.0, 70.0].iter() {
+                let converted =
+                    convert_cmh2o_to_pressure_unit(ConvertMode::WithDecimals, *value, *unit);
+
+                assert!(
+                    (convert_pressure_unit_to_cmh2o(ConvertMode::WithDecimals, converted, *unit)
+                        - value)
+                        .abs()
+                        < EPSILON
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pressure_known_values() {
+        assert_eq!(
+            convert_mmh2o_to_pressure_unit(
+                ConvertMode::Rounded,
+                200.0,
+                ConfigSettingsPressureUnit::CmH2O
+            ),
+            20.0
+        );
+        assert!(
+            (convert_cmh2o_to_pressure_unit(
+                ConvertMode::WithDecimals,
+                100.0,
+                ConfigSettingsPressureUnit::HPa
+            ) - 98.0665)
+                .abs()
+                < EPSILON
+        );
+        assert_eq!(
+            convert_cmh2o_to_pressure_unit(
+                ConvertMode::WithDecimals,
+                100.0,
+                ConfigSettingsPressureUnit::Mbar
+            ),
+            convert_cmh2o_to_pressure_unit(
+                ConvertMode::WithDecimals,
+                100.0,
+                ConfigSettingsPressureUnit::HPa
+            )
+        );
+    }
+
+    #[test]
+    fn flow_round_trip() {
+        for unit in ConfigSettingsFlowUnit::list_all().iter() {
+            for value in [0.0, 0.5, 6.0, 60.0, 120.0].iter() {
+                let converted = convert_lpm_to_flow_unit(ConvertMode::WithDecimals, *value, *unit);
+
+                assert!(
+                    (convert_flow_unit_to_lpm(ConvertMode::WithDecimals, converted, *unit) - value)
+                        .abs()
+                        < EPSILON
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn flow_known_values() {
+        assert_eq!(
+            convert_lpm_to_flow_unit(
+                ConvertMode::Rounded,
+                60.0,
+                ConfigSettingsFlowUnit::MillilitersPerSecond
+            ),
+            1000.0
+        );
+        assert_eq!(
+            convert_lpm_to_flow_unit(
+                ConvertMode::Rounded,
+                60.0,
+                ConfigSettingsFlowUnit::LitersPerMinute
+            ),
+            60.0
+        );
+    }
+
+    #[test]
+    fn volume_round_trip() {
+        for unit in ConfigSettingsVolumeUnit::list_all().iter() {
+            for value in [0.0, 50.0, 450.0, 1234.0, 3000.0].iter() {
+                let converted = convert_ml_to_volume_unit(ConvertMode::WithDecimals, *value, *unit);
+
+                assert!(
+                    (convert_volume_unit_to_ml(ConvertMode::WithDecimals, converted, *unit)
+                        - value)
+                        .abs()
+                        < EPSILON
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn volume_known_values() {
+        assert!(
+            (convert_ml_to_volume_unit(
+                ConvertMode::WithDecimals,
+                450.0,
+                ConfigSettingsVolumeUnit::Liters
+            ) - 0.45)
+                .abs()
+                < EPSILON
+        );
+        assert_eq!(
+            convert_volume_unit_to_ml(ConvertMode::Rounded, 0.45, ConfigSettingsVolumeUnit::Liters),
+            450.0
+        );
+    }
+
+    #[test]
+    fn rounded_matches_legacy_conversions() {
+        for value in [0.0, 15.0, 204.0, 355.0].iter() {
+            assert_eq!(
+                convert_mmh2o_to_pressure_unit(
+                    ConvertMode::Rounded,
+                    *value,
+                    ConfigSettingsPressureUnit::CmH2O
+                ),
+                convert_mmh2o_to_cmh2o(ConvertMode::Rounded, *value)
+            );
+        }
+    }
+}
@@ -8,6 +8,8 @@
 // Copyright: 2020, Makers For Life
 // License: Public Domain License
 
+use std::path::Path;
+
 use chrono::offset::Local;
 use conrod_core::{
     color::{self, Color},
@@ -18,16 +20,20 @@ use conrod_core::{
 use makair_telemetry::alarm::AlarmCode;
 use makair_telemetry::structures::{AlarmPriority, DataSnapshot, MachineStateSnapshot};
 
+use crate::chip::mechanics::ChipMechanics;
+use crate::chip::session::ChipSession;
 use crate::chip::settings::advanced::{SettingsAdvanced, SettingsAdvancedGroupTab};
+use crate::chip::weaning::{ChipWeaning, ChipWeaningCriteria};
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::locale::advanced::group_tab_to_locale as advanced_group_tab_to_locale;
+use crate::locale::preset::{age_to_locale, gender_to_locale};
 use crate::utilities::{
     battery::power_status_flags,
     parse::{parse_non_empty_number_to_string, parse_optional_number_to_string},
-    units::{convert_cv_to_v, convert_sub_ppm_to_ppm, ConvertMode},
+    units::{convert_cl_to_ml, convert_cv_to_v, convert_sub_ppm_to_ppm, ConvertMode},
 };
-use crate::{APP_CONTEXT, APP_I18N};
+use crate::{APP_CONTEXT, APP_I18N, APP_SETTINGS};
 
 type FieldWidgetIds = (
     WidgetId,
@@ -41,6 +47,8 @@ type FieldWidgetIds = (
 
 type TextWidgetIds = (WidgetId, WidgetId);
 
+type ButtonWidgetIds = (WidgetId, WidgetId, WidgetId);
+
 pub struct Config<'a> {
     pub width: f64,
     pub height: f64,
@@ -51,6 +59,12 @@ pub struct Config<'a> {
     pub machine_snapshot: &'a MachineStateSnapshot,
     pub data_snapshot: Option<&'a DataSnapshot>,
     pub alarms: &'a [(AlarmCode, AlarmPriority)],
+    pub mechanics: &'a ChipMechanics,
+    pub leak_threshold: usize,
+    pub weaning: &'a ChipWeaning,
+    pub weaning_criteria: &'a ChipWeaningCriteria,
+    pub weaning_available: bool,
+    pub session: &'a ChipSession,
 
     pub advanced_container_parent: WidgetId,
     pub advanced_container_widget: WidgetId,
@@ -64,10 +78,38 @@ pub struct Config<'a> {
     pub advanced_group_tab_texts: [WidgetId; ADVANCED_SETTINGS_GROUP_TABS_COUNT],
 
     pub field_locale_ids: FieldWidgetIds,
+    pub field_theme_ids: FieldWidgetIds,
 
     pub text_date_ids: TextWidgetIds,
     pub text_time_ids: TextWidgetIds,
     pub text_timezone_ids: TextWidgetIds,
+
+    pub field_weaning_duration_ids: FieldWidgetIds,
+    pub button_weaning_toggle_ids: ButtonWidgetIds,
+
+    pub text_weaning_elapsed_ids: TextWidgetIds,
+    pub text_weaning_rsbi_ids: TextWidgetIds,
+    pub text_weaning_rate_ids: TextWidgetIds,
+    pub text_weaning_minute_volume_ids: TextWidgetIds,
+    pub text_weaning_result_ids: TextWidgetIds,
+
+    pub button_session_patient_ids: ButtonWidgetIds,
+    pub text_session_started_ids: TextWidgetIds,
+    pub text_session_duration_ids: TextWidgetIds,
+    pub text_session_events_ids: TextWidgetIds,
+    pub text_session_record_ids: TextWidgetIds,
+    pub button_session_toggle_ids: ButtonWidgetIds,
+    pub button_session_new_ids: ButtonWidgetIds,
+
+    pub field_graph_seconds_ids: FieldWidgetIds,
+    pub field_pressure_unit_ids: FieldWidgetIds,
+    pub field_flow_unit_ids: FieldWidgetIds,
+    pub field_volume_unit_ids: FieldWidgetIds,
+    pub field_snooze_max_ids: FieldWidgetIds,
+    pub field_brightness_ids: FieldWidgetIds,
+    pub field_preset_age_ids: FieldWidgetIds,
+    pub field_preset_gender_ids: FieldWidgetIds,
+    pub text_record_directory_ids: TextWidgetIds,
 }
 
 struct Field {
@@ -82,6 +124,13 @@ struct Text {
     ids: TextWidgetIds,
 }
 
+struct Button {
+    label_text: String,
+    value_text: String,
+    value_color: Color,
+    ids: ButtonWidgetIds,
+}
+
 pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     // Create canvas
     gen_widget_container!(
@@ -90,7 +139,8 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
         color: color::TRANSPARENT,
         width: config.width,
         height: config.height,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.advanced_container_parent,
         ]
     );
@@ -157,7 +207,8 @@ fn form<'a>(master: &mut ControlWidget<'a>, config: &Config, parent_size: (f64,
         color: color::TRANSPARENT,
         width: parent_size.0 - tabs_total_width,
         height: parent_size.1,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.advanced_container_widget, 0.0, tabs_total_width,
         ]
     );
@@ -165,6 +216,10 @@ fn form<'a>(master: &mut ControlWidget<'a>, config: &Config, parent_size: (f64,
     // Append form depending on current group
     match config.advanced_settings.group {
         SettingsAdvancedGroupTab::Statistics => form_statistics(master, config),
+        SettingsAdvancedGroupTab::Mechanics => form_mechanics(master, config),
+        SettingsAdvancedGroupTab::Weaning => form_weaning(master, config),
+        SettingsAdvancedGroupTab::Session => form_session(master, config),
+        SettingsAdvancedGroupTab::Preferences => form_preferences(master, config),
         SettingsAdvancedGroupTab::Settings => form_settings(master, config),
     }
 }
@@ -268,10 +323,8 @@ fn form_statistics<'a>(master: &mut ControlWidget<'a>, config: &Config) {
                 .machine_snapshot
                 .battery_level
                 .map(|value| {
-                    format!(
-                        "{:.2}",
-                        convert_cv_to_v(ConvertMode::WithDecimals, value as _)
-                    )
+                    APP_I18N
+                        .format_number(convert_cv_to_v(ConvertMode::WithDecimals, value as _), 2)
                 })
                 .unwrap_or_else(|| "".to_string()),
         ),
@@ -281,13 +334,395 @@ fn form_statistics<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     form_statistics_lines(master, config, &line_data);
 }
 
+fn form_mechanics<'a>(master: &mut ControlWidget<'a>, config: &Config) {
+    let format_value = |value: Option<f64>| {
+        value
+            .map(|value| APP_I18N.format_number(value, 1))
+            .unwrap_or_else(|| "".to_string())
+    };
+
+    let (driving_pressure, compliance, resistance, leak_percent) = (
+        format_value(config.mechanics.driving_pressure),
+        format_value(config.mechanics.compliance),
+        format_value(config.mechanics.resistance),
+        format_value(config.mechanics.leak_percent),
+    );
+
+    // Show leak along with its alarm threshold (which is expressed in cL/min)
+    let leak = config
+        .mechanics
+        .leak
+        .map(|leak| {
+            APP_I18N.t_args(
+                "modal-advanced-value-maximum",
+                &[
+                    ("value", APP_I18N.format_number(leak, 0).into()),
+                    (
+                        "maximum",
+                        convert_cl_to_ml(ConvertMode::Rounded, config.leak_threshold as f64).into(),
+                    ),
+                ],
+            )
+        })
+        .unwrap_or_else(|| "".to_string());
+
+    // Generate line data
+    // Notice: those values are computed by the UI from end-of-cycle measurements, and are \
+    //   smoothed over multiple cycles.
+    let line_data = [
+        // Driving pressure (plateau pressure minus PEEP)
+        (
+            "mechanics-driving-pressure-cmh2o",
+            driving_pressure.as_str(),
+        ),
+        // Static compliance (tidal volume divided by driving pressure)
+        ("mechanics-static-compliance-ml-cmh2o", compliance.as_str()),
+        // Airway resistance (peak pressure minus plateau pressure, divided by peak flow; only \
+        //   known in volume-controlled modes, as the inspiratory flow is constant there)
+        ("mechanics-airway-resistance-cmh2o-l-s", resistance.as_str()),
+        // Leak (inspired volume minus expired volume, integrated over each cycle)
+        ("mechanics-leak-ml-min", leak.as_str()),
+        ("mechanics-leak-percent", leak_percent.as_str()),
+    ];
+
+    // Append form lines
+    form_statistics_lines(master, config, &line_data);
+}
+
+fn form_weaning<'a>(master: &mut ControlWidget<'a>, config: &Config) {
+    let (weaning, criteria) = (config.weaning, config.weaning_criteria);
+
+    let format_value = |value: Option<f64>, maximum: f64| {
+        value
+            .map(|value| {
+                APP_I18N.t_args(
+                    "modal-advanced-value-maximum",
+                    &[
+                        ("value", APP_I18N.format_number(value, 1).into()),
+                        (
+                            "maximum",
+                            APP_I18N
+                                .format_number(
+                                    maximum,
+                                    if maximum.fract().abs() < f64::EPSILON {
+                                        0
+                                    } else {
+                                        1
+                                    },
+                                )
+                                .into(),
+                        ),
+                    ],
+                )
+            })
+            .unwrap_or_else(|| ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string())
+    };
+    let format_duration = |seconds: u64| format!("{:02}:{:02}", seconds / 60, seconds % 60);
+
+    // Acquire values from the ongoing trial, or from the last trial summary (values show \
+    //   averages over the whole trial)
+    let (elapsed, duration, rsbi, rate, minute_volume, result) =
+        match (&weaning.trial, &weaning.summary) {
+            (Some(trial), _) => (
+                Some(trial.started_at.elapsed().min(trial.duration)),
+                trial.duration,
+                trial.average_rsbi(),
+                trial.average_rate(),
+                trial.average_minute_volume(),
+                "ongoing",
+            ),
+            (None, Some(summary)) => (
+                Some(summary.elapsed),
+                summary.duration,
+                summary.rsbi,
+                summary.rate,
+                summary.minute_volume,
+                if summary.passed {
+                    "passed"
+                } else if summary.completed {
+                    "failed"
+                } else {
+                    "aborted"
+                },
+            ),
+            (None, None) => (
+                None,
+                weaning.duration(),
+                None,
+                None,
+                None,
+                if config.weaning_available {
+                    ""
+                } else {
+                    "unavailable"
+                },
+            ),
+        };
+
+    draw_field(
+        0,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-weaning-duration"),
+            value_text: APP_I18N.t_args(
+                "modal-advanced-weaning-minutes",
+                &[("minutes", (weaning.duration().as_secs() / 60).into())],
+            ),
+            ids: config.field_weaning_duration_ids,
+        },
+    );
+
+    draw_button(
+        1,
+        master,
+        config,
+        Button {
+            label_text: APP_I18N.t("modal-advanced-weaning-trial"),
+            value_text: if weaning.trial.is_some() {
+                APP_I18N.t("modal-advanced-weaning-stop")
+            } else {
+                APP_I18N.t("modal-advanced-weaning-start")
+            },
+            value_color: if weaning.trial.is_some() {
+                master.theme.base.button_positive
+            } else {
+                master.theme.base.button_negative
+            },
+            ids: config.button_weaning_toggle_ids,
+        },
+    );
+
+    draw_text(
+        2,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-weaning-elapsed"),
+            value_text: elapsed
+                .map(|elapsed| {
+                    format!(
+                        "{} / {}",
+                        format_duration(elapsed.as_secs()),
+                        format_duration(duration.as_secs())
+                    )
+                })
+                .unwrap_or_else(|| ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()),
+            ids: config.text_weaning_elapsed_ids,
+        },
+    );
+
+    draw_text(
+        3,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-weaning-rsbi"),
+            value_text: format_value(rsbi, criteria.rsbi_max),
+            ids: config.text_weaning_rsbi_ids,
+        },
+    );
+
+    draw_text(
+        4,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-weaning-rate"),
+            value_text: format_value(rate, criteria.rate_max),
+            ids: config.text_weaning_rate_ids,
+        },
+    );
+
+    draw_text(
+        5,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-weaning-minute-volume"),
+            value_text: format_value(minute_volume, criteria.minute_volume_max),
+            ids: config.text_weaning_minute_volume_ids,
+        },
+    );
+
+    draw_text(
+        6,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-weaning-result"),
+            value_text: if result.is_empty() {
+                ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()
+            } else {
+                APP_I18N.t(&format!("modal-advanced-weaning-result-{}", result))
+            },
+            ids: config.text_weaning_result_ids,
+        },
+    );
+}
+
+fn form_session<'a>(master: &mut ControlWidget<'a>, config: &Config) {
+    let session = config.session;
+
+    // Acquire values from the ongoing session, or from the last session summary (if no session \
+    //   is ongoing)
+    let (patient_id, started_at, duration, alarms, settings_commits, record_path) =
+        match (&session.current, &session.summary) {
+            (Some(current), _) => (
+                current.patient_id.as_deref(),
+                Some(current.started_at),
+                Some((Local::now() - current.started_at).num_seconds().max(0)),
+                current.alarms,
+                current.settings_commits,
+                current.record_path.as_deref(),
+            ),
+            (None, Some(summary)) => (
+                summary.patient_id.as_deref(),
+                Some(summary.started_at),
+                Some(summary.duration_seconds()),
+                summary.alarms,
+                summary.settings_commits,
+                summary.record_path.as_deref(),
+            ),
+            (None, None) => (None, None, None, 0, 0, None),
+        };
+
+    // Notice: the patient identifier is typed (or scanned) after tapping its button, and gets \
+    //   used for the next session to be started. It is highlighted while it differs from the \
+    //   identifier of the last session.
+    draw_button(
+        0,
+        master,
+        config,
+        Button {
+            label_text: APP_I18N.t("modal-advanced-session-patient"),
+            value_text: if session.patient_id_input.is_empty() {
+                APP_I18N.t("modal-advanced-session-patient-input")
+            } else {
+                session.patient_id_input.to_owned()
+            },
+            value_color: if session.patient_id_input.as_str() != patient_id.unwrap_or("") {
+                master.theme.base.text_changed
+            } else {
+                master.theme.base.button_text
+            },
+            ids: config.button_session_patient_ids,
+        },
+    );
+
+    draw_text(
+        1,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-session-started"),
+            value_text: started_at
+                .map(|started_at| started_at.format("%d/%m/%Y %-I:%M %p").to_string())
+                .unwrap_or_else(|| ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()),
+            ids: config.text_session_started_ids,
+        },
+    );
+
+    draw_text(
+        2,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-session-duration"),
+            value_text: duration
+                .map(|seconds| format!("{:02}:{:02}", seconds / 3600, (seconds % 3600) / 60))
+                .unwrap_or_else(|| ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()),
+            ids: config.text_session_duration_ids,
+        },
+    );
+
+    draw_text(
+        3,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-session-events"),
+            value_text: if started_at.is_some() {
+                format!("{} / {}", alarms, settings_commits)
+            } else {
+                ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()
+            },
+            ids: config.text_session_events_ids,
+        },
+    );
+
+    draw_text(
+        4,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-session-record"),
+            value_text: record_path
+                .and_then(|record_path| Path::new(record_path).file_name())
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_else(|| ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()),
+            ids: config.text_session_record_ids,
+        },
+    );
+
+    // Notice: the record directory cannot be changed from there, as text cannot be input from \
+    //   the touchscreen (it can be set in the settings file, or with the output argument).
+    draw_text(
+        5,
+        master,
+        config,
+        Text {
+            label_text: APP_I18N.t("modal-advanced-preferences-record-directory"),
+            value_text: APP_SETTINGS
+                .read()
+                .unwrap()
+                .record_directory
+                .to_owned()
+                .unwrap_or_else(|| ADVANCED_SETTINGS_LINE_VALUE_EMPTY.to_string()),
+            ids: config.text_record_directory_ids,
+        },
+    );
+
+    draw_button(
+        6,
+        master,
+        config,
+        Button {
+            label_text: APP_I18N.t("modal-advanced-session-state"),
+            value_text: if session.current.is_some() {
+                APP_I18N.t("modal-advanced-session-end")
+            } else {
+                APP_I18N.t("modal-advanced-session-start")
+            },
+            value_color: if session.current.is_some() {
+                master.theme.base.button_positive
+            } else {
+                master.theme.base.button_negative
+            },
+            ids: config.button_session_toggle_ids,
+        },
+    );
+
+    draw_button(
+        7,
+        master,
+        config,
+        Button {
+            label_text: APP_I18N.t("modal-advanced-session-new-patient"),
+            value_text: APP_I18N.t("modal-advanced-session-new"),
+            value_color: master.theme.base.button_text,
+            ids: config.button_session_new_ids,
+        },
+    );
+}
+
 fn form_statistics_lines<'a>(
     master: &mut ControlWidget<'a>,
     config: &Config,
     line_data: &[(&str, &str)],
 ) {
-    for (index, container_line) in config.advanced_container_line_labels.iter().enumerate() {
-        let line_text = line_data[index].0;
+    for (index, &(line_text, line_value)) in line_data.iter().enumerate() {
+        let container_line = &config.advanced_container_line_labels[index];
 
         // Render line label (the positioning method varies if the line is the first one)
         if index == 0 {
@@ -297,7 +732,8 @@ fn form_statistics_lines<'a>(
                 value: line_text,
                 font_size: ADVANCED_SETTINGS_LINE_FONT_SIZE,
                 font_weight: bold,
-                positions: top_left_of[
+                positions: top_start_of[
+                    master.direction,
                     config.advanced_form_wrapper,
                 ]
             );
@@ -319,17 +755,18 @@ fn form_statistics_lines<'a>(
         let mut value_text_style = widget::text::Style::default();
 
         value_text_style.font_id = Some(Some(master.fonts.regular));
-        value_text_style.color = Some(color::WHITE);
+        value_text_style.color = Some(master.theme.base.text);
         value_text_style.font_size = Some(ADVANCED_SETTINGS_LINE_FONT_SIZE);
 
         // Create text
-        widget::Text::new(if line_data[index].1.is_empty() {
+        widget::Text::new(if line_value.is_empty() {
             ADVANCED_SETTINGS_LINE_VALUE_EMPTY
         } else {
-            &line_data[index].1
+            line_value
         })
         .with_style(value_text_style)
-        .top_left_with_margins_on(
+        .top_start_with_margins_on(
+            master.direction,
             *container_line,
             0.0,
             ADVANCED_SETTINGS_LINE_VALUE_PADDING_LEFT,
@@ -338,8 +775,106 @@ fn form_statistics_lines<'a>(
     }
 }
 
+fn form_preferences<'a>(master: &mut ControlWidget<'a>, config: &Config) {
+    let settings = APP_SETTINGS.read().unwrap();
+
+    draw_field(
+        0,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-graph-seconds"),
+            value_text: APP_I18N.t_args(
+                "modal-advanced-preferences-seconds",
+                &[("seconds", settings.graph_seconds.into())],
+            ),
+            ids: config.field_graph_seconds_ids,
+        },
+    );
+
+    draw_field(
+        1,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-pressure-unit"),
+            value_text: APP_I18N.t(settings.pressure_unit.to_locale_key()),
+            ids: config.field_pressure_unit_ids,
+        },
+    );
+
+    draw_field(
+        2,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-flow-unit"),
+            value_text: APP_I18N.t(settings.flow_unit.to_locale_key()),
+            ids: config.field_flow_unit_ids,
+        },
+    );
+
+    draw_field(
+        3,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-volume-unit"),
+            value_text: APP_I18N.t(settings.volume_unit.to_locale_key()),
+            ids: config.field_volume_unit_ids,
+        },
+    );
+
+    draw_field(
+        4,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-snooze-max"),
+            value_text: APP_I18N.t_args(
+                "modal-advanced-preferences-seconds",
+                &[("seconds", settings.snooze_max_seconds.into())],
+            ),
+            ids: config.field_snooze_max_ids,
+        },
+    );
+
+    draw_field(
+        5,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-brightness"),
+            value_text: format!("{}%", settings.brightness),
+            ids: config.field_brightness_ids,
+        },
+    );
+
+    draw_field(
+        6,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-preset-age"),
+            value_text: age_to_locale(&settings.preset_age),
+            ids: config.field_preset_age_ids,
+        },
+    );
+
+    draw_field(
+        7,
+        master,
+        config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-preferences-preset-gender"),
+            value_text: gender_to_locale(&settings.preset_gender),
+            ids: config.field_preset_gender_ids,
+        },
+    );
+}
+
 fn form_settings<'a>(master: &mut ControlWidget<'a>, config: &Config) {
-    let now = Local::now();
+    let (now, theme) = (Local::now(), APP_SETTINGS.read().unwrap().theme);
 
     draw_field(
         0,
@@ -352,10 +887,21 @@ fn form_settings<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         },
     );
 
-    draw_text(
+    draw_field(
         1,
         master,
         config,
+        Field {
+            label_text: APP_I18N.t("modal-advanced-theme"),
+            value_text: APP_I18N.t(theme.to_locale_key()),
+            ids: config.field_theme_ids,
+        },
+    );
+
+    draw_text(
+        2,
+        master,
+        config,
         Text {
             label_text: APP_I18N.t("modal-advanced-date"),
             value_text: now.format("%d/%m/%Y").to_string(),
@@ -364,7 +910,7 @@ fn form_settings<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     );
 
     draw_text(
-        2,
+        3,
         master,
         config,
         Text {
@@ -375,7 +921,7 @@ fn form_settings<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     );
 
     draw_text(
-        3,
+        4,
         master,
         config,
         Text {
@@ -392,7 +938,8 @@ fn draw_field<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config,
         master,
         text_id: field.ids.0,
         value: &field.label_text,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.advanced_form_wrapper, index as f64 * ADVANCED_SETTINGS_MODAL_FORM_FIELD_HEIGHT_PADDED, 0.0,
         ]
     );
@@ -408,7 +955,8 @@ fn draw_field<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config,
         value_id: field.ids.2,
         value: &field.value_text,
         changed: false,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             field.ids.0,
             MODAL_BUTTON_NAVIGATE_LEFT_ALIGN_TOP,
             ADVANCED_SETTINGS_MODAL_FORM_PADDING_LEFT,
@@ -422,7 +970,8 @@ fn draw_text<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config,
         master,
         text_id: text.ids.0,
         value: &text.label_text,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.advanced_form_wrapper, index as f64 * ADVANCED_SETTINGS_MODAL_FORM_FIELD_HEIGHT_PADDED, 0.0,
         ]
     );
@@ -434,10 +983,45 @@ fn draw_text<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config,
         value: text.value_text,
         changed: false,
         y_relative: 0.0,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             text.ids.0,
             0.0,
             ADVANCED_SETTINGS_MODAL_FORM_PADDING_LEFT,
         ]
     );
 }
+
+fn draw_button<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config, button: Button) {
+    // Generate label
+    gen_widget_label_form!(
+        master,
+        text_id: button.ids.0,
+        value: &button.label_text,
+        positions: top_start_with_margins_on[
+            master.direction,
+            config.advanced_form_wrapper, index as f64 * ADVANCED_SETTINGS_MODAL_FORM_FIELD_HEIGHT_PADDED, 0.0,
+        ]
+    );
+
+    // Generate button
+    gen_widget_button!(
+        master,
+        button_id: button.ids.1,
+        text_id: button.ids.2,
+        text_color: button.value_color,
+        text_font_size: MODAL_BUTTON_FONT_SIZE,
+        width: ADVANCED_SETTINGS_WEANING_BUTTON_WIDTH,
+        value_top: MODAL_BUTTON_VALUE_TOP,
+        value: &button.value_text,
+
+        positions: (
+            top_start_with_margins_on[
+                master.direction,
+                button.ids.0,
+                MODAL_BUTTON_NAVIGATE_LEFT_ALIGN_TOP,
+                ADVANCED_SETTINGS_MODAL_FORM_PADDING_LEFT,
+            ]
+        )
+    );
+}
@@ -15,16 +15,10 @@ use makair_telemetry::structures::AlarmPriority;
 use super::alarms;
 
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
-use crate::locale::alarms::description_to_locale as alarm_description_to_locale;
-
-const ALARM_HIGH_CODE_COLOR: Color = Color::Rgba(1.0, 0.0 / 255.0, 3.0 / 255.0, 1.0);
-const ALARM_MEDIUM_CODE_COLOR: Color = Color::Rgba(1.0, 135.0 / 255.0, 0.0, 1.0);
-const ALARM_LOW_CODE_COLOR: Color = Color::Rgba(1.0, 195.0 / 255.0, 0.0, 1.0);
-
-const ALARM_HIGH_MESSAGE_COLOR: Color = Color::Rgba(180.0 / 255.0, 24.0 / 255.0, 28.0 / 255.0, 1.0);
-const ALARM_MEDIUM_MESSAGE_COLOR: Color = Color::Rgba(189.0 / 255.0, 93.0 / 255.0, 0.0, 1.0);
-const ALARM_LOW_MESSAGE_COLOR: Color = Color::Rgba(174.0 / 255.0, 133.0 / 255.0, 0.0, 1.0);
+use crate::display::theme::Theme;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
+use crate::locale::alarms::code_to_locale as alarm_code_to_locale;
+use crate::APP_I18N;
 
 pub fn render(
     master: &mut ControlWidget,
@@ -32,6 +26,7 @@ pub fn render(
 
     alarm_code: AlarmCode,
     alarm_priority: &AlarmPriority,
+    alarm_resolved: bool,
 
     index: usize,
 ) {
@@ -58,7 +53,8 @@ pub fn render(
             config.container,
             conrod_core::position::Place::End(Some(from_top)),
         )
-        .right_from(
+        .end_from(
+            master.direction,
             config.title_wrapper,
             DISPLAY_ALARM_CONTAINER_TITLE_TO_ALARM_HAS_ALARMS_SPACING,
         )
@@ -66,24 +62,36 @@ pub fn render(
 
     // Draw code & message
     code(master, &config, alarm_code, alarm_priority, index);
-    message(master, &config, alarm_code, alarm_priority, index);
+    message(
+        master,
+        &config,
+        alarm_code,
+        alarm_priority,
+        alarm_resolved,
+        index,
+    );
 }
 
-fn code_color(alarm_priority: &AlarmPriority) -> Color {
+fn code_color(theme: &Theme, alarm_priority: &AlarmPriority) -> Color {
     // Map alarm code colors
     match alarm_priority {
-        AlarmPriority::High => ALARM_HIGH_CODE_COLOR,
-        AlarmPriority::Medium => ALARM_MEDIUM_CODE_COLOR,
-        AlarmPriority::Low => ALARM_LOW_CODE_COLOR,
+        AlarmPriority::High => theme.alarm.code_high,
+        AlarmPriority::Medium => theme.alarm.code_medium,
+        AlarmPriority::Low => theme.alarm.code_low,
     }
 }
 
-fn message_color(alarm_priority: &AlarmPriority) -> Color {
+fn message_color(theme: &Theme, alarm_priority: &AlarmPriority, alarm_resolved: bool) -> Color {
+    // Resolved alarms are dimmed, as they only need to be acknowledged
+    if alarm_resolved {
+        return theme.alarm.message_resolved;
+    }
+
     // Map alarm message colors
     match alarm_priority {
-        AlarmPriority::High => ALARM_HIGH_MESSAGE_COLOR,
-        AlarmPriority::Medium => ALARM_MEDIUM_MESSAGE_COLOR,
-        AlarmPriority::Low => ALARM_LOW_MESSAGE_COLOR,
+        AlarmPriority::High => theme.alarm.message_high,
+        AlarmPriority::Medium => theme.alarm.message_medium,
+        AlarmPriority::Low => theme.alarm.message_low,
     }
 }
 
@@ -98,12 +106,13 @@ fn code<'a>(
     gen_widget_container!(
         master,
         container_id: config.alarm_codes_containers[index],
-        color: code_color(alarm_priority),
+        color: code_color(master.theme, alarm_priority),
         width: DISPLAY_ALARM_CODE_WIDTH,
         height: DISPLAY_ALARM_CODE_HEIGHT,
-        positions: x_place_on[
+        positions: x_place_start_on[
+            master.direction,
             config.alarm_widgets[index],
-            conrod_core::position::Place::Start(None),
+            None,
         ]
     );
 
@@ -111,7 +120,7 @@ fn code<'a>(
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::WHITE);
+    text_style.color = Some(master.theme.base.text);
     text_style.font_size = Some(DISPLAY_ALARM_CODE_FONT_SIZE);
 
     widget::text::Text::new(&format!("{}", alarm_code.code()))
@@ -125,25 +134,47 @@ fn message<'a>(
     config: &alarms::Config,
     alarm_code: AlarmCode,
     alarm_priority: &AlarmPriority,
+    alarm_resolved: bool,
     index: usize,
 ) {
     // Create canvas
     gen_widget_container!(
         master,
         container_id: config.alarm_messages_containers[index],
-        color: message_color(alarm_priority),
+        color: message_color(master.theme, alarm_priority, alarm_resolved),
         width: DISPLAY_ALARM_MESSAGE_WIDTH,
         height: DISPLAY_ALARM_MESSAGE_HEIGHT,
-        positions: x_place_on[
+        positions: x_place_start_on[
+            master.direction,
             config.alarm_widgets[index],
-            conrod_core::position::Place::Start(Some(DISPLAY_ALARM_CODE_WIDTH)),
+            Some(DISPLAY_ALARM_CODE_WIDTH),
         ]
     );
 
     // Insert text in canvas
-    widget::text::Text::new(&alarm_description_to_locale(alarm_code.description()))
-        .color(color::WHITE)
-        .font_size(DISPLAY_ALARM_MESSAGE_FONT_SIZE)
-        .top_left_with_margins_on(config.alarm_messages_containers[index], 3.0, 8.0)
+    let (message_text, message_font_size) = if alarm_resolved {
+        (
+            APP_I18N.t_args(
+                "alarms-resolved",
+                &[("alarm", alarm_code_to_locale(alarm_code).into())],
+            ),
+            DISPLAY_ALARM_MESSAGE_RESOLVED_FONT_SIZE,
+        )
+    } else {
+        (
+            alarm_code_to_locale(alarm_code),
+            DISPLAY_ALARM_MESSAGE_FONT_SIZE,
+        )
+    };
+
+    widget::text::Text::new(&message_text)
+        .color(master.theme.base.text)
+        .font_size(message_font_size)
+        .top_start_with_margins_on(
+            master.direction,
+            config.alarm_messages_containers[index],
+            3.0,
+            8.0,
+        )
         .set(config.alarm_messages[index], &mut master.ui);
 }
@@ -8,7 +8,7 @@ use std::cmp::max;
 use conrod_core::widget::id::List;
 use conrod_core::widget::Id as WidgetId;
 use conrod_core::{
-    color::{self, Color},
+    color,
     widget::{self, rounded_rectangle::RoundedRectangle},
     Colorable, Positionable, Sizeable, Widget,
 };
@@ -19,14 +19,9 @@ use makair_telemetry::structures::AlarmPriority;
 use super::alarm;
 
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::APP_I18N;
 
-const CONTAINER_WITH_ALARMS_BACKGROUND_COLOR: Color =
-    Color::Rgba(42.0 / 255.0, 42.0 / 255.0, 42.0 / 255.0, 0.96);
-const CONTAINER_WITHOUT_ALARMS_BACKGROUND_COLOR: Color =
-    Color::Rgba(17.0 / 255.0, 17.0 / 255.0, 17.0 / 255.0, 0.96);
-
 pub struct Config<'a> {
     pub parent: WidgetId,
     pub container: WidgetId,
@@ -43,11 +38,12 @@ pub struct Config<'a> {
     pub alarm_messages: &'a List,
 
     pub alarms: &'a [(AlarmCode, AlarmPriority)],
+    pub latched_alarms: &'a [(AlarmCode, AlarmPriority)],
 }
 
 pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
-    // Count alarm totals
-    let alarms_count = config.alarms.len();
+    // Count alarm totals (resolved alarms that were not acknowledged yet are listed as well)
+    let alarms_count = config.alarms.len() + config.latched_alarms.len();
 
     // Compute container width based on alarm count (the container should be small if there are \
     //   no alarms, wide if there are alarms)
@@ -77,12 +73,14 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
 
     // Acquire container background color (based on alarms count)
     let container_background_color = if alarms_count > 0 {
-        CONTAINER_WITH_ALARMS_BACKGROUND_COLOR
+        master.theme.alarm.container_active
     } else {
-        CONTAINER_WITHOUT_ALARMS_BACKGROUND_COLOR
+        master.theme.alarm.container_empty
     };
 
     // Draw container box
+    // Notice: the container stays next to the branding whatever the locale direction, as the \
+    //   header arrangement follows the header texture. Only its contents get mirrored.
     RoundedRectangle::fill_with(
         [container_width, container_height],
         DISPLAY_ROUNDED_RECTANGLES_ROUND,
@@ -99,7 +97,8 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
         color: color::TRANSPARENT,
         width: DISPLAY_ALARM_TITLE_WRAPPER_WIDTH,
         height: DISPLAY_ALARM_TITLE_WRAPPER_HEIGHT,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.container,
             DISPLAY_ALARM_CONTAINER_PADDING_TOP,
             if alarms_count > 0 {
@@ -118,28 +117,31 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     let mut title_text_style = conrod_core::widget::primitive::text::Style::default();
 
     title_text_style.font_id = Some(Some(master.fonts.bold));
-    title_text_style.color = Some(color::WHITE);
+    title_text_style.color = Some(master.theme.base.text);
     title_text_style.font_size = Some(14);
 
     // Insert text in canvas
     widget::text::Text::new(&APP_I18N.t("alarms-title"))
         .with_style(title_text_style)
-        .top_left_of(config.title_wrapper)
+        .top_start_of(master.direction, config.title_wrapper)
         .set(config.title, &mut master.ui);
 
     // Append all alarms?
     // Notice: only draw alarms box if there are active alarms
     if alarms_count > 0 {
-        for x in 0..alarms_count {
-            let (code, alarm) = config.alarms.get(x).unwrap();
+        for (x, (code, alarm)) in config.alarms.iter().enumerate() {
+            alarm::render(master, &config, *code, alarm, false, x);
+        }
 
-            alarm::render(master, &config, *code, alarm, x);
+        for (x, (code, alarm)) in config.latched_alarms.iter().enumerate() {
+            alarm::render(master, &config, *code, alarm, true, config.alarms.len() + x);
         }
     } else {
         widget::text::Text::new(&APP_I18N.t("alarms-empty"))
-            .color(Color::Rgba(1.0, 1.0, 1.0, 0.5))
+            .color(master.theme.base.text.alpha(0.5))
             .font_size(12)
-            .right_from(
+            .end_from(
+                master.direction,
                 config.title_wrapper,
                 DISPLAY_ALARM_CONTAINER_TITLE_TO_ALARM_EMPTY_SPACING,
             )
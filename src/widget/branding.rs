@@ -38,7 +38,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     let branding_text = format!("F{} | C{}", config.version_firmware, config.version_control);
 
     widget::Text::new(&branding_text)
-        .color(color::WHITE.with_alpha(0.45))
+        .color(master.theme.base.text.with_alpha(0.45))
         .top_left_with_margins_on(
             config.parent,
             BRANDING_TEXT_MARGIN_TOP,
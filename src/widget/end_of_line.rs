@@ -6,7 +6,7 @@
 use std::cmp::Ordering;
 
 use conrod_core::{
-    color::{self, Color},
+    color,
     widget::{self, id::List, Id as WidgetId},
     Positionable, Sizeable, Widget,
 };
@@ -15,14 +15,6 @@ use crate::config::environment::*;
 use crate::display::widget::ControlWidget;
 use crate::APP_I18N;
 
-const CONTENT_BOX_DEFAULT_BORDER_COLOR: Color =
-    Color::Rgba(42.0 / 255.0, 42.0 / 255.0, 42.0 / 255.0, 1.0);
-const CONTENT_BOX_ERROR_BORDER_COLOR: Color =
-    Color::Rgba(219.0 / 255.0, 16.0 / 255.0, 16.0 / 255.0, 1.0);
-const CONTENT_BOX_SUCCESS_BORDER_COLOR: Color =
-    Color::Rgba(6.0 / 255.0, 174.0 / 255.0, 33.0 / 255.0, 1.0);
-const CONTENT_DETAILS_BOX_BORDER_COLOR: Color = Color::Rgba(1.0, 1.0, 1.0, 0.3);
-
 pub struct Config<'a> {
     pub title_wrapper: WidgetId,
     pub title_separator: WidgetId,
@@ -91,7 +83,7 @@ fn title_wrapper<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     gen_widget_container!(
         master,
         container_id: config.title_wrapper,
-        color: color::RED,
+        color: master.theme.end_of_line.title_background,
         width: END_OF_LINE_TITLE_WIDTH,
         height: END_OF_LINE_TITLE_HEIGHT,
         positions: top_left[]
@@ -101,7 +93,7 @@ fn title_wrapper<'a>(master: &mut ControlWidget<'a>, config: &Config) {
 fn title_separator<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     widget::Rectangle::fill_with(
         [1.0, (END_OF_LINE_TITLE_FONT_SIZE + 4) as _],
-        color::WHITE.alpha(0.15),
+        master.theme.end_of_line.title_separator,
     )
     .middle_of(config.title_wrapper)
     .y_relative(1.0)
@@ -113,7 +105,7 @@ fn title_primary<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::WHITE);
+    text_style.color = Some(master.theme.end_of_line.title_primary);
     text_style.font_size = Some(END_OF_LINE_TITLE_FONT_SIZE);
 
     // Create text
@@ -129,7 +121,7 @@ fn title_secondary<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.regular));
-    text_style.color = Some(color::WHITE.alpha(0.65));
+    text_style.color = Some(master.theme.end_of_line.title_secondary);
     text_style.font_size = Some(END_OF_LINE_TITLE_FONT_SIZE);
 
     // Create text
@@ -177,7 +169,7 @@ fn steps_progress<'a>(master: &mut ControlWidget<'a>, config: &Config) {
                     END_OF_LINE_STEPS_CIRCLE_MARGIN_RIGHT,
                     END_OF_LINE_STEPS_PROGRESS_HEIGHT,
                 ],
-                color::WHITE.alpha(0.075),
+                master.theme.end_of_line.step_progress,
             )
             .mid_left_with_margin_on(
                 config.steps_items,
@@ -202,15 +194,18 @@ fn steps_circles<'a>(master: &mut ControlWidget<'a>, config: &Config) {
 
         // Step is active?
         if current_step == ongoing_step {
-            widget::primitive::shape::circle::Circle::fill_with(circle_radius, color::WHITE)
-                .top_left_with_margins_on(config.steps_items, 0.0, margin_left)
-                .set(*steps_circle, &mut master.ui);
+            widget::primitive::shape::circle::Circle::fill_with(
+                circle_radius,
+                master.theme.end_of_line.step_active,
+            )
+            .top_left_with_margins_on(config.steps_items, 0.0, margin_left)
+            .set(*steps_circle, &mut master.ui);
         } else {
             let line_style = widget::primitive::line::Style::solid()
                 .color(if current_step > ongoing_step {
-                    color::WHITE.alpha(0.02)
+                    master.theme.end_of_line.step_pending
                 } else {
-                    color::WHITE.alpha(0.45)
+                    master.theme.end_of_line.step_done
                 })
                 .thickness(END_OF_LINE_STEPS_CIRCLE_THICKNESS);
 
@@ -232,9 +227,9 @@ fn steps_indexes<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         text_style.font_id = Some(Some(master.fonts.bold));
 
         text_style.color = Some(match current_step.cmp(&ongoing_step) {
-            Ordering::Greater => color::WHITE.alpha(0.1),
-            Ordering::Less => color::WHITE,
-            Ordering::Equal => color::BLACK,
+            Ordering::Greater => master.theme.end_of_line.index_pending,
+            Ordering::Less => master.theme.end_of_line.index_done,
+            Ordering::Equal => master.theme.end_of_line.index_active,
         });
 
         text_style.font_size = Some(END_OF_LINE_STEPS_INDEX_FONT_SIZE);
@@ -268,16 +263,16 @@ fn content_box<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     container_style.color = Some(if config.error || config.success {
         color::TRANSPARENT
     } else {
-        color::WHITE.alpha(0.01)
+        master.theme.end_of_line.content_background
     });
     container_style.border = Some(2.0);
 
     container_style.border_color = Some(if config.error {
-        CONTENT_BOX_ERROR_BORDER_COLOR
+        master.theme.end_of_line.content_border_error
     } else if config.success {
-        CONTENT_BOX_SUCCESS_BORDER_COLOR
+        master.theme.end_of_line.content_border_success
     } else {
-        CONTENT_BOX_DEFAULT_BORDER_COLOR
+        master.theme.end_of_line.content_border_default
     });
 
     // Create container
@@ -318,7 +313,7 @@ fn content_text_title<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::WHITE);
+    text_style.color = Some(master.theme.end_of_line.content_title);
     text_style.font_size = Some(END_OF_LINE_CONTENT_TEXT_TITLE_FONT_SIZE);
 
     // Create text
@@ -333,7 +328,7 @@ fn content_text_message<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.regular));
-    text_style.color = Some(color::WHITE.alpha(0.5));
+    text_style.color = Some(master.theme.end_of_line.content_message);
     text_style.font_size = Some(END_OF_LINE_CONTENT_TEXT_MESSAGE_FONT_SIZE);
 
     // Create text
@@ -351,10 +346,10 @@ fn content_details_box<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         // Initialize container style
         let mut container_style = widget::canvas::Style::default();
 
-        container_style.color = Some(color::BLACK);
+        container_style.color = Some(master.theme.end_of_line.details_background);
         container_style.border = Some(1.0);
 
-        container_style.border_color = Some(CONTENT_DETAILS_BOX_BORDER_COLOR);
+        container_style.border_color = Some(master.theme.end_of_line.details_border);
 
         // Create container
         widget::Canvas::new()
@@ -377,7 +372,7 @@ fn content_details_text<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
         text_style.font_id = Some(Some(master.fonts.regular));
-        text_style.color = Some(color::WHITE);
+        text_style.color = Some(master.theme.end_of_line.details_text);
         text_style.font_size = Some(END_OF_LINE_CONTENT_DETAILS_TEXT_FONT_SIZE);
 
         // Create text
@@ -396,7 +391,7 @@ fn content_button<'a>(master: &mut ControlWidget<'a>, config: &Config) {
             master,
             button_id: config.content_button,
             text_id: config.content_button_text,
-            text_color: color::BLACK,
+            text_color: master.theme.base.button_text,
             text_font_size: END_OF_LINE_CONTENT_BUTTON_FONT_SIZE,
             width: END_OF_LINE_CONTENT_BUTTON_WIDTH,
             value_top: END_OF_LINE_CONTENT_BUTTON_VALUE_TOP,
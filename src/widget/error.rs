@@ -77,7 +77,7 @@ fn text_title<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::WHITE);
+    text_style.color = Some(master.theme.base.text);
     text_style.font_size = Some(ERROR_TITLE_FONT_SIZE);
 
     // Create text
@@ -92,7 +92,7 @@ fn text_message<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.regular));
-    text_style.color = Some(color::WHITE.with_alpha(0.5));
+    text_style.color = Some(master.theme.base.text.with_alpha(0.5));
     text_style.font_size = Some(ERROR_MESSAGE_FONT_SIZE);
 
     // Create text
@@ -11,32 +11,28 @@ use conrod_core::{
     widget::{self, Id as WidgetId},
     Positionable, Sizeable, Widget,
 };
-use makair_telemetry::structures::MachineStateSnapshot;
+use makair_telemetry::structures::{MachineStateSnapshot, VentilationModeClass};
 use plotters::prelude::*;
 use plotters::style::{Color, ShapeStyle, TextStyle};
 use plotters_conrod::{ConrodBackend, ConrodBackendReusableGraph};
 
-use crate::chip::{ChipData, ChipState};
+use crate::chip::{
+    freeze::ChipFreeze, settings::mode::SettingsModeSettings, ChipData, ChipDataEventKind,
+    ChipState,
+};
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
-use crate::APP_I18N;
-
-const GRAPH_PRESSURE_LINE_COLOR: RGBColor = plotters::style::RGBColor(0, 196, 255);
-const GRAPH_FLOW_LINE_COLOR: RGBColor = plotters::style::RGBColor(196, 37, 20);
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
+use crate::locale::graph::data_event_kind_to_locale;
+use crate::locale::recorder::event_kind_to_locale;
+use crate::serial::recorder::RecordEvent;
+use crate::utilities::units::{flow_unit_ratio, pressure_unit_ratio};
+use crate::{APP_I18N, APP_SETTINGS};
 
-const GRAPH_MESH_AXIS_COLOR_RGB: RGBColor = plotters::style::RGBColor(255, 255, 255);
 const GRAPH_MESH_AXIS_COLOR_ALPHA: f64 = 0.5;
-
-const GRAPH_MESH_BOLD_COLOR_RGB: RGBColor = plotters::style::RGBColor(255, 255, 255);
 const GRAPH_MESH_BOLD_COLOR_ALPHA: f64 = 0.22;
-
-const GRAPH_MESH_LIGHT_COLOR_RGB: RGBColor = plotters::style::RGBColor(0, 0, 0);
-
-const GRAPH_AXIS_Y_FONT_COLOR_RGB: RGBColor = plotters::style::RGBColor(255, 255, 255);
 const GRAPH_AXIS_Y_FONT_COLOR_ALPHA: f64 = 0.75;
-
-const GRAPH_SATURATE_COLOR: color::Color =
-    color::Color::Rgba(184.0 / 255.0, 1.0 / 255.0, 24.0 / 255.0, 1.0);
+const GRAPH_MARKER_COLOR_ALPHA: f64 = 0.6;
+const GRAPH_LIMIT_COLOR_ALPHA: f64 = 0.7;
 
 pub struct Config<'a> {
     pub width: f64,
@@ -56,6 +52,9 @@ pub struct Config<'a> {
     pub pressure_saturate_ids: (WidgetId, WidgetId),
     pub flow_saturate_ids: (WidgetId, WidgetId),
 
+    pub freeze_ids: (WidgetId, WidgetId, WidgetId, WidgetId),
+    pub cursor_ids: (WidgetId, WidgetId, WidgetId, WidgetId),
+
     pub boot_time: Option<DateTime<Utc>>,
     pub last_tick: Option<u64>,
 
@@ -64,6 +63,11 @@ pub struct Config<'a> {
 
     pub chip_state: &'a ChipState,
     pub machine_snapshot: &'a MachineStateSnapshot,
+    pub mode_settings: &'a SettingsModeSettings,
+
+    pub replay_events: &'a [RecordEvent],
+    pub freeze: Option<&'a ChipFreeze>,
+    pub freeze_save: bool,
 
     pub plot_graphs: &'a mut (ConrodBackendReusableGraph, ConrodBackendReusableGraph),
 }
@@ -72,13 +76,19 @@ struct PlotContext<'a, 'b> {
     value_range: Range<i32>,
     plot_id: WidgetId,
     precision_divide: i32,
+    unit_ratio: f64,
     line_color: &'a RGBColor,
     data_values: &'b ChipData,
+    markers: &'b [(DateTime<Utc>, String)],
+    glyphs: &'b [DateTime<Utc>],
+    limits: &'b [(i32, &'a RGBColor)],
 }
 
 lazy_static! {
     static ref GRAPH_AXIS_Y_FONT: TextStyle<'static> =
         TextStyle::from(("sans-serif", GRAPH_DRAW_AXIS_FONT_SIZE).into_font());
+    static ref GRAPH_MARKER_FONT: TextStyle<'static> =
+        TextStyle::from(("sans-serif", GRAPH_DRAW_MARKER_FONT_SIZE).into_font());
 }
 
 pub fn render<'a>(master: &mut ControlWidget<'a>, mut config: Config<'a>) -> f64 {
@@ -89,7 +99,8 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, mut config: Config<'a>) -> f64
         color: color::TRANSPARENT,
         width: config.width as _,
         height: config.height as _,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.parent,
         ]
     );
@@ -101,8 +112,9 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, mut config: Config<'a>) -> f64
     // Notice: to prevent the graph from progressing in time periodically when stopped, use the \
     //   latest pressure data as a reference (will also be used for the flow graph in that case). \
     //   Using the last tick otherwise gives out much smoother graph results at high FPS, with \
-    //   less visual jitter.
-    let reference_time = if config.chip_state == &ChipState::Running {
+    //   less visual jitter. When frozen, the last tick at the time of the freeze is used, so \
+    //   that the cursor time matches with the drawn graph.
+    let reference_time = if config.chip_state == &ChipState::Running || config.freeze.is_some() {
         config.boot_time.map(|boot_time| {
             boot_time + chrono::Duration::microseconds(config.last_tick.unwrap_or(0) as i64)
         })
@@ -114,13 +126,94 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, mut config: Config<'a>) -> f64
             .map(|pressure| pressure.0)
     };
 
-    // Acquire common graph time range
+    // Acquire common graph time range (the range that was shown at the time of the freeze is \
+    //   kept while frozen, even if the graph time range setting was changed meanwhile)
+    let graph_seconds = config
+        .freeze
+        .map(|freeze| freeze.seconds)
+        .unwrap_or_else(|| APP_SETTINGS.read().unwrap().graph_seconds as i64);
+
     let newest_time = reference_time.unwrap_or_else(Utc::now);
-    let oldest_time = newest_time - chrono::Duration::seconds(GRAPH_DRAW_SECONDS);
+    let oldest_time = newest_time - chrono::Duration::seconds(graph_seconds);
+
+    // Acquire markers in graph time range (from replayed UI events)
+    let mut markers: Vec<(DateTime<Utc>, String)> = if let Some(boot_time) = config.boot_time {
+        config
+            .replay_events
+            .iter()
+            .filter_map(|event| {
+                let time = boot_time + chrono::Duration::microseconds(event.systick as i64);
+
+                if time >= oldest_time && time <= newest_time {
+                    Some((time, event_kind_to_locale(event.kind)))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // Append markers from data events (eg. acknowledged settings, fired alarms)
+    // Notice: events are stored along with data points, thus they scroll along with the data, \
+    //   and get frozen along with it as well. Triggered breaths are too frequent to be shown \
+    //   as labelled markers, thus they are drawn as glyphs instead.
+    let is_visible_event = |time: &DateTime<Utc>| *time >= oldest_time && *time <= newest_time;
+
+    markers.extend(
+        config
+            .data_pressure
+            .events
+            .iter()
+            .filter(|event| {
+                is_visible_event(&event.0) && event.1 != ChipDataEventKind::TriggeredBreath
+            })
+            .map(|event| (event.0, data_event_kind_to_locale(event.1))),
+    );
+
+    let glyphs: Vec<DateTime<Utc>> = config
+        .data_pressure
+        .events
+        .iter()
+        .filter(|event| is_visible_event(&event.0) && event.1 == ChipDataEventKind::TriggeredBreath)
+        .map(|event| event.0)
+        .collect();
+
+    // Acquire flow markers (drawn without their labels, which are already shown on the \
+    //   pressure graph right above)
+    let flow_markers: Vec<(DateTime<Utc>, String)> = config
+        .data_flow
+        .events
+        .iter()
+        .filter(|event| is_visible_event(&event.0) && event.1 != ChipDataEventKind::TriggeredBreath)
+        .map(|event| (event.0, String::new()))
+        .collect();
 
     // Draw plots
-    pressure(master, &mut config, size, oldest_time..newest_time);
-    flow(master, &mut config, size, oldest_time..newest_time);
+    pressure(
+        master,
+        &mut config,
+        size,
+        oldest_time..newest_time,
+        &markers,
+        &glyphs,
+    );
+    flow(
+        master,
+        &mut config,
+        size,
+        oldest_time..newest_time,
+        &flow_markers,
+    );
+
+    // Draw cursor? (only if frozen)
+    if let Some(freeze) = config.freeze {
+        cursor(master, &config, freeze);
+    }
+
+    // Draw freeze controls (on top of everything else)
+    freeze_controls(master, &config);
 
     config.width
 }
@@ -130,7 +223,11 @@ fn pressure<'a>(
     config: &mut Config<'a>,
     size: (f64, f64),
     time_range: Range<DateTime<Utc>>,
+    markers: &[(DateTime<Utc>, String)],
+    glyphs: &[DateTime<Utc>],
 ) {
+    let (pressure_unit, theme) = (APP_SETTINGS.read().unwrap().pressure_unit, master.theme);
+
     // Create pressure container
     gen_widget_container!(
         master,
@@ -138,11 +235,41 @@ fn pressure<'a>(
         color: color::TRANSPARENT,
         width: size.0,
         height: size.1,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.wrapper_id,
         ]
     );
 
+    // Acquire pressure limits (only relevant while running)
+    // Notice: pressure points are expressed in mmH2O, as well as pressure settings.
+    let limits = if config.chip_state == &ChipState::Running {
+        let mode_settings = config.mode_settings;
+
+        let mut limits = vec![
+            (
+                mode_settings.alarm_threshold_peak_pressure as i32,
+                &theme.graph.limit_alarm,
+            ),
+            (
+                mode_settings.pressure_expiratory as i32,
+                &theme.graph.limit_command,
+            ),
+        ];
+
+        // The plateau pressure command is only used in pressure-controlled modes
+        if mode_settings.mode.class() == VentilationModeClass::Pressure {
+            limits.push((
+                mode_settings.pressure_plateau as i32,
+                &theme.graph.limit_command,
+            ));
+        }
+
+        limits
+    } else {
+        Vec::new()
+    };
+
     // Draw pressure plot
     plot(
         master,
@@ -154,8 +281,12 @@ fn pressure<'a>(
                 ..GRAPH_DRAW_PRESSURE_RANGE_HIGH_PRECISION_DIVIDED,
             plot_id: config.pressure_id,
             precision_divide: TELEMETRY_POINTS_PRESSURE_PRECISION_DIVIDE,
-            line_color: &GRAPH_PRESSURE_LINE_COLOR,
+            unit_ratio: pressure_unit_ratio(pressure_unit),
+            line_color: &theme.graph.pressure_line,
             data_values: &config.data_pressure,
+            markers,
+            glyphs,
+            limits: &limits,
         },
     );
 
@@ -165,7 +296,7 @@ fn pressure<'a>(
         config.pressure_id,
         config.pressure_label_box_id,
         config.pressure_label_text_id,
-        &APP_I18N.t("telemetry-unit-cmh2o"),
+        &APP_I18N.t(pressure_unit.to_locale_key()),
     );
 
     // Create saturate lines? (as needed)
@@ -186,7 +317,10 @@ fn flow<'a>(
     config: &mut Config<'a>,
     size: (f64, f64),
     time_range: Range<DateTime<Utc>>,
+    markers: &[(DateTime<Utc>, String)],
 ) {
+    let (flow_unit, theme) = (APP_SETTINGS.read().unwrap().flow_unit, master.theme);
+
     // Create flow container
     gen_widget_container!(
         master,
@@ -194,11 +328,25 @@ fn flow<'a>(
         color: color::TRANSPARENT,
         width: size.0,
         height: size.1,
-        positions: bottom_left_of[
+        positions: bottom_start_of[
+            master.direction,
             config.wrapper_id,
         ]
     );
 
+    // Acquire flow limits (only relevant while running in volume-controlled modes)
+    // Notice: flow points are expressed in cL/min, while the target flow setting is in L/min.
+    let limits = if config.chip_state == &ChipState::Running
+        && config.mode_settings.mode.class() == VentilationModeClass::Volume
+    {
+        vec![(
+            config.mode_settings.flow_inspiration as i32 * TELEMETRY_POINTS_FLOW_PRECISION_DIVIDE,
+            &theme.graph.limit_command,
+        )]
+    } else {
+        Vec::new()
+    };
+
     // Draw flow plot
     plot(
         master,
@@ -210,8 +358,12 @@ fn flow<'a>(
                 ..GRAPH_DRAW_FLOW_RANGE_HIGH_PRECISION_DIVIDED,
             plot_id: config.flow_id,
             precision_divide: TELEMETRY_POINTS_FLOW_PRECISION_DIVIDE,
-            line_color: &GRAPH_FLOW_LINE_COLOR,
+            unit_ratio: flow_unit_ratio(flow_unit),
+            line_color: &theme.graph.flow_line,
             data_values: &config.data_flow,
+            markers,
+            glyphs: &[],
+            limits: &limits,
         },
     );
 
@@ -221,7 +373,7 @@ fn flow<'a>(
         config.flow_id,
         config.flow_label_box_id,
         config.flow_label_text_id,
-        &APP_I18N.t("telemetry-unit-lpm"),
+        &APP_I18N.t(flow_unit.to_locale_key()),
     );
 
     // Create saturate lines? (as needed)
@@ -244,6 +396,8 @@ fn plot<'a>(
     plot_graph: &mut ConrodBackendReusableGraph,
     context: PlotContext,
 ) {
+    let theme = master.theme;
+
     // Create drawing backend
     let drawing = ConrodBackend::new(
         &mut master.ui,
@@ -254,6 +408,10 @@ fn plot<'a>(
     )
     .into_drawing_area();
 
+    // Acquire markers & limits ranges (before those get consumed by the chart)
+    let markers_range = (context.value_range.start, context.value_range.end);
+    let limits_range = (time_range.start, time_range.end);
+
     // Configure chart
     let mut chart = ChartBuilder::on(&drawing)
         .margin_top(GRAPH_DRAW_MARGIN_TOP)
@@ -266,26 +424,27 @@ fn plot<'a>(
         .expect("failed to build chart");
 
     // Configure mesh
-    let precision_divide = context.precision_divide;
+    let (precision_divide, unit_ratio) = (context.precision_divide, context.unit_ratio);
 
     chart
         .configure_mesh()
-        .bold_line_style(&GRAPH_MESH_BOLD_COLOR_RGB.mix(GRAPH_MESH_BOLD_COLOR_ALPHA))
-        .light_line_style(&GRAPH_MESH_LIGHT_COLOR_RGB)
+        .bold_line_style(&theme.graph.mesh_bold.mix(GRAPH_MESH_BOLD_COLOR_ALPHA))
+        .light_line_style(&theme.graph.mesh_light)
         .axis_style(ShapeStyle {
-            color: GRAPH_MESH_AXIS_COLOR_RGB.mix(GRAPH_MESH_AXIS_COLOR_ALPHA),
+            color: theme.graph.mesh_axis.mix(GRAPH_MESH_AXIS_COLOR_ALPHA),
             filled: true,
             stroke_width: GRAPH_DRAW_AXIS_SIZE,
         })
         .y_labels(GRAPH_DRAW_LABEL_NUMBER_MAX)
         .y_label_style(
-            GRAPH_AXIS_Y_FONT
-                .color(&GRAPH_AXIS_Y_FONT_COLOR_RGB.mix(GRAPH_AXIS_Y_FONT_COLOR_ALPHA)),
+            GRAPH_AXIS_Y_FONT.color(&theme.graph.axis_font.mix(GRAPH_AXIS_Y_FONT_COLOR_ALPHA)),
         )
         .y_label_formatter(&|y| {
             // Convert high-precision point to low-precision point (which measurements & targets \
-            //   both use), eg. cL to L or mmH2O to cmH2O.
-            (y / precision_divide).to_string()
+            //   both use), eg. cL to L or mmH2O to cmH2O, then to the display unit (if it differs)
+            ((y / precision_divide) as f64 * unit_ratio)
+                .round()
+                .to_string()
         })
         .draw()
         .expect("failed to draw chart mesh");
@@ -301,6 +460,230 @@ fn plot<'a>(
             .border_style(ShapeStyle::from(context.line_color).stroke_width(GRAPH_DRAW_LINE_SIZE)),
         )
         .expect("failed to draw chart data");
+
+    // Draw markers? (as vertical lines, with their label on top, if any)
+    if !context.markers.is_empty() {
+        let marker_color = theme.graph.marker.mix(GRAPH_MARKER_COLOR_ALPHA);
+
+        chart
+            .draw_series(context.markers.iter().map(|(time, _)| {
+                PathElement::new(
+                    vec![(*time, markers_range.0), (*time, markers_range.1)],
+                    ShapeStyle::from(&marker_color).stroke_width(GRAPH_DRAW_MARKER_SIZE),
+                )
+            }))
+            .expect("failed to draw chart markers");
+
+        chart
+            .draw_series(
+                context
+                    .markers
+                    .iter()
+                    .filter(|(_, label)| !label.is_empty())
+                    .map(|(time, label)| {
+                        Text::new(
+                            label.to_owned(),
+                            (*time, markers_range.1),
+                            GRAPH_MARKER_FONT.color(&marker_color),
+                        )
+                    }),
+            )
+            .expect("failed to draw chart markers labels");
+    }
+
+    // Draw glyphs? (as small triangles pointing up, at the bottom of the plot)
+    if !context.glyphs.is_empty() {
+        let glyph_value =
+            markers_range.0 + (markers_range.1 - markers_range.0) / GRAPH_DRAW_GLYPH_OFFSET_RATIO;
+
+        chart
+            .draw_series(context.glyphs.iter().map(|time| {
+                TriangleMarker::new(
+                    (*time, glyph_value),
+                    GRAPH_DRAW_GLYPH_SIZE,
+                    ShapeStyle::from(&theme.graph.glyph).filled(),
+                )
+            }))
+            .expect("failed to draw chart glyphs");
+    }
+
+    // Draw limits? (as dashed horizontal lines, made of as many segments as there are dashes)
+    if !context.limits.is_empty() {
+        let dash_duration = (limits_range.1 - limits_range.0) / (GRAPH_DRAW_LIMIT_DASHES * 2);
+
+        chart
+            .draw_series(context.limits.iter().flat_map(|&(value, color)| {
+                (0..GRAPH_DRAW_LIMIT_DASHES).map(move |index| {
+                    let dash_start = limits_range.0 + dash_duration * (index * 2);
+
+                    PathElement::new(
+                        vec![(dash_start, value), (dash_start + dash_duration, value)],
+                        ShapeStyle::from(&color.mix(GRAPH_LIMIT_COLOR_ALPHA))
+                            .stroke_width(GRAPH_DRAW_LIMIT_SIZE),
+                    )
+                })
+            }))
+            .expect("failed to draw chart limits");
+    }
+}
+
+fn cursor<'a>(master: &mut ControlWidget<'a>, config: &Config, freeze: &ChipFreeze) {
+    let (overlay_id, line_id, box_id, text_id) = config.cursor_ids;
+
+    // Create overlay over plot areas (which receives cursor drags)
+    // Notice: as plots are not mirrored, the cursor is laid out from the left whatever the \
+    //   locale direction.
+    let overlay_width = config.width - GRAPH_DRAW_LABEL_WIDTH as f64;
+
+    widget::Rectangle::fill_with(
+        [overlay_width, config.height],
+        master.theme.graph.cursor_overlay,
+    )
+    .top_right_of(config.wrapper_id)
+    .set(overlay_id, &mut master.ui);
+
+    // Create cursor line
+    let cursor_left = freeze.cursor * overlay_width;
+
+    widget::Rectangle::fill_with(
+        [GRAPH_CURSOR_LINE_THICKNESS, config.height],
+        master.theme.graph.cursor_line,
+    )
+    .top_left_with_margins_on(
+        overlay_id,
+        0.0,
+        (cursor_left - GRAPH_CURSOR_LINE_THICKNESS / 2.0)
+            .max(0.0)
+            .min(overlay_width - GRAPH_CURSOR_LINE_THICKNESS),
+    )
+    .set(line_id, &mut master.ui);
+
+    // Create values box (follows the cursor, but always stays within the overlay)
+    widget::Rectangle::fill_with(
+        [GRAPH_CURSOR_BOX_WIDTH, GRAPH_CURSOR_BOX_HEIGHT],
+        master.theme.graph.cursor_box,
+    )
+    .top_left_with_margins_on(
+        overlay_id,
+        GRAPH_CURSOR_BOX_MARGIN_TOP,
+        (cursor_left - GRAPH_CURSOR_BOX_WIDTH / 2.0)
+            .max(0.0)
+            .min(overlay_width - GRAPH_CURSOR_BOX_WIDTH),
+    )
+    .set(box_id, &mut master.ui);
+
+    // Create values text
+    let (pressure, flow) = freeze.cursor_values();
+    let (pressure_unit, flow_unit) = {
+        let settings = APP_SETTINGS.read().unwrap();
+
+        (settings.pressure_unit, settings.flow_unit)
+    };
+
+    let format_value = |value: Option<i16>, precision_divide: i32, unit_ratio: f64| {
+        value
+            .map(|value| {
+                APP_I18N.format_number(value as f64 / precision_divide as f64 * unit_ratio, 1)
+            })
+            .unwrap_or_else(|| TELEMETRY_WIDGET_VALUE_EMPTY.to_string())
+    };
+
+    let mut text_style = conrod_core::widget::primitive::text::Style::default();
+
+    text_style.font_id = Some(Some(master.fonts.bold));
+    text_style.color = Some(master.theme.base.text);
+    text_style.font_size = Some(GRAPH_CURSOR_BOX_FONT_SIZE);
+
+    widget::Text::new(
+        &APP_I18N.t_args(
+            "graph-cursor-values",
+            &[
+                (
+                    "time",
+                    APP_I18N
+                        .format_number(freeze.cursor_offset_seconds(), 2)
+                        .into(),
+                ),
+                (
+                    "pressure",
+                    format_value(
+                        pressure,
+                        TELEMETRY_POINTS_PRESSURE_PRECISION_DIVIDE,
+                        pressure_unit_ratio(pressure_unit),
+                    )
+                    .into(),
+                ),
+                (
+                    "pressure-unit",
+                    APP_I18N.t(pressure_unit.to_locale_key()).into(),
+                ),
+                (
+                    "flow",
+                    format_value(
+                        flow,
+                        TELEMETRY_POINTS_FLOW_PRECISION_DIVIDE,
+                        flow_unit_ratio(flow_unit),
+                    )
+                    .into(),
+                ),
+                ("flow-unit", APP_I18N.t(flow_unit.to_locale_key()).into()),
+            ],
+        ),
+    )
+    .with_style(text_style)
+    .middle_of(box_id)
+    .set(text_id, &mut master.ui);
+}
+
+fn freeze_controls<'a>(master: &mut ControlWidget<'a>, config: &Config) {
+    let (freeze_button_id, freeze_text_id, save_button_id, save_text_id) = config.freeze_ids;
+
+    // Create freeze (or resume) button
+    // Notice: plots are not mirrored for right-to-left locales (time always flows from left to \
+    //   right, with axis labels on the left), thus controls laid over plots keep their side.
+    gen_widget_button!(
+        master,
+        button_id: freeze_button_id,
+        text_id: freeze_text_id,
+        text_color: master.theme.base.button_text,
+        text_font_size: GRAPH_FREEZE_BUTTON_FONT_SIZE,
+        width: GRAPH_FREEZE_BUTTON_WIDTH,
+        value_top: GRAPH_FREEZE_BUTTON_VALUE_TOP,
+        value: &APP_I18N.t(if config.freeze.is_some() {
+            "graph-resume"
+        } else {
+            "graph-freeze"
+        }),
+
+        positions: (
+            top_right_with_margins_on[
+                config.wrapper_id,
+                GRAPH_FREEZE_BUTTON_MARGIN,
+                GRAPH_FREEZE_BUTTON_MARGIN,
+            ]
+        )
+    );
+
+    // Create save button? (only if frozen, and if the session is being recorded)
+    if config.freeze.is_some() && config.freeze_save {
+        gen_widget_button!(
+            master,
+            button_id: save_button_id,
+            text_id: save_text_id,
+            text_color: master.theme.base.button_text,
+            text_font_size: GRAPH_FREEZE_BUTTON_FONT_SIZE,
+            width: GRAPH_FREEZE_BUTTON_WIDTH,
+            value_top: GRAPH_FREEZE_BUTTON_VALUE_TOP,
+            value: &APP_I18N.t("graph-freeze-save"),
+
+            positions: (
+                left_from[
+                    freeze_button_id,
+                    GRAPH_FREEZE_BUTTON_MARGIN,
+                ]
+            )
+        );
+    }
 }
 
 fn label<'a>(
@@ -314,7 +697,7 @@ fn label<'a>(
     gen_widget_container!(
         master,
         container_id: box_id,
-        color: color::BLACK,
+        color: master.theme.graph.label_background,
         width: GRAPH_LABEL_BOX_WIDTH,
         height: GRAPH_LABEL_BOX_HEIGHT,
         positions: top_left_of[
@@ -326,7 +709,7 @@ fn label<'a>(
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::WHITE);
+    text_style.color = Some(master.theme.base.text);
     text_style.font_size = Some(GRAPH_LABEL_BOX_FONT_SIZE);
 
     widget::Text::new(text)
@@ -354,7 +737,7 @@ fn saturate<'a>(
                 size.0 - GRAPH_DRAW_LABEL_WIDTH as f64,
                 GRAPH_SATURATE_LINE_THICKNESS,
             ],
-            GRAPH_SATURATE_COLOR,
+            master.theme.graph.saturate,
         )
         .bottom_left_with_margins_on(
             parent_id,
@@ -374,7 +757,7 @@ fn saturate<'a>(
                 size.0 - GRAPH_DRAW_LABEL_WIDTH as f64,
                 GRAPH_SATURATE_LINE_THICKNESS,
             ],
-            GRAPH_SATURATE_COLOR,
+            master.theme.graph.saturate,
         )
         .top_left_with_margins_on(parent_id, -1.0, GRAPH_DRAW_LABEL_WIDTH as _)
         .set(saturate_ids.1, &mut master.ui);
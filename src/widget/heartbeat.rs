@@ -6,7 +6,6 @@
 use std::cmp::{max, min};
 
 use conrod_core::{
-    color::{self, Color},
     widget::{self, Id as WidgetId},
     Positionable, Widget,
 };
@@ -17,17 +16,6 @@ use crate::config::environment::*;
 use crate::display::widget::ControlWidget;
 use crate::utilities::pressure::process_max_allowed_pressure;
 
-const SURROUND_CIRCLE_COLOR: Color = Color::Rgba(153.0 / 255.0, 153.0 / 255.0, 153.0 / 255.0, 1.0);
-
-const INNER_CIRCLE_COLOR_DEFAULT: Color = color::WHITE;
-const INNER_CIRCLE_COLOR_OVERFLOW: Color =
-    Color::Rgba(184.0 / 255.0, 1.0 / 255.0, 24.0 / 255.0, 1.0);
-
-const GROUND_CIRCLE_COLOR_DEFAULT: Color =
-    Color::Rgba(116.0 / 255.0, 116.0 / 255.0, 116.0 / 255.0, 1.0);
-const GROUND_CIRCLE_COLOR_OVERFLOW: Color =
-    Color::Rgba(204.0 / 255.0, 204.0 / 255.0, 204.0 / 255.0, 1.0);
-
 pub struct Config<'a> {
     pub mode: VentilationMode,
     pub data_pressure: &'a ChipData,
@@ -49,7 +37,7 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
 
     // #1: Create surround circle
     let surround_line_style = widget::primitive::line::Style::solid()
-        .color(SURROUND_CIRCLE_COLOR)
+        .color(master.theme.heartbeat.surround)
         .thickness(HEARTBEAT_SURROUND_THICKNESS);
 
     widget::primitive::shape::circle::Circle::outline_styled(surround_radius, surround_line_style)
@@ -92,9 +80,9 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
     ) as f64;
 
     let inner_color = if last_pressure_radius >= surround_radius {
-        INNER_CIRCLE_COLOR_OVERFLOW
+        master.theme.heartbeat.inner_overflow
     } else {
-        INNER_CIRCLE_COLOR_DEFAULT
+        master.theme.heartbeat.inner_default
     };
 
     // Draw circle for the current pressure (normal, or exceeding alert threshold)
@@ -104,9 +92,9 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
 
     // #3: Create ground circle
     let ground_color = if last_pressure_radius >= surround_radius {
-        GROUND_CIRCLE_COLOR_OVERFLOW
+        master.theme.heartbeat.ground_overflow
     } else {
-        GROUND_CIRCLE_COLOR_DEFAULT
+        master.theme.heartbeat.ground_default
     };
 
     widget::primitive::shape::circle::Circle::fill_with(ground_radius, ground_color)
@@ -4,7 +4,7 @@
 // License: Public Domain License
 
 use conrod_core::{
-    color::{self, Color},
+    color,
     widget::{self, Id as WidgetId},
     Positionable, Sizeable, Widget,
 };
@@ -13,8 +13,6 @@ use crate::config::environment::*;
 use crate::display::widget::ControlWidget;
 use crate::APP_I18N;
 
-const TEXT_COLOR: Color = Color::Rgba(1.0, 1.0, 1.0, 0.2);
-
 pub struct Config {
     pub container: WidgetId,
     pub logo: WidgetId,
@@ -56,7 +54,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.regular));
-    text_style.color = Some(TEXT_COLOR);
+    text_style.color = Some(master.theme.base.text.alpha(0.2));
     text_style.font_size = Some(INITIALIZING_MESSAGE_FONT_SIZE);
 
     // Create text
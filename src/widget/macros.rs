@@ -41,7 +41,7 @@ macro_rules! gen_widget_button {
         positions: ($($position_call:tt[$($position_arguments:expr,)*]),+)
     ) => {
         // Initialize button style
-        let button_style = widget::primitive::shape::Style::Fill(Some(color::WHITE));
+        let button_style = widget::primitive::shape::Style::Fill(Some($master.theme.base.button));
 
         // Create rectangle for button
         widget::RoundedRectangle::styled(
@@ -80,7 +80,7 @@ macro_rules! gen_widget_label_styled {
         let mut text_style = widget::text::Style::default();
 
         text_style.font_id = Some(Some($master.fonts.$font_weight));
-        text_style.color = Some(color::WHITE);
+        text_style.color = Some($master.theme.base.text);
         text_style.font_size = Some($font_size);
 
         // Create text
@@ -127,9 +127,9 @@ macro_rules! gen_widget_text {
             $master.fonts.regular
         }));
         value_style.color = Some(if $changed {
-            Color::Rgba(110.0 / 255.0, 191.0 / 255.0, 255.0 / 255.0, 1.0)
+            $master.theme.base.text_changed
         } else {
-            color::WHITE
+            $master.theme.base.text
         });
         value_style.font_size = Some(MODAL_TEXT_FONT_SIZE);
 
@@ -160,7 +160,7 @@ macro_rules! gen_widget_button_navigate {
             $master,
             button_id: $button_less_id,
             text_id: $button_less_text_id,
-            text_color: color::BLACK,
+            text_color: $master.theme.base.button_text,
             text_font_size: MODAL_BUTTON_NAVIGATE_FONT_SIZE,
             width: MODAL_BUTTON_NAVIGATE_WIDTH,
             value_top: MODAL_BUTTON_NAVIGATE_VALUE_MARGIN_TOP,
@@ -175,8 +175,8 @@ macro_rules! gen_widget_button_navigate {
             color: color::TRANSPARENT,
             width: MODAL_BUTTON_NAVIGATE_VALUE_WIDTH,
             height: MODAL_BUTTON_NAVIGATE_VALUE_HEIGHT,
-            positions: right_from[
-                $button_less_id, MODAL_BUTTON_NAVIGATE_PADDING_INNER,
+            positions: end_from[
+                $master.direction, $button_less_id, MODAL_BUTTON_NAVIGATE_PADDING_INNER,
             ]
         );
 
@@ -197,14 +197,15 @@ macro_rules! gen_widget_button_navigate {
             $master,
             button_id: $button_more_id,
             text_id: $button_more_text_id,
-            text_color: color::BLACK,
+            text_color: $master.theme.base.button_text,
             text_font_size: MODAL_BUTTON_NAVIGATE_FONT_SIZE,
             width: MODAL_BUTTON_NAVIGATE_WIDTH,
             value_top: MODAL_BUTTON_NAVIGATE_VALUE_MARGIN_TOP,
             value: MODAL_BUTTON_NAVIGATE_VALUE_INCREASE,
 
             positions: (
-                right_from[
+                end_from[
+                    $master.direction,
                     $value_wrapper_id,
                     MODAL_BUTTON_NAVIGATE_PADDING_INNER,
                 ],
@@ -230,7 +231,8 @@ macro_rules! gen_widget_group {
             color: color::TRANSPARENT,
             width: MODAL_GROUP_TABS_WIDTH,
             height: $height,
-            positions: top_left_of[
+            positions: top_start_of[
+                $master.direction,
                 $parent_id,
             ]
         );
@@ -251,14 +253,14 @@ macro_rules! gen_widget_group_tab {
         // Acquire button colors
         let (color_button, color_text) = (
             if $tab_active == $tab_current {
-                Color::Rgba(1.0, 1.0, 1.0, 1.0)
+                $master.theme.base.button
             } else {
-                Color::Rgba(48.0 / 255.0, 48.0 / 255.0, 48.0 / 255.0, 1.0)
+                $master.theme.modal.tab_inactive
             },
             if $tab_active == $tab_current {
-                color::BLACK
+                $master.theme.base.button_text
             } else {
-                color::WHITE
+                $master.theme.base.text
             },
         );
 
@@ -268,7 +270,8 @@ macro_rules! gen_widget_group_tab {
             MODAL_GROUP_TABS_BORDER_RADIUS,
             color_button,
         )
-        .top_left_with_margins_on(
+        .top_start_with_margins_on(
+            $master.direction,
             $group_id,
             $index as f64 * (MODAL_GROUP_TABS_HEIGHT + MODAL_GROUP_TABS_MARGIN_TOP),
             0.0,
@@ -23,8 +23,10 @@ pub mod mode_overview;
 pub mod mode_settings;
 pub mod preset_settings;
 pub mod run_settings;
+pub mod settings_notice;
 pub mod snooze_settings;
 pub mod status;
 pub mod stop;
 pub mod telemetry_container;
+pub mod telemetry_stale;
 pub mod telemetry_view;
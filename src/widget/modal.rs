@@ -12,14 +12,9 @@ use conrod_core::{
 };
 
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::APP_I18N;
 
-const CANVAS_COLOR: Color = Color::Rgba(0.0, 0.0, 0.0, 0.8);
-
-const CONTAINER_BORDER_COLOR: Color = Color::Rgba(81.0 / 255.0, 81.0 / 255.0, 81.0 / 255.0, 1.0);
-const CONTAINER_COLOR: Color = Color::Rgba(26.0 / 255.0, 26.0 / 255.0, 26.0 / 255.0, 1.0);
-
 pub struct Config {
     pub parent: WidgetId,
     pub background: WidgetId,
@@ -41,7 +36,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     gen_widget_container!(
         master,
         container_id: config.background,
-        color: CANVAS_COLOR,
+        color: master.theme.modal.overlay,
         width: config.background_sizes.map(|sizes| {
             sizes.0 + MODAL_SIZE_ADJUST_OVERFLOW
         }).unwrap_or(DISPLAY_WINDOW_SIZE_WIDTH) as _,
@@ -58,7 +53,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
         config
             .colors
             .map(|colors| colors.1)
-            .or(Some(CONTAINER_BORDER_COLOR)),
+            .or(Some(master.theme.modal.border)),
     );
 
     // Create rectangle for borders
@@ -76,7 +71,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     container_style.color = config
         .colors
         .map(|colors| colors.0)
-        .or(Some(CONTAINER_COLOR));
+        .or(Some(master.theme.modal.background));
     container_style.border = Some(0.0);
     container_style.border_color = Some(color::TRANSPARENT);
 
@@ -100,9 +95,9 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
             button_id: close_button,
             text_id: close_text,
             text_color: if config.save.is_some() {
-                color::RED
+                master.theme.base.button_cancel
             } else {
-                color::BLACK
+                master.theme.base.button_text
             },
             text_font_size: MODAL_FINALIZE_BUTTON_FONT_SIZE,
             width: MODAL_FINALIZE_BUTTON_WIDTH,
@@ -114,7 +109,8 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
             }),
 
             positions: (
-                bottom_right_of[
+                bottom_end_of[
+                    master.direction,
                     config.container,
                 ]
             )
@@ -127,14 +123,15 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
                 master,
                 button_id: save_button,
                 text_id: save_text,
-                text_color: color::BLUE,
+                text_color: master.theme.base.button_apply,
                 text_font_size: MODAL_FINALIZE_BUTTON_FONT_SIZE,
                 width: MODAL_FINALIZE_BUTTON_WIDTH,
                 value_top: MODAL_FINALIZE_BUTTON_VALUE_TOP,
                 value: &APP_I18N.t("modal-apply"),
 
                 positions: (
-                    bottom_right_with_margins_on[
+                    bottom_end_with_margins_on[
+                        master.direction,
                         close_button,
                         0.0,
                         MODAL_FINALIZE_BUTTON_WIDTH + MODAL_FINALIZE_BUTTON_MARGIN_RIGHT,
@@ -4,7 +4,7 @@
 // License: Public Domain License
 
 use conrod_core::{
-    color::{self, Color},
+    color::Color,
     widget::{self, Id as WidgetId},
     Positionable, Widget,
 };
@@ -17,11 +17,6 @@ use crate::locale::modes::{
     class_to_locale as mode_class_to_locale, kind_to_locale as mode_kind_to_locale,
 };
 
-const CLASS_PRESSURE_COLOR: color::Color =
-    color::Color::Rgba(14.0 / 255.0, 112.0 / 255.0, 182.0 / 255.0, 1.0);
-const CLASS_FLOW_COLOR: color::Color =
-    color::Color::Rgba(150.0 / 255.0, 20.0 / 255.0, 6.0 / 255.0, 1.0);
-
 pub struct Config<'a> {
     pub background_color: Color,
 
@@ -58,7 +53,7 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
 fn separator<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     widget::Rectangle::fill_with(
         [1.0, TELEMETRY_WIDGET_RIGHT_MODE_FONT_SIZE as _],
-        color::BLACK.alpha(0.65),
+        master.theme.mode.overview_text.alpha(0.65),
     )
     .middle_of(config.container)
     .x_relative(11.0)
@@ -74,8 +69,8 @@ fn class<'a>(master: &mut ControlWidget<'a>, config: &Config) {
 
     text_style.font_id = Some(Some(master.fonts.bold));
     text_style.color = Some(match mode_class {
-        VentilationModeClass::Pressure => CLASS_PRESSURE_COLOR,
-        VentilationModeClass::Volume => CLASS_FLOW_COLOR,
+        VentilationModeClass::Pressure => master.theme.mode.class_pressure,
+        VentilationModeClass::Volume => master.theme.mode.class_volume,
     });
     text_style.font_size = Some(TELEMETRY_WIDGET_RIGHT_MODE_FONT_SIZE);
 
@@ -95,7 +90,7 @@ fn kind<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = conrod_core::widget::primitive::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::BLACK);
+    text_style.color = Some(master.theme.mode.overview_text);
     text_style.font_size = Some(TELEMETRY_WIDGET_RIGHT_MODE_FONT_SIZE);
 
     // Create text
@@ -4,7 +4,7 @@
 // License: Public Domain License
 
 use conrod_core::{
-    color::{self, Color},
+    color,
     widget::{self, Id as WidgetId},
     Positionable, Sizeable, Widget,
 };
@@ -12,20 +12,16 @@ use makair_telemetry::structures::VentilationMode;
 
 use crate::chip::settings::mode::{SettingsMode, SettingsModeGroupTab};
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::locale::modes::{
     class_to_locale as mode_class_to_locale, group_tab_to_locale as mode_group_tab_to_locale,
     kind_to_locale as mode_kind_to_locale,
 };
-use crate::utilities::units::{convert_cl_to_ml, convert_mmh2o_to_cmh2o, ConvertMode};
-use crate::APP_I18N;
-
-const SELECTOR_BORDER_COLOR: Color = Color::Rgba(81.0 / 255.0, 81.0 / 255.0, 81.0 / 255.0, 1.0);
-
-const SELECTOR_COLOR_DEFAULT: Color = Color::Rgba(0.0, 0.0, 0.0, 0.975);
-const SELECTOR_COLOR_SELECTED: Color = Color::Rgba(26.0 / 255.0, 26.0 / 255.0, 26.0 / 255.0, 1.0);
-
-const SELECTOR_TEXT_COLOR_CHANGED: Color = Color::Rgba(110.0 / 255.0, 191.0 / 255.0, 1.0, 1.0);
+use crate::utilities::units::{
+    convert_cl_to_ml, convert_lpm_to_flow_unit, convert_ml_to_volume_unit,
+    convert_mmh2o_to_pressure_unit, ConvertMode,
+};
+use crate::{APP_I18N, APP_SETTINGS};
 
 type FieldWidgetIds = (
     WidgetId,
@@ -73,12 +69,19 @@ pub struct Config<'a> {
     pub field_alarm_threshold_leak_ids: FieldWidgetIds,
     pub field_alarm_threshold_peak_pressure_ids: FieldWidgetIds,
 
+    pub leak_measured: WidgetId,
+    pub leak: Option<(f64, f64)>,
+
     pub group_wrapper: WidgetId,
     pub content_wrapper: WidgetId,
     pub form_wrapper: WidgetId,
 
     pub group_tab_buttons: [WidgetId; MODE_SETTINGS_GROUP_TABS_COUNT],
     pub group_tab_texts: [WidgetId; MODE_SETTINGS_GROUP_TABS_COUNT],
+
+    pub alarms_auto_button: WidgetId,
+    pub alarms_auto_text: WidgetId,
+    pub alarms_auto_available: bool,
 }
 
 struct Field {
@@ -101,7 +104,8 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         color: color::TRANSPARENT,
         width: config.width,
         height: config.height,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.container_parent,
         ]
     );
@@ -124,10 +128,11 @@ fn selector<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     gen_widget_container!(
         master,
         container_id: config.selector_wrapper,
-        color: SELECTOR_BORDER_COLOR,
+        color: master.theme.modal.border,
         width: config.width,
         height: MODE_SETTINGS_SELECTOR_TABS_HEIGHT,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.container_widget, -MODE_SETTINGS_MODAL_PADDING, -MODE_SETTINGS_MODAL_PADDING,
         ]
     );
@@ -140,14 +145,14 @@ fn selector<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         let (rectangle_color, rectangle_height_offset, rectangle_width_offset, selection_offset) =
             if Some(mode) == index_mode {
                 (
-                    SELECTOR_COLOR_SELECTED,
+                    master.theme.modal.selector_selected,
                     0.0,
                     0.0,
                     if index == 0 { 0.0 } else { 1.0 },
                 )
             } else {
                 (
-                    SELECTOR_COLOR_DEFAULT,
+                    master.theme.modal.selector_default,
                     1.0,
                     if index == 0 { 0.0 } else { 1.0 },
                     0.0,
@@ -161,7 +166,8 @@ fn selector<'a>(master: &mut ControlWidget<'a>, config: &Config) {
             ],
             rectangle_color,
         )
-        .top_left_with_margins_on(
+        .top_start_with_margins_on(
+            master.direction,
             config.selector_wrapper,
             0.0,
             rectangle_width_offset + selection_offset + index as f64 * tab_width,
@@ -177,9 +183,9 @@ fn selector<'a>(master: &mut ControlWidget<'a>, config: &Config) {
 
             text_style.font_id = Some(Some(master.fonts.bold));
             text_style.color = Some(if is_selected && mode != config.mode_settings.live.mode {
-                SELECTOR_TEXT_COLOR_CHANGED
+                master.theme.base.text_changed
             } else {
-                color::WHITE
+                master.theme.base.text
             });
             text_style.font_size = Some(14);
 
@@ -213,7 +219,8 @@ fn content<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         color: color::TRANSPARENT,
         width: size.0,
         height: size.1,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.container_widget, MODE_SETTINGS_SELECTOR_TABS_HEIGHT, 0.0,
         ]
     );
@@ -240,6 +247,31 @@ fn group<'a>(master: &mut ControlWidget<'a>, config: &Config, parent_size: (f64,
             index,
         );
     }
+
+    // Render alarms auto-set button (only if measured values are available to auto-set from)
+    if config.mode_settings.group == SettingsModeGroupTab::Alarms && config.alarms_auto_available {
+        gen_widget_button!(
+            master,
+            button_id: config.alarms_auto_button,
+            text_id: config.alarms_auto_text,
+            text_color: master.theme.base.button_text,
+            text_font_size: MODE_SETTINGS_ALARMS_AUTO_FONT_SIZE,
+            width: MODAL_GROUP_TABS_WIDTH,
+            value_top: MODAL_BUTTON_VALUE_TOP,
+            value: &APP_I18N.t("modal-mode-alarms-auto-set"),
+
+            positions: (
+                top_start_with_margins_on[
+                    master.direction,
+                    config.group_wrapper,
+                    MODE_SETTINGS_GROUP_TABS_COUNT as f64
+                        * (MODAL_GROUP_TABS_HEIGHT + MODAL_GROUP_TABS_MARGIN_TOP)
+                        + MODE_SETTINGS_ALARMS_AUTO_MARGIN_TOP,
+                    0.0,
+                ]
+            )
+        );
+    }
 }
 
 fn group_tab<'a>(
@@ -274,7 +306,8 @@ fn form<'a>(master: &mut ControlWidget<'a>, config: &Config, parent_size: (f64,
         color: color::TRANSPARENT,
         width: parent_size.0 - tabs_total_width,
         height: parent_size.1,
-        positions: top_right_of[
+        positions: top_end_of[
+            master.direction,
             config.content_wrapper,
         ]
     );
@@ -402,11 +435,7 @@ fn field_pressure_inspiratory<'a>(index: usize, master: &mut ControlWidget<'a>,
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-pressure-inspiratory"),
-            value_text: format!(
-                "{} {}",
-                convert_mmh2o_to_cmh2o(ConvertMode::Rounded, field_values.current as f64),
-                APP_I18N.t("telemetry-unit-cmh2o")
-            ),
+            value_text: format_pressure(field_values.current as f64, 0),
             ids: config.field_pressure_inspiratory_ids,
         },
         field_values,
@@ -422,11 +451,7 @@ fn field_pressure_expiratory<'a>(index: usize, master: &mut ControlWidget<'a>, c
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-pressure-expiratory"),
-            value_text: format!(
-                "{} {}",
-                convert_mmh2o_to_cmh2o(ConvertMode::Rounded, field_values.current as f64),
-                APP_I18N.t("telemetry-unit-cmh2o")
-            ),
+            value_text: format_pressure(field_values.current as f64, 0),
             ids: config.field_pressure_expiratory_ids,
         },
         field_values,
@@ -510,11 +535,7 @@ fn field_tidal_volume<'a>(index: usize, master: &mut ControlWidget<'a>, config:
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-tidal-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-milliliters")
-            ),
+            value_text: format_volume(field_values.current as f64),
             ids: config.field_tidal_volume_ids,
         },
         field_values,
@@ -530,11 +551,7 @@ fn field_inspiratory_flow<'a>(index: usize, master: &mut ControlWidget<'a>, conf
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-flow-inspiratory"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-lpm")
-            ),
+            value_text: format_flow(field_values.current as f64),
             ids: config.field_inspiratory_flow_ids,
         },
         field_values,
@@ -590,11 +607,7 @@ fn field_trigger_offset<'a>(index: usize, master: &mut ControlWidget<'a>, config
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-trigger-offset"),
-            value_text: format!(
-                "{:.1} {}",
-                convert_mmh2o_to_cmh2o(ConvertMode::WithDecimals, field_values.current as f64),
-                APP_I18N.t("telemetry-unit-cmh2o")
-            ),
+            value_text: format_pressure(field_values.current as f64, 1),
             ids: config.field_trigger_offset_ids,
         },
         field_values,
@@ -631,11 +644,7 @@ fn field_alarm_threshold_low_inspiratory_minute_volume<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-low-inspiratory-minute-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-lpm")
-            ),
+            value_text: format_flow(field_values.current as f64),
             ids: config.field_alarm_threshold_low_inspiratory_minute_volume_ids,
         },
         field_values,
@@ -656,11 +665,7 @@ fn field_alarm_threshold_high_inspiratory_minute_volume<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-high-inspiratory-minute-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-lpm")
-            ),
+            value_text: format_flow(field_values.current as f64),
             ids: config.field_alarm_threshold_high_inspiratory_minute_volume_ids,
         },
         field_values,
@@ -681,11 +686,7 @@ fn field_alarm_threshold_low_expiratory_minute_volume<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-low-expiratory-minute-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-lpm")
-            ),
+            value_text: format_flow(field_values.current as f64),
             ids: config.field_alarm_threshold_low_expiratory_minute_volume_ids,
         },
         field_values,
@@ -706,11 +707,7 @@ fn field_alarm_threshold_high_expiratory_minute_volume<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-high-expiratory-minute-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-lpm")
-            ),
+            value_text: format_flow(field_values.current as f64),
             ids: config.field_alarm_threshold_high_expiratory_minute_volume_ids,
         },
         field_values,
@@ -778,11 +775,7 @@ fn field_alarm_threshold_low_tidal_volume<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-low-tidal-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-milliliters")
-            ),
+            value_text: format_volume(field_values.current as f64),
             ids: config.field_alarm_threshold_low_tidal_volume_ids,
         },
         field_values,
@@ -802,11 +795,7 @@ fn field_alarm_threshold_high_tidal_volume<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-high-tidal-volume"),
-            value_text: format!(
-                "{} {}",
-                field_values.current,
-                APP_I18N.t("telemetry-unit-milliliters")
-            ),
+            value_text: format_volume(field_values.current as f64),
             ids: config.field_alarm_threshold_high_tidal_volume_ids,
         },
         field_values,
@@ -830,7 +819,32 @@ fn field_alarm_threshold_leak<'a>(index: usize, master: &mut ControlWidget<'a>,
             ids: config.field_alarm_threshold_leak_ids,
         },
         field_values,
-    )
+    );
+
+    // Show measured leak next to its alarm threshold (if it could be measured)
+    if let Some((leak, leak_percent)) = config.leak {
+        let mut measured_text_style = widget::text::Style::default();
+
+        measured_text_style.font_id = Some(Some(master.fonts.regular));
+        measured_text_style.color = Some(master.theme.base.text);
+        measured_text_style.font_size = Some(MODE_SETTINGS_LEAK_MEASURED_FONT_SIZE);
+
+        widget::text::Text::new(&APP_I18N.t_args(
+            "modal-mode-alarm-leak-measured",
+            &[
+                ("leak", APP_I18N.format_number(leak, 0).into()),
+                ("unit", APP_I18N.t("telemetry-unit-mlpm").into()),
+                ("percent", APP_I18N.format_number(leak_percent, 0).into()),
+            ],
+        ))
+        .with_style(measured_text_style)
+        .end_from(
+            master.direction,
+            config.field_alarm_threshold_leak_ids.3,
+            MODE_SETTINGS_LEAK_MEASURED_MARGIN_LEFT,
+        )
+        .set(config.leak_measured, &mut master.ui);
+    }
 }
 
 fn field_alarm_threshold_peak_pressure<'a>(
@@ -846,17 +860,55 @@ fn field_alarm_threshold_peak_pressure<'a>(
         config,
         Field {
             label_text: APP_I18N.t("modal-mode-alarm-peak-pressure"),
-            value_text: format!(
-                "{} {}",
-                convert_mmh2o_to_cmh2o(ConvertMode::Rounded, field_values.current as f64),
-                APP_I18N.t("telemetry-unit-cmh2o")
-            ),
+            value_text: format_pressure(field_values.current as f64, 0),
             ids: config.field_alarm_threshold_peak_pressure_ids,
         },
         field_values,
     )
 }
 
+fn format_pressure(value: f64, decimals: usize) -> String {
+    // Notice: pressure settings are expressed in mmH2O, and shown in the pressure unit.
+    let pressure_unit = APP_SETTINGS.read().unwrap().pressure_unit;
+
+    format!(
+        "{} {}",
+        APP_I18N.format_number(
+            convert_mmh2o_to_pressure_unit(ConvertMode::WithDecimals, value, pressure_unit),
+            decimals
+        ),
+        APP_I18N.t(pressure_unit.to_locale_key())
+    )
+}
+
+fn format_flow(value: f64) -> String {
+    // Notice: flow settings are expressed in L/min, and shown in the flow unit.
+    let flow_unit = APP_SETTINGS.read().unwrap().flow_unit;
+
+    format!(
+        "{} {}",
+        APP_I18N.format_number(
+            convert_lpm_to_flow_unit(ConvertMode::WithDecimals, value, flow_unit),
+            0
+        ),
+        APP_I18N.t(flow_unit.to_locale_key())
+    )
+}
+
+fn format_volume(value: f64) -> String {
+    // Notice: volume settings are expressed in mL, and shown in the volume unit.
+    let volume_unit = APP_SETTINGS.read().unwrap().volume_unit;
+
+    format!(
+        "{} {}",
+        APP_I18N.format_number(
+            convert_ml_to_volume_unit(ConvertMode::WithDecimals, value, volume_unit),
+            volume_unit.decimals()
+        ),
+        APP_I18N.t(volume_unit.to_locale_key())
+    )
+}
+
 fn draw_field<'a>(
     index: usize,
     master: &mut ControlWidget<'a>,
@@ -876,7 +928,8 @@ fn draw_field<'a>(
         master,
         text_id: field.ids.0,
         value: &field.label_text,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.form_wrapper, index as f64 * MODE_SETTINGS_MODAL_FORM_FIELD_HEIGHT_PADDED, 0.0,
         ]
     );
@@ -892,7 +945,8 @@ fn draw_field<'a>(
         value_id: field.ids.2,
         value: &field.value_text,
         changed: has_changed,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             field.ids.0,
             MODAL_BUTTON_NAVIGATE_LEFT_ALIGN_TOP,
             MODE_SETTINGS_MODAL_FORM_PADDING_LEFT,
@@ -9,14 +9,14 @@
 // License: Public Domain License
 
 use conrod_core::{
-    color::{self, Color},
+    color,
     widget::{self, Id as WidgetId},
     Positionable, Sizeable, Widget,
 };
 
 use crate::chip::settings::preset::{SettingsPreset, SettingsPresetAge};
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::locale::preset::{
     age_to_locale as preset_age_to_locale, gender_to_locale as preset_gender_to_locale,
 };
@@ -72,7 +72,8 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         color: color::TRANSPARENT,
         width: config.width,
         height: config.height,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.container_parent,
         ]
     );
@@ -95,7 +96,7 @@ fn title_primary<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = widget::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.bold));
-    text_style.color = Some(color::WHITE);
+    text_style.color = Some(master.theme.base.text);
     text_style.font_size = Some(19);
 
     // Append text
@@ -110,7 +111,7 @@ fn title_secondary<'a>(master: &mut ControlWidget<'a>, config: &Config) {
     let mut text_style = widget::text::Style::default();
 
     text_style.font_id = Some(Some(master.fonts.regular));
-    text_style.color = Some(color::WHITE.alpha(0.5));
+    text_style.color = Some(master.theme.base.text.alpha(0.5));
     text_style.font_size = Some(15);
 
     // Append text
@@ -133,7 +134,8 @@ fn content<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         color: color::TRANSPARENT,
         width: size.0,
         height: size.1,
-        positions: bottom_left_of[
+        positions: bottom_start_of[
+            master.direction,
             config.container_widget,
         ]
     );
@@ -158,13 +160,14 @@ fn content_image<'a>(master: &mut ControlWidget<'a>, config: &Config) {
             PRESET_SETTINGS_MODAL_CONTENT_IMAGE_WIDTH,
             PRESET_SETTINGS_MODAL_CONTENT_IMAGE_HEIGHT,
         )
-        .mid_left_of(config.content_wrapper)
+        .mid_start_of(master.direction, config.content_wrapper)
         .set(config.content_image, &mut master.ui);
 }
 
 fn content_separator<'a>(master: &mut ControlWidget<'a>, config: &Config, size: (f64, f64)) {
-    widget::Rectangle::fill_with([1.0, size.1], color::WHITE.alpha(0.035))
-        .mid_right_with_margin_on(
+    widget::Rectangle::fill_with([1.0, size.1], master.theme.base.text.alpha(0.035))
+        .mid_end_with_margin_on(
+            master.direction,
             config.content_image,
             -PRESET_SETTINGS_MODAL_CONTENT_SEPARATOR_MARGIN_SIDES,
         )
@@ -179,7 +182,8 @@ fn content_form<'a>(master: &mut ControlWidget<'a>, config: &Config, size: (f64,
         color: color::TRANSPARENT,
         width: size.0 - PRESET_SETTINGS_MODAL_CONTENT_IMAGE_WIDTH - (2.0 * PRESET_SETTINGS_MODAL_CONTENT_SEPARATOR_MARGIN_SIDES),
         height: PRESET_SETTINGS_MODAL_CONTENT_FORM_FIELD_COUNT * PRESET_SETTINGS_MODAL_CONTENT_FORM_FIELD_HEIGHT_PADDED - PRESET_SETTINGS_MODAL_CONTENT_FORM_FIELD_HEIGHT_PADDED / 2.0,
-        positions: mid_right_of[
+        positions: mid_end_of[
+            master.direction,
             config.content_wrapper,
         ]
     );
@@ -228,7 +232,8 @@ fn draw_field<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config,
         master,
         text_id: field.ids.0,
         value: &field.label_text,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             config.content_form_wrapper, index as f64 * PRESET_SETTINGS_MODAL_CONTENT_FORM_FIELD_HEIGHT_PADDED, 0.0,
         ]
     );
@@ -244,7 +249,8 @@ fn draw_field<'a>(index: usize, master: &mut ControlWidget<'a>, config: &Config,
         value_id: field.ids.2,
         value: &field.value_text,
         changed: false,
-        positions: top_left_with_margins_on[
+        positions: top_start_with_margins_on[
+            master.direction,
             field.ids.0,
             MODAL_BUTTON_NAVIGATE_LEFT_ALIGN_TOP,
             PRESET_SETTINGS_MODAL_CONTENT_FORM_PADDING_LEFT,
@@ -16,7 +16,7 @@ use conrod_core::{
 
 use crate::chip::settings::{run::SettingsRun, SettingActionState};
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::APP_I18N;
 
 pub struct Config<'a> {
@@ -40,7 +40,8 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         color: color::TRANSPARENT,
         width: config.width,
         height: config.height / 2.0,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.status_container_parent,
         ]
     );
@@ -63,7 +64,8 @@ fn status_label<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         master,
         text_id: config.status_enabled_text_widget,
         value: &APP_I18N.t("modal-run-status"),
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.status_container_widget,
         ]
     );
@@ -76,8 +78,8 @@ fn status_form<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         SettingActionState::Disabled => APP_I18N.t("modal-run-status-stopped"),
     };
     let button_color = match config.run_settings.state {
-        SettingActionState::Enabled => color::DARK_GREEN,
-        SettingActionState::Disabled => color::DARK_RED,
+        SettingActionState::Enabled => master.theme.base.button_positive,
+        SettingActionState::Disabled => master.theme.base.button_negative,
     };
 
     // Append status button
@@ -92,7 +94,8 @@ fn status_form<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         value: &status_label,
 
         positions: (
-            top_left_with_margins_on[
+            top_start_with_margins_on[
+                master.direction,
                 config.status_container_widget,
                 -4.5,
                 RUN_SETTINGS_MODAL_FORM_PADDING_LEFT,
@@ -0,0 +1,52 @@
+// MakAir Control UI
+//
+// Copyright: 2021, Makers For Life
+// License: Public Domain License
+
+use conrod_core::{
+    widget::{self, Id as WidgetId},
+    Positionable, Widget,
+};
+
+use crate::config::environment::*;
+use crate::config::settings::ConfigSettingsWarning;
+use crate::display::widget::ControlWidget;
+use crate::APP_I18N;
+
+pub struct Config {
+    pub parent: WidgetId,
+
+    pub message_box: WidgetId,
+    pub message_text: WidgetId,
+
+    pub warning: ConfigSettingsWarning,
+}
+
+pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
+    // Create message box (tapping it dismisses the notice)
+    widget::rounded_rectangle::RoundedRectangle::fill_with(
+        [SETTINGS_NOTICE_WIDTH, SETTINGS_NOTICE_HEIGHT],
+        DISPLAY_ROUNDED_RECTANGLES_ROUND,
+        master.theme.status.notice,
+    )
+    .mid_top_with_margin_on(config.parent, SETTINGS_NOTICE_MARGIN_TOP)
+    .set(config.message_box, &mut master.ui);
+
+    // Initialize message style
+    let mut message_style = widget::text::Style::default();
+
+    message_style.color = Some(master.theme.base.text);
+    message_style.font_size = Some(SETTINGS_NOTICE_FONT_SIZE);
+    message_style.font_id = Some(Some(master.fonts.bold));
+
+    // Create message text
+    widget::text::Text::new(&APP_I18N.t(match config.warning {
+        ConfigSettingsWarning::Corrupt => "settings-notice-corrupt",
+        ConfigSettingsWarning::Invalid => "settings-notice-invalid",
+    }))
+    .with_style(message_style)
+    .middle_of(config.message_box)
+    .set(config.message_text, &mut master.ui);
+
+    0 as _
+}
@@ -16,7 +16,7 @@ use conrod_core::{
 
 use crate::chip::settings::{snooze::SettingsSnooze, SettingActionState};
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::APP_I18N;
 
 pub struct Config<'a> {
@@ -41,7 +41,8 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         color: color::TRANSPARENT,
         width: config.width,
         height: config.height / 2.0,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.container_parent,
         ]
     );
@@ -64,7 +65,8 @@ fn alarms_label<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         master,
         text_id: config.alarms_enabled_text_widget,
         value: &APP_I18N.t("modal-snooze-alarms"),
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.container_widget,
         ]
     );
@@ -77,8 +79,8 @@ fn alarms_form<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         SettingActionState::Enabled => APP_I18N.t("modal-snooze-alarms-inactive"),
     };
     let button_color = match config.snooze_settings.alarms {
-        SettingActionState::Disabled => color::DARK_BLUE,
-        SettingActionState::Enabled => color::DARK_RED,
+        SettingActionState::Disabled => master.theme.base.button_neutral,
+        SettingActionState::Enabled => master.theme.base.button_negative,
     };
 
     // Append alarms button
@@ -93,7 +95,8 @@ fn alarms_form<'a>(master: &mut ControlWidget<'a>, config: &Config) {
         value: &alarms_label,
 
         positions: (
-            top_left_with_margins_on[
+            top_start_with_margins_on[
+                master.direction,
                 config.container_widget,
                 -4.5,
                 SNOOZE_SETTINGS_MODAL_FORM_PADDING_LEFT,
@@ -4,7 +4,7 @@
 // License: Public Domain License
 
 use conrod_core::{
-    color::{self, Color},
+    color,
     widget::{self, Id as WidgetId},
     Positionable, Sizeable, Widget,
 };
@@ -14,19 +14,11 @@ use makair_telemetry::structures::{AlarmPriority, DataSnapshot};
 
 use crate::chip::ChipState;
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 use crate::APP_I18N;
 
 const POWER_BOX_BATTERY_WEAK_THRESHOLD: u8 = 25;
 
-const WRAPPER_COLOR: Color = Color::Rgba(52.0 / 255.0, 52.0 / 255.0, 52.0 / 255.0, 1.0);
-
-const UNIT_STOPPED_COLOR: Color = Color::Rgba(180.0 / 255.0, 24.0 / 255.0, 28.0 / 255.0, 1.0);
-const UNIT_ACTIVE_COLOR: Color = Color::Rgba(50.0 / 255.0, 186.0 / 255.0, 0.0, 1.0);
-
-const POWER_BOX_BATTERY_NORMAL_COLOR: Color = Color::Rgba(208.0 / 255.0, 92.0 / 255.0, 0.0, 1.0);
-const POWER_BOX_BATTERY_WEAK_COLOR: Color = Color::Rgba(1.0, 0.0 / 255.0, 3.0 / 255.0, 1.0);
-
 pub struct Config<'a> {
     pub container: WidgetId,
     pub wrapper: WidgetId,
@@ -34,9 +26,13 @@ pub struct Config<'a> {
     pub unit_text: WidgetId,
     pub power_box: WidgetId,
     pub power_text: WidgetId,
+    pub snooze_box: WidgetId,
+    pub snooze_text: WidgetId,
     pub recording: Option<(WidgetId, WidgetId)>,
 
     pub battery_soc: Option<u8>,
+    pub snooze_remaining_seconds: Option<u64>,
+    pub snooze_escalated: bool,
     pub chip_state: &'a ChipState,
     pub data_snapshot: Option<&'a DataSnapshot>,
     pub alarms: &'a [(AlarmCode, AlarmPriority)],
@@ -57,10 +53,13 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         .any(|alarm| alarm.0.code() == RMC_SW_16);
 
     // Create wrapper canvas
+    // Notice: the wrapper stays on the right side of the header whatever the locale direction, \
+    //   as it sits next to the control buttons, which are drawn from the header texture. Only \
+    //   its contents get mirrored.
     gen_widget_container!(
         master,
         container_id: config.wrapper,
-        color: WRAPPER_COLOR,
+        color: master.theme.status.wrapper,
         width: STATUS_WRAPPER_WIDTH,
         height: STATUS_WRAPPER_HEIGHT,
         positions: top_right_with_margins_on[
@@ -75,13 +74,14 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         master,
         container_id: config.unit_box,
         color: if is_unit_stopped {
-            UNIT_STOPPED_COLOR
+            master.theme.status.unit_stopped
         } else {
-            UNIT_ACTIVE_COLOR
+            master.theme.status.unit_active
         },
         width: box_width,
         height: box_height,
-        positions: top_left_of[
+        positions: top_start_of[
+            master.direction,
             config.wrapper,
         ]
     );
@@ -90,7 +90,7 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
     let mut unit_text_style = conrod_core::widget::primitive::text::Style::default();
 
     unit_text_style.font_id = Some(Some(master.fonts.bold));
-    unit_text_style.color = Some(color::WHITE);
+    unit_text_style.color = Some(master.theme.base.text);
     unit_text_style.font_size = Some(11);
 
     let unit_text_value = if is_unit_stopped {
@@ -103,7 +103,8 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
     if let Some(recording) = config.recording {
         widget::text::Text::new(&unit_text_value)
             .with_style(unit_text_style)
-            .top_left_with_margins_on(
+            .top_start_with_margins_on(
+                master.direction,
                 config.unit_box,
                 STATUS_BOX_TEXT_MARGIN_TOP,
                 STATUS_BOX_RECORDING_PADDING_LEFT,
@@ -112,14 +113,18 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
 
         widget::primitive::shape::circle::Circle::fill_with(
             STATUS_RECORDING_OUTER_RADIUS,
-            color::WHITE,
+            master.theme.base.text,
+        )
+        .mid_end_with_margin_on(
+            master.direction,
+            config.unit_box,
+            STATUS_BOX_RECORDING_PADDING_RIGHT,
         )
-        .mid_right_with_margin_on(config.unit_box, STATUS_BOX_RECORDING_PADDING_RIGHT)
         .set(recording.0, &mut master.ui);
 
         widget::primitive::shape::circle::Circle::fill_with(
             STATUS_RECORDING_INNER_RADIUS,
-            color::RED,
+            master.theme.status.recording,
         )
         .middle_of(recording.0)
         .set(recording.1, &mut master.ui);
@@ -145,16 +150,17 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         container_id: config.power_box,
         color: if is_battery_powered {
             if battery_soc.unwrap_or(100) <= POWER_BOX_BATTERY_WEAK_THRESHOLD {
-                POWER_BOX_BATTERY_WEAK_COLOR
+                master.theme.status.battery_weak
             } else {
-                POWER_BOX_BATTERY_NORMAL_COLOR
+                master.theme.status.battery_normal
             }
         } else {
             color::TRANSPARENT
         },
         width: box_width,
         height: box_height,
-        positions: bottom_left_of[
+        positions: bottom_start_of[
+            master.direction,
             config.wrapper,
         ]
     );
@@ -163,7 +169,7 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
     let mut power_text_style = conrod_core::widget::primitive::text::Style::default();
 
     power_text_style.font_id = Some(Some(master.fonts.bold));
-    power_text_style.color = Some(color::WHITE);
+    power_text_style.color = Some(master.theme.base.text);
     power_text_style.font_size = Some(11);
 
     let power_text_value = if is_battery_powered {
@@ -183,5 +189,54 @@ pub fn render<'a>(master: &mut ControlWidget<'a>, config: Config) -> f64 {
         .mid_top_with_margin_on(config.power_box, STATUS_BOX_TEXT_MARGIN_TOP)
         .set(config.power_text, &mut master.ui);
 
+    // Create snooze countdown box (if alarms are snoozed)
+    if let Some(snooze_remaining_seconds) = config.snooze_remaining_seconds {
+        snooze(master, &config, box_height, snooze_remaining_seconds);
+    }
+
     STATUS_WRAPPER_WIDTH
 }
+
+fn snooze(master: &mut ControlWidget, config: &Config, box_height: f64, remaining_seconds: u64) {
+    // Create snooze box canvas (escalates if a new high-priority alarm was raised while snoozed)
+    // Notice: as the wrapper is not mirrored, the snooze box always sits on its left side.
+    gen_widget_container!(
+        master,
+        container_id: config.snooze_box,
+        color: if config.snooze_escalated {
+            master.theme.status.snooze_escalated
+        } else {
+            master.theme.status.snooze_normal
+        },
+        width: STATUS_SNOOZE_WIDTH,
+        height: box_height,
+        positions: left_from[
+            config.wrapper,
+            STATUS_SNOOZE_MARGIN_RIGHT,
+        ]
+    );
+
+    // Create snooze countdown text
+    let mut snooze_text_style = conrod_core::widget::primitive::text::Style::default();
+
+    snooze_text_style.font_id = Some(Some(master.fonts.bold));
+    snooze_text_style.color = Some(master.theme.base.text);
+    snooze_text_style.font_size = Some(11);
+
+    let snooze_text_value = APP_I18N.t_args(
+        if config.snooze_escalated {
+            "status-snooze-escalated"
+        } else {
+            "status-snooze-remaining"
+        },
+        &[(
+            "remaining",
+            format!("{}:{:02}", remaining_seconds / 60, remaining_seconds % 60).into(),
+        )],
+    );
+
+    widget::text::Text::new(&snooze_text_value)
+        .with_style(snooze_text_style)
+        .mid_top_with_margin_on(config.snooze_box, STATUS_BOX_TEXT_MARGIN_TOP)
+        .set(config.snooze_text, &mut master.ui);
+}
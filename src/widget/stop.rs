@@ -4,7 +4,6 @@
 // License: Public Domain License
 
 use conrod_core::{
-    color::{self, Color},
     widget::{self, Id as WidgetId},
     Positionable, Widget,
 };
@@ -13,8 +12,6 @@ use crate::config::environment::*;
 use crate::display::widget::ControlWidget;
 use crate::APP_I18N;
 
-const MESSAGE_COLOR: Color = Color::Rgba(1.0, 1.0, 1.0, 0.75);
-
 pub struct Config {
     pub container: WidgetId,
     pub title: WidgetId,
@@ -25,7 +22,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     // Initialize title style
     let mut title_style = widget::text::Style::default();
 
-    title_style.color = Some(color::WHITE);
+    title_style.color = Some(master.theme.base.text);
     title_style.font_size = Some(19);
     title_style.font_id = Some(Some(master.fonts.bold));
 
@@ -38,7 +35,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     // Initialize message style
     let mut message_style = widget::text::Style::default();
 
-    message_style.color = Some(MESSAGE_COLOR);
+    message_style.color = Some(master.theme.base.text.alpha(0.75));
     message_style.font_size = Some(14);
     message_style.font_id = Some(Some(master.fonts.regular));
 
@@ -9,7 +9,7 @@ use conrod_core::{
     Positionable, Widget,
 };
 
-use crate::display::widget::ControlWidget;
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 
 pub struct Config {
     pub width: f64,
@@ -22,7 +22,7 @@ pub struct Config {
 pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     // Create rectangle for container
     widget::rectangle::Rectangle::fill_with([config.width, config.height], color::TRANSPARENT)
-        .right_from(config.parent, 0.0)
+        .end_from(master.direction, config.parent, 0.0)
         .set(config.id, &mut master.ui);
 
     0.0
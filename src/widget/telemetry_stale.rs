@@ -0,0 +1,73 @@
+// MakAir Control UI
+//
+// Copyright: 2020, Makers For Life
+// License: Public Domain License
+
+use conrod_core::{
+    widget::{self, Id as WidgetId},
+    Positionable, Widget,
+};
+
+use crate::config::environment::*;
+use crate::display::widget::ControlWidget;
+use crate::APP_I18N;
+
+pub struct Config {
+    pub parent_right: WidgetId,
+    pub parent_bottom: WidgetId,
+
+    pub overlay_right: WidgetId,
+    pub overlay_bottom: WidgetId,
+    pub message_box: WidgetId,
+    pub message_text: WidgetId,
+
+    pub elapsed_seconds: u64,
+}
+
+pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
+    // Grey out right telemetry views
+    widget::rectangle::Rectangle::fill_with(
+        [TELEMETRY_WIDGET_RIGHT_SIZE_WIDTH, LAYOUT_BODY_SIZE_HEIGHT],
+        master.theme.telemetry.stale_overlay,
+    )
+    .top_left_of(config.parent_right)
+    .set(config.overlay_right, &mut master.ui);
+
+    // Grey out bottom telemetry views
+    widget::rectangle::Rectangle::fill_with(
+        [DISPLAY_WINDOW_SIZE_WIDTH as _, LAYOUT_FOOTER_SIZE_HEIGHT],
+        master.theme.telemetry.stale_overlay,
+    )
+    .middle_of(config.parent_bottom)
+    .set(config.overlay_bottom, &mut master.ui);
+
+    // Create message box
+    widget::rounded_rectangle::RoundedRectangle::fill_with(
+        [
+            TELEMETRY_STALE_MESSAGE_WIDTH,
+            TELEMETRY_STALE_MESSAGE_HEIGHT,
+        ],
+        DISPLAY_ROUNDED_RECTANGLES_ROUND,
+        master.theme.telemetry.stale_message,
+    )
+    .middle_of(config.overlay_bottom)
+    .set(config.message_box, &mut master.ui);
+
+    // Initialize message style
+    let mut message_style = widget::text::Style::default();
+
+    message_style.color = Some(master.theme.base.text);
+    message_style.font_size = Some(TELEMETRY_STALE_MESSAGE_FONT_SIZE);
+    message_style.font_id = Some(Some(master.fonts.bold));
+
+    // Create message text
+    widget::text::Text::new(&APP_I18N.t_args(
+        "telemetry-stale-since",
+        &[("seconds", config.elapsed_seconds.into())],
+    ))
+    .with_style(message_style)
+    .middle_of(config.message_box)
+    .set(config.message_text, &mut master.ui);
+
+    0 as _
+}
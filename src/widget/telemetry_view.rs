@@ -6,15 +6,13 @@
 use std::iter::once;
 
 use conrod_core::{
-    color::{self, Color},
+    color::Color,
     widget::{self, Id as WidgetId},
     Colorable, Positionable, Widget,
 };
 
 use crate::config::environment::*;
-use crate::display::widget::ControlWidget;
-
-const ARROW_COLOR: Color = Color::Rgba(1.0, 1.0, 1.0, 0.25);
+use crate::display::widget::{ControlWidget, DirectionalPositionable};
 
 pub struct Config {
     pub title: String,
@@ -46,13 +44,19 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
         TELEMETRY_WIDGET_UNIT_BORDER_RADIUS,
         config.background_color,
     )
-    .bottom_left_with_margins_on(config.ids.0, config.y_position, config.x_position)
+    .bottom_start_with_margins_on(
+        master.direction,
+        config.ids.0,
+        config.y_position,
+        config.x_position,
+    )
     .set(config.ids.1, &mut master.ui);
 
     // Create title text
     widget::Text::new(&config.title)
-        .color(color::WHITE)
-        .top_left_with_margins_on(
+        .color(master.theme.base.text)
+        .top_start_with_margins_on(
+            master.direction,
             config.ids.1,
             TELEMETRY_WIDGET_UNIT_PADDING_BOTTOM_TOP,
             TELEMETRY_WIDGET_PADDING_LEFT,
@@ -64,7 +68,7 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
     let mut value_text_style = conrod_core::widget::primitive::text::Style::default();
 
     value_text_style.font_id = Some(Some(master.fonts.bold));
-    value_text_style.color = Some(color::WHITE);
+    value_text_style.color = Some(master.theme.base.text);
     value_text_style.font_size = Some(40);
 
     // Create value text
@@ -75,26 +79,37 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
             let mut target_text_style = conrod_core::widget::primitive::text::Style::default();
 
             target_text_style.font_id = Some(Some(master.fonts.regular));
-            target_text_style.color = Some(color::WHITE);
+            target_text_style.color = Some(master.theme.base.text);
             target_text_style.font_size = Some(23);
 
             // Draw measured value
             widget::Text::new(&value_measured)
                 .with_style(value_text_style)
-                .mid_left_with_margin_on(config.ids.1, TELEMETRY_WIDGET_PADDING_LEFT)
+                .mid_start_with_margin_on(
+                    master.direction,
+                    config.ids.1,
+                    TELEMETRY_WIDGET_PADDING_LEFT,
+                )
                 .set(config.ids.3, &mut master.ui);
 
-            // Draw arrow
+            // Draw arrow (points towards the measured value)
+            let (arrow_tip, arrow_base) = if master.direction.is_rtl() {
+                ((TELEMETRY_ARROW_MAIN_WIDTH - 1) as f64, 0.0)
+            } else {
+                (0.0, (TELEMETRY_ARROW_MAIN_WIDTH - 1) as f64)
+            };
+
             widget::polygon::Polygon::centred_styled(
-                once([0.0, ((TELEMETRY_ARROW_MAIN_HEIGHT - 1) / 2) as _])
-                    .chain(once([(TELEMETRY_ARROW_MAIN_WIDTH - 1) as _, 0.0]))
-                    .chain(once([
-                        (TELEMETRY_ARROW_MAIN_WIDTH - 1) as _,
-                        (TELEMETRY_ARROW_MAIN_HEIGHT - 1) as _,
-                    ])),
-                widget::primitive::shape::Style::Fill(Some(ARROW_COLOR)),
+                once([arrow_tip, ((TELEMETRY_ARROW_MAIN_HEIGHT - 1) / 2) as _])
+                    .chain(once([arrow_base, 0.0]))
+                    .chain(once([arrow_base, (TELEMETRY_ARROW_MAIN_HEIGHT - 1) as _])),
+                widget::primitive::shape::Style::Fill(Some(master.theme.telemetry.arrow)),
+            )
+            .end_from(
+                master.direction,
+                config.ids.3,
+                TELEMETRY_ARROW_SPACING_SIDES,
             )
-            .right_from(config.ids.3, TELEMETRY_ARROW_SPACING_SIDES)
             .y_relative_to(config.ids.3, -7.0)
             .set((config.ids.4).0, &mut master.ui);
 
@@ -103,16 +118,20 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
                     TELEMETRY_ARROW_LINE_WIDTH as _,
                     TELEMETRY_ARROW_LINE_HEIGHT as _,
                 ],
-                ARROW_COLOR,
+                master.theme.telemetry.arrow,
             )
-            .right_from((config.ids.4).0, 0.0)
+            .end_from(master.direction, (config.ids.4).0, 0.0)
             .y_relative_to((config.ids.4).0, 0.0)
             .set((config.ids.4).1, &mut master.ui);
 
             // Draw target value
             widget::Text::new(&format!("({})", value_target))
                 .with_style(target_text_style)
-                .right_from((config.ids.4).1, TELEMETRY_ARROW_SPACING_SIDES + 1.0)
+                .end_from(
+                    master.direction,
+                    (config.ids.4).1,
+                    TELEMETRY_ARROW_SPACING_SIDES + 1.0,
+                )
                 .y_relative_to(config.ids.3, -2.0)
                 .set(config.ids.5, &mut master.ui);
         }
@@ -120,14 +139,22 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
             // Draw measured value
             widget::Text::new(&value_measured)
                 .with_style(value_text_style)
-                .mid_left_with_margin_on(config.ids.1, TELEMETRY_WIDGET_PADDING_LEFT)
+                .mid_start_with_margin_on(
+                    master.direction,
+                    config.ids.1,
+                    TELEMETRY_WIDGET_PADDING_LEFT,
+                )
                 .set(config.ids.3, &mut master.ui);
         }
         (None, Some(value_target)) => {
             // Draw target value
             widget::Text::new(&value_target)
                 .with_style(value_text_style)
-                .mid_left_with_margin_on(config.ids.1, TELEMETRY_WIDGET_PADDING_LEFT)
+                .mid_start_with_margin_on(
+                    master.direction,
+                    config.ids.1,
+                    TELEMETRY_WIDGET_PADDING_LEFT,
+                )
                 .set(config.ids.5, &mut master.ui);
         }
         _ => {}
@@ -138,13 +165,14 @@ pub fn render(master: &mut ControlWidget, config: Config) -> f64 {
         let mut unit_text_style = conrod_core::widget::primitive::text::Style::default();
 
         unit_text_style.font_id = Some(Some(master.fonts.regular));
-        unit_text_style.color = Some(color::WHITE.with_alpha(0.35));
+        unit_text_style.color = Some(master.theme.base.text.with_alpha(0.35));
         unit_text_style.font_size = Some(TELEMETRY_WIDGET_UNIT_FONT_SIZE);
 
         // Create unit text
         widget::Text::new(&config.unit)
             .with_style(unit_text_style)
-            .bottom_left_with_margins_on(
+            .bottom_start_with_margins_on(
+                master.direction,
                 config.ids.1,
                 TELEMETRY_WIDGET_UNIT_PADDING_BOTTOM_TOP,
                 TELEMETRY_WIDGET_PADDING_LEFT,